@@ -0,0 +1,3 @@
+-- 안건 목록 Keyset(커서) 페이지네이션용 인덱스
+-- ORDER BY created_at DESC, id DESC + WHERE (created_at, id) < (커서) 조건을 인덱스만으로 처리
+CREATE INDEX idx_agendas_created_at ON agendas(created_at DESC, id DESC);
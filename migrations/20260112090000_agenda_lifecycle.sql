@@ -0,0 +1,13 @@
+-- 안건 상태 (draft → open → closed, 그리고 cancelled)
+CREATE TYPE agenda_status AS ENUM ('draft', 'open', 'closed', 'cancelled');
+
+-- 기존 안건은 지금처럼 계속 투표를 받을 수 있도록 'open'으로 시작
+ALTER TABLE agendas
+    ADD COLUMN status agenda_status NOT NULL DEFAULT 'open',
+    ADD COLUMN opens_at TIMESTAMPTZ,   -- 예약 시작 시각 (NULL: 즉시/수동)
+    ADD COLUMN closes_at TIMESTAMPTZ,  -- 예약 마감 시각 (NULL: 수동 마감)
+    ADD CONSTRAINT ck_agenda_window CHECK (opens_at IS NULL OR closes_at IS NULL OR opens_at < closes_at);
+
+-- 스케줄러가 "열릴 때가 된 draft" / "닫힐 때가 된 open" 안건만 빠르게 찾기 위한 부분 인덱스
+CREATE INDEX idx_agendas_pending_open ON agendas(opens_at) WHERE status = 'draft';
+CREATE INDEX idx_agendas_pending_close ON agendas(closes_at) WHERE status = 'open';
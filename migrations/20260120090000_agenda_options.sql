@@ -0,0 +1,24 @@
+-- 다지선다 안건의 선택지
+-- 선택지가 없는 안건은 지금처럼 찬/반(is_agree) 2지선다로 동작합니다.
+CREATE TABLE agenda_options (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    agenda_id UUID NOT NULL REFERENCES agendas(id),
+    position INT NOT NULL,         -- 표시 순서 (0부터)
+    label TEXT NOT NULL,
+
+    -- [반정규화] 선택지별 득표 캐싱 컬럼 (agree_count / disagree_count 와 같은 역할)
+    vote_count INT NOT NULL DEFAULT 0,
+
+    CONSTRAINT uk_option_agenda_position UNIQUE (agenda_id, position),
+    CONSTRAINT uk_option_agenda_label UNIQUE (agenda_id, label),
+    -- votes 에서 (agenda_id, option_id) 복합 FK로 참조하기 위한 키
+    CONSTRAINT uk_option_agenda_id UNIQUE (agenda_id, id)
+);
+
+-- 투표는 찬/반(is_agree) 또는 선택지(option_id) 중 정확히 하나
+ALTER TABLE votes
+    ALTER COLUMN is_agree DROP NOT NULL,
+    ADD COLUMN option_id UUID,
+    -- 다른 안건의 선택지에 투표하는 것을 DB 차원에서 차단
+    ADD CONSTRAINT fk_vote_option FOREIGN KEY (agenda_id, option_id) REFERENCES agenda_options(agenda_id, id),
+    ADD CONSTRAINT ck_vote_choice CHECK ((is_agree IS NULL) <> (option_id IS NULL));
@@ -0,0 +1,24 @@
+-- 안건별 투표 방식
+-- single_choice: 선택지 하나 (찬/반 안건 포함), approval: 여러 선택지 승인,
+-- instant_runoff / borda: 선택지에 순위를 매기는 선호 투표
+CREATE TYPE voting_method AS ENUM ('single_choice', 'approval', 'instant_runoff', 'borda');
+
+ALTER TABLE agendas
+    ADD COLUMN voting_method voting_method NOT NULL DEFAULT 'single_choice';
+
+-- 승인/선호 투표의 선택 목록 (rank: 0부터, 선호 투표는 선호 순서)
+-- 이 경우 votes 행은 is_agree / option_id 모두 NULL
+CREATE TABLE vote_selections (
+    vote_id UUID NOT NULL REFERENCES votes(id) ON DELETE CASCADE,
+    agenda_id UUID NOT NULL,
+    option_id UUID NOT NULL,
+    rank INT NOT NULL,
+
+    PRIMARY KEY (vote_id, rank),
+    CONSTRAINT uk_selection_vote_option UNIQUE (vote_id, option_id),
+    CONSTRAINT fk_selection_option FOREIGN KEY (agenda_id, option_id) REFERENCES agenda_options(agenda_id, id)
+);
+
+ALTER TABLE votes
+    DROP CONSTRAINT ck_vote_choice,
+    ADD CONSTRAINT ck_vote_choice CHECK (is_agree IS NULL OR option_id IS NULL);
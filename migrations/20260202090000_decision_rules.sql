@@ -0,0 +1,15 @@
+-- 안건별 가결 규칙
+-- simple_majority: 찬성(또는 1위 득표)이 과반, two_thirds: 2/3 이상
+CREATE TYPE decision_rule AS ENUM ('simple_majority', 'two_thirds');
+
+-- 동률 처리: fail = 부결(현상 유지), tie = 결정 없이 동률로 보고
+CREATE TYPE tie_policy AS ENUM ('fail', 'tie');
+
+ALTER TABLE agendas
+    ADD COLUMN decision_rule decision_rule NOT NULL DEFAULT 'simple_majority',
+    ADD COLUMN tie_policy tie_policy NOT NULL DEFAULT 'fail',
+    -- 최소 투표 참여 수 (NULL이면 정족수 없음)
+    ADD COLUMN quorum INT,
+    -- 마감 전까지 집계 비공개
+    ADD COLUMN hide_results BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD CONSTRAINT ck_agenda_quorum CHECK (quorum IS NULL OR quorum > 0);
@@ -0,0 +1,8 @@
+-- 기권: 찬/반/선택지 어느 쪽도 아니지만 투표 참여(정족수)에는 포함
+ALTER TABLE votes
+    ADD COLUMN abstain BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD CONSTRAINT ck_vote_abstain CHECK (NOT abstain OR (is_agree IS NULL AND option_id IS NULL));
+
+-- [반정규화] 기권 수 캐싱 컬럼
+ALTER TABLE agendas
+    ADD COLUMN abstain_count INT NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+-- 비밀번호 로그인: Argon2id PHC 문자열 ($argon2id$v=19$m=...,t=...,p=...$salt$hash)
+-- 기존 사용자는 비밀번호가 없으므로 NULL 허용 (NULL이면 로그인 불가)
+ALTER TABLE users
+    ADD COLUMN password_hash TEXT;
@@ -0,0 +1,25 @@
+-- 로그인 세션: 로그인 1회 = 세션 1개, 세션의 Refresh Token은 사용할 때마다 교체(rotation)
+-- Access Token(JWT)의 sid 클레임이 세션 ID이며, 폐기된 세션의 Access Token은 거부됩니다.
+CREATE TABLE sessions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMPTZ -- 로그아웃 또는 토큰 재사용 감지 시각 (NULL: 유효)
+);
+
+CREATE INDEX idx_sessions_user ON sessions(user_id);
+
+-- Refresh Token: 원문은 저장하지 않고 SHA-256 해시만 저장
+-- 한 번 사용된(used_at) 토큰이 다시 제출되면 탈취로 보고 세션 전체를 폐기합니다.
+CREATE TABLE refresh_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    session_id UUID NOT NULL REFERENCES sessions(id) ON DELETE CASCADE,
+    token_hash BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ, -- 교체된 시각 (NULL: 아직 사용 전)
+
+    CONSTRAINT uk_refresh_token_hash UNIQUE (token_hash)
+);
+
+CREATE INDEX idx_refresh_tokens_session ON refresh_tokens(session_id);
@@ -0,0 +1,9 @@
+-- 사용자 역할: user(기본) < moderator < admin
+-- 첫 관리자는 직접 지정합니다: UPDATE users SET role = 'admin' WHERE username = '...';
+CREATE TYPE user_role AS ENUM ('user', 'moderator', 'admin');
+
+-- 탈퇴(삭제) 처리된 사용자: 투표/안건 기록은 남기고 계정만 비활성화
+-- (username은 다시 쓸 수 있도록 바꾸고, 비밀번호를 지워 로그인 불가)
+ALTER TABLE users
+    ADD COLUMN role user_role NOT NULL DEFAULT 'user',
+    ADD COLUMN deleted_at TIMESTAMPTZ;
@@ -0,0 +1,46 @@
+-- 웹훅 구독: 사용자가 등록한 URL로 안건 이벤트를 전송
+-- agenda_id가 있으면 그 안건의 이벤트만, NULL이면 모든 안건의 이벤트를 받습니다.
+-- events가 비어 있으면 모든 종류의 이벤트를 받습니다.
+CREATE TABLE webhook_subscriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    owner_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    agenda_id UUID REFERENCES agendas(id) ON DELETE CASCADE,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL, -- HMAC 서명 키 (등록 응답에서 한 번만 공개)
+    events TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_webhook_subscriptions_owner ON webhook_subscriptions(owner_id);
+CREATE INDEX idx_webhook_subscriptions_agenda ON webhook_subscriptions(agenda_id);
+
+-- 전송 대기열: 이벤트 1개 × 구독 1개 = 전송 1건
+-- pending → delivered (2xx 응답), 재시도 한도를 넘기면 dead (더 이상 시도하지 않음)
+CREATE TYPE webhook_delivery_status AS ENUM ('pending', 'delivered', 'dead');
+
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id) ON DELETE CASCADE,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    status webhook_delivery_status NOT NULL DEFAULT 'pending',
+    attempts INT NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(), -- 전송 중에는 임대 만료 시각 (작업이 죽으면 다시 시도)
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_webhook_deliveries_due ON webhook_deliveries(next_attempt_at) WHERE status = 'pending';
+CREATE INDEX idx_webhook_deliveries_subscription ON webhook_deliveries(subscription_id);
+
+-- 전송 시도 기록 (응답 코드 또는 연결 에러)
+CREATE TABLE webhook_attempts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    delivery_id UUID NOT NULL REFERENCES webhook_deliveries(id) ON DELETE CASCADE,
+    attempted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    status_code INT,  -- NULL: 응답을 받지 못함
+    error TEXT,       -- 2xx가 아니거나 연결 실패 사유
+    duration_ms INT NOT NULL
+);
+
+CREATE INDEX idx_webhook_attempts_delivery ON webhook_attempts(delivery_id, attempted_at DESC);
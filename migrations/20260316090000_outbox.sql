@@ -0,0 +1,16 @@
+-- 트랜잭션 아웃박스: 도메인 이벤트를 변경과 같은 트랜잭션에서 기록
+-- relay 작업이 id 순서대로 읽어 싱크(log / webhook / channel)로 발행합니다.
+-- 같은 안건의 이벤트는 앞 이벤트가 발행되기 전에는 뒤 이벤트를 발행하지 않습니다.
+CREATE TABLE outbox (
+    id BIGSERIAL PRIMARY KEY,          -- 기록 순서 (발행 순서)
+    agenda_id UUID NOT NULL,           -- 순서 보장 단위 (외래 키 없음: 이벤트는 기록으로 남음)
+    event_type TEXT NOT NULL,          -- agenda.created / vote.cast ...
+    payload JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_until TIMESTAMPTZ,          -- relay가 가져간 동안 / 발행 실패 후 재시도 전까지 다른 relay가 가져가지 않음
+    published_at TIMESTAMPTZ           -- NULL: 아직 발행되지 않음
+);
+
+CREATE INDEX idx_outbox_pending ON outbox(id) WHERE published_at IS NULL;
+CREATE INDEX idx_outbox_pending_agenda ON outbox(agenda_id, locked_until) WHERE published_at IS NULL;
+CREATE INDEX idx_outbox_published ON outbox(published_at) WHERE published_at IS NOT NULL;
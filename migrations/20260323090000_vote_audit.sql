@@ -0,0 +1,34 @@
+-- 투표 감사 로그: 안건별 해시 체인 (추가 전용)
+-- 투표/변경/철회마다 같은 트랜잭션에서 한 줄씩 추가하고, 각 줄의 hash에 직전 줄의 hash를 포함합니다.
+-- 해시는 애플리케이션이 계산합니다. (`src/api/agenda/audit.rs`)
+CREATE TYPE vote_audit_action AS ENUM ('cast', 'changed', 'retracted');
+
+CREATE TABLE vote_audit_log (
+    agenda_id UUID NOT NULL REFERENCES agendas(id),
+    seq BIGINT NOT NULL,                 -- 안건별 1부터 연속
+    action vote_audit_action NOT NULL,
+    vote_id UUID NOT NULL,               -- votes.id (외래 키 없음: 철회로 지워진 투표도 기록으로 남음)
+    user_id UUID NOT NULL,
+    choice TEXT,                         -- 선택 JSON 원문 (해시 대상 그대로 보관, retracted는 NULL)
+    created_at TIMESTAMPTZ NOT NULL,     -- 해시 대상 (마이크로초 단위)
+    prev_hash TEXT NOT NULL,             -- 직전 줄의 hash (첫 줄은 0 64개)
+    hash TEXT NOT NULL,                  -- SHA-256 hex
+
+    PRIMARY KEY (agenda_id, seq),
+    CONSTRAINT ck_audit_seq CHECK (seq >= 1)
+);
+
+-- 추가 전용: 수정/삭제는 트리거로 거부 (트리거를 끌 수 있는 권한으로 고쳐도 해시 체인 검증에서 드러남)
+CREATE FUNCTION reject_vote_audit_change() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'vote_audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_vote_audit_no_update_delete
+    BEFORE UPDATE OR DELETE ON vote_audit_log
+    FOR EACH ROW EXECUTE FUNCTION reject_vote_audit_change();
+
+CREATE TRIGGER trg_vote_audit_no_truncate
+    BEFORE TRUNCATE ON vote_audit_log
+    FOR EACH STATEMENT EXECUTE FUNCTION reject_vote_audit_change();
@@ -0,0 +1,24 @@
+-- 투표 영수증: 투표/변경마다 선택 + 서버 salt로 만든 커밋먼트를 발급
+-- 마감 시 집계된 영수증의 Merkle 루트를 receipt_roots에 공개합니다. (계산: `src/api/agenda/receipt.rs`)
+CREATE TYPE receipt_status AS ENUM ('counted', 'superseded', 'retracted');
+
+CREATE TABLE vote_receipts (
+    receipt TEXT PRIMARY KEY,            -- SHA-256 hex
+    agenda_id UUID NOT NULL REFERENCES agendas(id),
+    vote_id UUID NOT NULL,               -- votes.id (외래 키 없음: 철회된 투표의 영수증도 조회 가능)
+    salt BYTEA NOT NULL,                 -- 256비트 난수, 서버만 보관 (공개하면 선택을 대입해 맞춰 볼 수 있음)
+    status receipt_status NOT NULL DEFAULT 'counted', -- 변경되면 superseded, 철회되면 retracted
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- 투표 하나에 집계 중인 영수증은 하나
+CREATE UNIQUE INDEX uk_receipt_counted_vote ON vote_receipts(vote_id) WHERE status = 'counted';
+CREATE INDEX idx_receipt_counted_agenda ON vote_receipts(agenda_id) WHERE status = 'counted';
+
+-- 마감 때 한 번 기록 (이후 바뀌지 않음)
+CREATE TABLE receipt_roots (
+    agenda_id UUID PRIMARY KEY REFERENCES agendas(id),
+    root TEXT NOT NULL,                  -- SHA-256 hex
+    leaf_count INT NOT NULL,
+    published_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,34 @@
+-- 입력 정규화(username: 앞뒤 공백 제거 + NFKC, 비밀번호: NFKC) 이전에 가입한 계정 정리 (한 번만)
+-- 로그인은 정규화한 값으로만 확인하므로, 정규화 전 값으로 저장된 계정을 여기서 맞춰 둡니다.
+
+-- 1. username 정규화
+--    같은 이름이 이미 있거나 여러 계정이 같은 이름이 되면 가장 먼저 가입한 계정만 바꾸고 나머지는 그대로 둡니다.
+--    (남은 계정은 로그인할 수 없으므로 관리자가 직접 정리)
+--    Unicode 정규화는 UTF8 DB에서만 가능 (SQL_ASCII 등에서는 문자열을 바이트로만 다루므로 건너뜀)
+DO $$
+BEGIN
+    IF current_setting('server_encoding') = 'UTF8' THEN
+        WITH candidates AS (
+            SELECT DISTINCT ON (normalized) id, normalized
+            FROM (
+                SELECT id, created_at, btrim(normalize(username, NFKC), E' \t\n\r') AS normalized
+                FROM users
+                WHERE deleted_at IS NULL
+            ) u
+            WHERE normalized <> (SELECT username FROM users WHERE users.id = u.id)
+            ORDER BY normalized, created_at
+        )
+        UPDATE users
+        SET username = c.normalized
+        FROM candidates c
+        WHERE users.id = c.id
+          AND NOT EXISTS (SELECT 1 FROM users other WHERE other.username = c.normalized);
+    END IF;
+END $$;
+
+-- 2. 비밀번호는 해시만 있어 여기서 바꿀 수 없으므로 표시만 해 두고,
+--    다음 로그인에 입력 그대로 확인한 뒤 정규화한 값으로 다시 해시합니다. (`service::login`)
+ALTER TABLE users
+    ADD COLUMN legacy_password BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE users SET legacy_password = TRUE WHERE password_hash IS NOT NULL;
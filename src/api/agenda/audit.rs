@@ -0,0 +1,132 @@
+//! 투표 감사 로그 해시 체인 (DB와 무관한 순수 함수)
+//!
+//! 안건마다 투표/변경/철회를 seq 순서대로 한 줄씩 기록하고, 각 줄의 해시에 직전 줄의 해시를 넣어 체인으로 잇습니다.
+//! - 해시: 직전 hash, 안건 ID, seq, 동작, 투표 ID, 사용자 ID, 선택 JSON, 기록 시각을 `\n`으로 이은 문자열의 SHA-256 (hex)
+//! - 첫 줄의 직전 hash: `GENESIS_HASH`
+//!
+//! 중간 줄을 고치거나 빼거나 끼워 넣으면 그 뒤의 해시가 모두 달라지므로,
+//! 미리 공개해 둔 체인 머리(마지막 줄의 hash)와 비교하면 사후 조작이 드러납니다.
+//! 검증은 체인을 처음부터 다시 계산한 뒤 로그를 재생한 최종 상태가 votes와 같은지도 확인합니다.
+
+use std::collections::HashMap;
+use chrono::{SecondsFormat, SubsecRound, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use super::repository::{AuditAction, AuditEntry, AuditTrail, Choice};
+
+/// 첫 줄의 직전 hash
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 검증에서 찾은 첫 불일치
+/// - 체인 검사(seq 순) → votes 대조(사용자 ID 순) 순서로 확인해 처음 어긋난 곳 하나만 보고
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Inconsistency {
+    SequenceGap { seq: i64, expected: i64 }, // 줄이 빠지거나 끼워짐
+    BrokenLink { seq: i64 },                 // prev_hash가 직전 줄의 hash와 다름
+    HashMismatch { seq: i64 },               // 내용이 hash와 다름 (변조)
+    InvalidTransition { seq: i64 },          // 투표 없이 변경/철회, 중복 투표 등 있을 수 없는 순서
+    UnloggedVote { user_id: Uuid },          // votes에 있지만 로그에 없음 (사후 삽입)
+    AlteredVote { user_id: Uuid },           // votes의 선택/투표 ID가 로그의 마지막 기록과 다름 (사후 수정)
+    MissingVote { user_id: Uuid },           // 로그상 남아 있어야 할 투표가 votes에 없음 (사후 삭제)
+}
+
+/// 직전 줄(`previous`, 첫 줄이면 `None`) 뒤에 이어 붙일 새 줄
+pub fn link(
+    previous: Option<&AuditEntry>,
+    agenda_id: Uuid,
+    action: AuditAction,
+    vote_id: Uuid,
+    user_id: Uuid,
+    choice: Option<&Choice>,
+) -> AuditEntry {
+    let mut entry = AuditEntry {
+        agenda_id,
+        seq: previous.map_or(1, |p| p.seq + 1),
+        action,
+        vote_id,
+        user_id,
+        choice: choice.map(encode),
+        // DB에 저장되는 정밀도로 맞춰야 읽어 온 줄로 다시 계산한 해시가 같음
+        created_at: Utc::now().trunc_subsecs(6),
+        prev_hash: previous.map_or(GENESIS_HASH, |p| p.hash.as_str()).to_string(),
+        hash: String::new(),
+    };
+    entry.hash = hash(&entry);
+    entry
+}
+
+/// 줄의 내용(hash 제외)으로 계산한 해시
+pub fn hash(entry: &AuditEntry) -> String {
+    let content = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+        entry.prev_hash,
+        entry.agenda_id,
+        entry.seq,
+        entry.action.as_str(),
+        entry.vote_id,
+        entry.user_id,
+        entry.choice.as_deref().unwrap_or(""),
+        entry.created_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+    );
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// 체인을 처음부터 다시 계산하고, 로그를 재생한 결과를 현재 votes와 대조
+pub fn verify(trail: &AuditTrail) -> Result<(), Inconsistency> {
+    let mut previous: Option<&AuditEntry> = None;
+    let mut replayed: HashMap<Uuid, (Uuid, &str)> = HashMap::new(); // 사용자 → (투표 ID, 선택 JSON)
+
+    for entry in &trail.entries {
+        let seq = entry.seq;
+        let expected = previous.map_or(1, |p| p.seq + 1);
+        if seq != expected {
+            return Err(Inconsistency::SequenceGap { seq, expected });
+        }
+        if entry.prev_hash != previous.map_or(GENESIS_HASH, |p| p.hash.as_str()) {
+            return Err(Inconsistency::BrokenLink { seq });
+        }
+        if entry.hash != hash(entry) {
+            return Err(Inconsistency::HashMismatch { seq });
+        }
+
+        let current = replayed.get(&entry.user_id).map(|(vote_id, _)| *vote_id);
+        match (entry.action, current, entry.choice.as_deref()) {
+            (AuditAction::Cast, None, Some(choice)) => {
+                replayed.insert(entry.user_id, (entry.vote_id, choice));
+            }
+            (AuditAction::Changed, Some(vote_id), Some(choice)) if vote_id == entry.vote_id => {
+                replayed.insert(entry.user_id, (vote_id, choice));
+            }
+            (AuditAction::Retracted, Some(vote_id), None) if vote_id == entry.vote_id => {
+                replayed.remove(&entry.user_id);
+            }
+            _ => return Err(Inconsistency::InvalidTransition { seq }),
+        }
+
+        previous = Some(entry);
+    }
+
+    let mut votes: Vec<_> = trail.votes.iter().collect();
+    votes.sort_by_key(|v| v.user_id);
+    for vote in votes {
+        match replayed.remove(&vote.user_id) {
+            None => return Err(Inconsistency::UnloggedVote { user_id: vote.user_id }),
+            Some((vote_id, choice)) if vote_id != vote.id || choice != encode(&vote.choice) => {
+                return Err(Inconsistency::AlteredVote { user_id: vote.user_id });
+            }
+            Some(_) => {}
+        }
+    }
+
+    match replayed.into_keys().min() {
+        Some(user_id) => Err(Inconsistency::MissingVote { user_id }),
+        None => Ok(()),
+    }
+}
+
+/// 해시 대상이 되는 선택 JSON (serde 기본 직렬화, 공백 없음 - 투표 영수증도 같은 형식)
+pub fn encode(choice: &Choice) -> String {
+    serde_json::to_string(choice).expect("Choice serializes to JSON")
+}
@@ -0,0 +1,129 @@
+//! 가결 판정 (DB와 무관한 순수 함수)
+//!
+//! 1. 투표 참여 수(기권 포함)가 정족수(quorum)에 못 미치면 `no_quorum`
+//! 2. 최다 득표가 동률이면 동률 처리 규칙에 따라 `failed` 또는 `tied`
+//! 3. 최다 득표의 비율이 가결 규칙(과반 / 2/3 이상)을 넘으면 `passed`, 아니면 `failed`
+//!
+//! 비율의 분모는 찬/반 안건은 찬성 + 반대, 선택지 안건은 마지막 라운드의 총점입니다.
+//! (approval은 한 사람이 여러 선택지를 승인하므로 투표 용지 수를 분모로 사용)
+//! 기권은 정족수에만 포함되고 분모에는 들어가지 않습니다.
+
+use serde::Serialize;
+use uuid::Uuid;
+use super::repository::{DecisionRule, TiePolicy, VotingMethod};
+use super::tally::Tally;
+
+/// 안건의 판정 규칙
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+    pub rule: DecisionRule,
+    pub tie_policy: TiePolicy,
+    pub quorum: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Passed,
+    Failed,
+    Tied,
+    NoQuorum,
+}
+
+/// 판정 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    pub outcome: Outcome,
+    /// 최다 득표 - 차점 (찬/반 안건은 찬성 - 반대)
+    pub margin: i64,
+    /// 투표 참여 수 (기권 포함)
+    pub turnout: u64,
+    pub quorum_met: bool,
+    /// 가결된 선택지 (선택지 안건이 passed일 때만)
+    pub winner: Option<Uuid>,
+}
+
+/// 찬/반 안건 판정
+pub fn decide_motion(rules: &Rules, agree: u64, disagree: u64, abstain: u64) -> Decision {
+    let cast = agree + disagree;
+    let turnout = cast + abstain;
+    let quorum_met = quorum_met(rules, turnout);
+
+    let outcome = if !quorum_met {
+        Outcome::NoQuorum
+    } else if agree == disagree && cast > 0 {
+        on_tie(rules)
+    } else if meets_threshold(rules.rule, agree, cast) {
+        Outcome::Passed
+    } else {
+        Outcome::Failed
+    };
+
+    Decision {
+        outcome,
+        margin: agree as i64 - disagree as i64,
+        turnout,
+        quorum_met,
+        winner: None,
+    }
+}
+
+/// 선택지 안건 판정 (집계의 마지막 라운드 기준)
+pub fn decide_election(rules: &Rules, tally: &Tally, abstain: u64) -> Decision {
+    let turnout = tally.total_ballots + abstain;
+    let quorum_met = quorum_met(rules, turnout);
+
+    let mut scores: Vec<u64> = tally
+        .rounds
+        .last()
+        .map(|round| round.scores.iter().map(|s| s.score).collect())
+        .unwrap_or_default();
+    scores.sort_unstable_by(|a, b| b.cmp(a));
+    let top = scores.first().copied().unwrap_or(0);
+    let runner_up = scores.get(1).copied().unwrap_or(0);
+
+    let denominator = match tally.method {
+        VotingMethod::Approval => tally.total_ballots,
+        _ => scores.iter().sum(),
+    };
+
+    let outcome = if !quorum_met {
+        Outcome::NoQuorum
+    } else if tally.winners.len() > 1 {
+        on_tie(rules)
+    } else if !tally.winners.is_empty() && meets_threshold(rules.rule, top, denominator) {
+        Outcome::Passed
+    } else {
+        Outcome::Failed
+    };
+
+    Decision {
+        outcome,
+        margin: top as i64 - runner_up as i64,
+        turnout,
+        quorum_met,
+        winner: (outcome == Outcome::Passed).then(|| tally.winners[0]),
+    }
+}
+
+fn quorum_met(rules: &Rules, turnout: u64) -> bool {
+    rules.quorum.is_none_or(|quorum| turnout >= quorum)
+}
+
+fn on_tie(rules: &Rules) -> Outcome {
+    match rules.tie_policy {
+        TiePolicy::Fail => Outcome::Failed,
+        TiePolicy::Tie => Outcome::Tied,
+    }
+}
+
+/// `votes / total`이 가결 규칙을 넘는지 (표가 없으면 부결)
+fn meets_threshold(rule: DecisionRule, votes: u64, total: u64) -> bool {
+    if votes == 0 {
+        return false;
+    }
+    match rule {
+        DecisionRule::SimpleMajority => votes * 2 > total,
+        DecisionRule::TwoThirds => votes * 3 >= total * 2,
+    }
+}
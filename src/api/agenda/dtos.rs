@@ -1,16 +1,256 @@
 use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use crate::api::validation::{is_printable, Validate, Validator};
+use super::audit::Inconsistency;
+use super::decision::Outcome;
+use super::receipt::ProofStep;
+use super::repository::{AgendaStatus, CounterKind, DecisionRule, ReceiptStatus, TiePolicy, VotingMethod};
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default)]
 pub struct CreateAgendaRequest {
     pub title: String,
+    pub opens_at: Option<DateTime<Utc>>,  // 미래 시각이면 draft로 생성 후 스케줄러가 open
+    pub closes_at: Option<DateTime<Utc>>, // 지정 시 스케줄러가 closed로 전이
+    #[serde(default)]
+    pub options: Vec<String>,             // 다지선다 선택지 (비어 있으면 찬/반 안건)
+    #[serde(default)]
+    pub voting_method: VotingMethod,      // 선택지가 있을 때만 single_choice 외 방식 허용
+    #[serde(default)]
+    pub decision_rule: DecisionRule,
+    #[serde(default)]
+    pub tie_policy: TiePolicy,
+    pub quorum: Option<i32>,              // 최소 투표 참여 수
+    #[serde(default)]
+    pub hide_results: bool,               // 마감 전까지 집계 비공개
+}
+
+/// 안건 제목 / 선택지 라벨 최대 길이 (문자 수)
+const TITLE_MAX_LEN: usize = 200;
+const OPTION_LABEL_MAX_LEN: usize = 100;
+
+/// 다지선다 안건의 선택지 개수 범위
+const MIN_OPTIONS: usize = 2;
+const MAX_OPTIONS: usize = 10;
+
+// 제목/라벨: 앞뒤 공백 제거 + NFC, 제어 문자 금지
+// 선택지: 비어 있으면 찬/반 안건, 있으면 2~10개의 서로 다른 라벨 (정규화 후 비교)
+impl Validate for CreateAgendaRequest {
+    fn rules(&mut self, v: &mut Validator) {
+        v.text("title", &mut self.title)
+            .trim()
+            .nfc()
+            .length(1, TITLE_MAX_LEN)
+            .chars(is_printable, "printable characters");
+        v.list("options", &mut self.options)
+            .count(MIN_OPTIONS, MAX_OPTIONS)
+            .each(|label| {
+                label
+                    .trim()
+                    .nfc()
+                    .length(1, OPTION_LABEL_MAX_LEN)
+                    .chars(is_printable, "printable characters")
+            })
+            .unique();
+    }
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Debug)]
 pub struct AgendaResponse {
     pub id: uuid::Uuid,
     pub title: String,
     pub created_by: String,
     pub created_at: chrono::DateTime<chrono::Utc>,
-    pub agree_count: i32,
-    pub disagree_count: i32,
+    pub agree_count: Option<i32>,    // 집계 비공개 중이면 null
+    pub disagree_count: Option<i32>,
+    pub abstain_count: Option<i32>,
+    pub status: AgendaStatus,
+    pub opens_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub options: Vec<AgendaOptionResponse>, // 찬/반 안건이면 빈 배열
+    pub voting_method: VotingMethod,
+    pub decision_rule: DecisionRule,
+    pub tie_policy: TiePolicy,
+    pub quorum: Option<i32>,
+    pub hide_results: bool,
+}
+
+// [Response] 선택지별 득표
+#[derive(Serialize, Debug)]
+pub struct AgendaOptionResponse {
+    pub id: uuid::Uuid,
+    pub label: String,
+    pub vote_count: Option<i32>, // 집계 비공개 중이면 null
+}
+
+// [Event] 실시간 집계 (GET /agendas/:id/stream, /agendas/:id/ws)
+// 매번 전체 집계를 보내므로 놓친 이벤트가 있어도 가장 최근 것 하나로 따라잡음
+#[derive(Serialize, Debug)]
+pub struct TallyUpdate {
+    pub seq: u64, // 서버 프로세스 안에서 단조 증가 (재연결 시 last_seq로 전달)
+    pub agenda_id: uuid::Uuid,
+    pub status: AgendaStatus,
+    pub agree_count: Option<i32>, // 집계 비공개 중이면 null
+    pub disagree_count: Option<i32>,
+    pub abstain_count: Option<i32>,
+    pub options: Vec<AgendaOptionResponse>,
+}
+
+// [Query] 실시간 집계 구독 (GET /agendas/:id/stream?last_seq=...)
+#[derive(Deserialize)]
+pub struct StreamQuery {
+    pub last_seq: Option<u64>, // 마지막으로 받은 seq (SSE는 Last-Event-ID 헤더도 가능)
+}
+
+// [Query] 목록 조회 (GET /agendas?cursor=...&limit=...)
+#[derive(Deserialize)]
+pub struct ListAgendasQuery {
+    pub cursor: Option<String>, // 직전 응답의 next_cursor (없으면 첫 페이지)
+    pub limit: Option<i64>,
+}
+
+// [Response] 목록 조회 - next_cursor가 None이면 마지막 페이지
+#[derive(Serialize, Debug)]
+pub struct AgendaListResponse {
+    pub items: Vec<AgendaResponse>,
+    pub next_cursor: Option<String>,
+}
+
+// [Request] 투표 - 안건 종류에 맞는 필드 하나만
+// - 찬/반 안건: is_agree
+// - single_choice: option_id
+// - approval: option_ids (승인할 선택지들), instant_runoff/borda: option_ids (선호 순서대로)
+// - 기권: abstain: true (모든 안건)
+#[derive(Deserialize, Default)]
+pub struct VoteRequest {
+    pub is_agree: Option<bool>,
+    pub option_id: Option<uuid::Uuid>,
+    pub option_ids: Option<Vec<uuid::Uuid>>,
+    #[serde(default)]
+    pub abstain: bool,
+}
+
+#[derive(Serialize, Debug)]
+pub struct VoteResponse {
+    pub agenda_id: uuid::Uuid,
+    pub is_agree: Option<bool>,
+    pub option_id: Option<uuid::Uuid>,
+    pub option_ids: Option<Vec<uuid::Uuid>>,
+    pub abstain: bool,
+    pub agree_count: Option<i32>, // 집계 비공개 중이면 null
+    pub disagree_count: Option<i32>,
+    pub abstain_count: Option<i32>,
+    pub options: Vec<AgendaOptionResponse>, // 다지선다 안건의 갱신된 득표
+    pub receipt: String,                    // 투표 영수증 (누구나 GET /agendas/:id/receipts/:receipt로 확인)
+}
+
+// [Response] 선택지 투표 집계 (GET /agendas/:id/tally)
+#[derive(Serialize, Debug)]
+pub struct TallyResponse {
+    pub agenda_id: uuid::Uuid,
+    pub voting_method: VotingMethod,
+    pub total_ballots: u64,
+    pub rounds: Vec<TallyRoundResponse>, // instant_runoff 외에는 한 라운드
+    pub winners: Vec<uuid::Uuid>,        // 동률이면 여러 개
+}
+
+#[derive(Serialize, Debug)]
+pub struct TallyRoundResponse {
+    pub scores: Vec<TallyScoreResponse>,
+    pub eliminated: Vec<uuid::Uuid>,
+    pub exhausted: u64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TallyScoreResponse {
+    pub option_id: uuid::Uuid,
+    pub label: String,
+    pub score: u64,
+}
+
+// [Response] 가결 판정 (GET /agendas/:id/result)
+#[derive(Serialize, Debug)]
+pub struct ResultResponse {
+    pub agenda_id: uuid::Uuid,
+    pub status: AgendaStatus,
+    pub is_final: bool,              // 마감 전이면 현재 시점의 잠정 결과
+    pub outcome: Outcome,
+    pub margin: i64,                 // 최다 득표 - 차점 (찬/반 안건은 찬성 - 반대)
+    pub turnout: u64,                // 기권 포함
+    pub quorum: Option<i32>,
+    pub quorum_met: bool,
+    pub decision_rule: DecisionRule,
+    pub winner: Option<uuid::Uuid>,  // 가결된 선택지 (선택지 안건)
+}
+
+// [Query] 카운터 점검 (POST /agendas/reconcile?repair=true)
+#[derive(Deserialize, Default)]
+pub struct ReconcileQuery {
+    #[serde(default)]
+    pub repair: bool, // false면 보고만 (dry run)
+}
+
+// [Response] 카운터 점검 보고서
+#[derive(Serialize, Debug)]
+pub struct ReconcileReport {
+    pub checked_at: DateTime<Utc>,
+    pub repaired: bool,                   // true면 drifts는 실제로 고친 내역
+    pub agendas_affected: usize,
+    pub drifts: Vec<CounterDriftResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct CounterDriftResponse {
+    pub agenda_id: uuid::Uuid,
+    pub option_id: Option<uuid::Uuid>,
+    pub counter: CounterKind,
+    pub cached: i32,  // 캐싱 컬럼 값 (복구 전)
+    pub actual: i64,  // votes 기준 재계산 값
+}
+
+// [Response] 감사 로그 체인 머리 (GET /agendas/:id/audit/head)
+#[derive(Serialize, Debug)]
+pub struct AuditHeadResponse {
+    pub agenda_id: uuid::Uuid,
+    pub seq: i64,                           // 기록된 줄 수 (없으면 0)
+    pub hash: String,                       // 마지막 줄의 hash (없으면 0 64개)
+    pub recorded_at: Option<DateTime<Utc>>, // 마지막 줄의 기록 시각
+}
+
+// [Response] 감사 로그 검증 보고서 (GET /agendas/:id/audit/verify)
+#[derive(Serialize, Debug)]
+pub struct AuditReport {
+    pub verified_at: DateTime<Utc>,
+    pub valid: bool,
+    pub entries: usize,                       // 감사 로그 줄 수
+    pub votes: usize,                         // 현재 votes 수
+    pub head: AuditHeadResponse,              // 공개해 둔 값과 비교할 체인 머리
+    pub inconsistency: Option<Inconsistency>, // 처음 어긋난 곳 (valid면 null)
+}
+
+// [Response] 투표 영수증 확인 (GET /agendas/:id/receipts/:receipt)
+#[derive(Serialize, Debug)]
+pub struct ReceiptResponse {
+    pub agenda_id: uuid::Uuid,
+    pub receipt: String,
+    pub status: ReceiptStatus,               // counted / superseded / retracted
+    pub recorded_at: DateTime<Utc>,
+    pub inclusion: Option<InclusionProof>,   // 마감 후 집계된 영수증이면 Merkle 포함 증명
+}
+
+#[derive(Serialize, Debug)]
+pub struct InclusionProof {
+    pub root: String,
+    pub leaf_count: i32,
+    pub leaf_index: usize,  // 정렬된 잎 중 위치
+    pub proof: Vec<ProofStep>, // 잎 → 루트 순서의 형제 노드
+}
+
+// [Response] 마감 때 공개한 영수증 Merkle 루트 (GET /agendas/:id/receipts)
+#[derive(Serialize, Debug)]
+pub struct ReceiptRootResponse {
+    pub agenda_id: uuid::Uuid,
+    pub root: String,
+    pub leaf_count: i32,
+    pub published_at: DateTime<Utc>,
+    pub receipts: Vec<String>, // 정렬된 잎 (오프라인에서 트리를 다시 만들 때)
 }
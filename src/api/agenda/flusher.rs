@@ -0,0 +1,41 @@
+//! 카운터 쓰기 지연 반영 작업
+//!
+//! 투표가 버퍼(`CounterBuffer`)에 모아 둔 카운터 증감을 `period` 마다,
+//! 또는 `batch_size`만큼 쌓이면 바로 DB의 캐싱 컬럼에 반영합니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use super::repository::{counter::CounterBuffer, traits::AgendaRepository};
+use crate::api::state::Agendas;
+
+/// 백그라운드 반영 작업 시작
+pub fn spawn(repo: Agendas, counters: Arc<CounterBuffer>, period: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = counters.filled() => ticker.reset(),
+            }
+            run_once(repo.as_ref()).await;
+        }
+    })
+}
+
+/// 반영 1회 실행 - 실패한 증감은 버퍼에 남아 다음 주기에 다시 시도하므로 로그만 남깁니다.
+pub async fn run_once<R: AgendaRepository + ?Sized>(repo: &R) {
+    if let Err(e) = repo.flush_counters().await {
+        eprintln!("❌ Counter flush failed: {}", e);
+    }
+}
+
+/// 종료 직전 남은 증감 반영 (서버가 요청을 더 받지 않는 시점에 호출)
+pub async fn flush_remaining<R: AgendaRepository + ?Sized>(repo: &R) {
+    match repo.flush_counters().await {
+        Ok(flushed) => println!("💾 Flushed pending counters of {} agendas", flushed),
+        Err(e) => eprintln!("❌ Counter flush on shutdown failed: {}", e),
+    }
+}
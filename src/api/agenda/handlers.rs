@@ -1,26 +1,373 @@
-use axum::{Json, extract::State};
-use sqlx::PgPool;
+use std::sync::Arc;
+use axum::{
+    extract::{ws::{Message, WebSocket, WebSocketUpgrade}, Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{sse::{Event, KeepAlive, Sse}, IntoResponse, Response},
+};
+use futures_util::{stream, Stream};
+use uuid::Uuid;
 use crate::api::auth::jwt::Claims;
+use crate::api::auth::role::{Admin, Moderator, RequireRole};
 use crate::api::error::AppError;
-use super::dtos::{CreateAgendaRequest, AgendaResponse};
-use super::repository::PgAgendaRepository;
+use crate::api::extract::{Json, Query};
+use crate::api::state::Agendas;
+use super::dtos::{
+    CreateAgendaRequest, AgendaResponse, AgendaListResponse, AuditHeadResponse, AuditReport, ListAgendasQuery, ReceiptResponse, ReceiptRootResponse, ReconcileQuery, ReconcileReport, ResultResponse, StreamQuery, TallyResponse, VoteRequest, VoteResponse,
+};
+use super::live::{LiveTally, Subscription};
+use super::receipt::is_receipt;
+use super::repository::{traits::AgendaRepository, AgendaStatus};
 use super::service;
 
 /// 안건 생성 핸들러
 ///
 /// - `claims`: JWT 토큰에서 추출한 사용자 정보 (인증 필수)
 pub async fn create_agenda(
-    State(pool): State<PgPool>,
+    State(repo): State<Agendas>,
     claims: Claims, // JWT 인증 (Authorization header required)
     Json(payload): Json<CreateAgendaRequest>,
 ) -> Result<Json<AgendaResponse>, AppError> {
-    let repo = PgAgendaRepository::new(&pool);
-    
     // Claims의 sub(subject)는 user_id (String) 이므로 Uuid로 파싱 필요
-    let user_id = uuid::Uuid::parse_str(&claims.sub)
-        .map_err(|_| AppError::Unauthorized("Invalid token user_id".to_string()))?;
+    let user_id = claims.user_id()?;
 
-    let response = service::create_agenda(&repo, payload.title, user_id).await?;
+    let response = service::create_agenda(repo.as_ref(), payload, user_id).await?;
     
     Ok(Json(response))
 }
+
+/// 안건 단건 조회 핸들러 (GET /agendas/:id)
+pub async fn get_agenda(
+    State(repo): State<Agendas>,
+    Path(id): Path<String>,
+) -> Result<Json<AgendaResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let response = service::get_agenda(repo.as_ref(), agenda_id).await?;
+
+    Ok(Json(response))
+}
+
+/// 선택지 투표 집계 핸들러 (GET /agendas/:id/tally)
+/// - 투표 방식에 따른 라운드별 집계와 당선 선택지
+pub async fn tally_agenda(
+    State(repo): State<Agendas>,
+    Path(id): Path<String>,
+) -> Result<Json<TallyResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let response = service::tally_agenda(repo.as_ref(), agenda_id).await?;
+
+    Ok(Json(response))
+}
+
+/// 가결 판정 핸들러 (GET /agendas/:id/result)
+/// - 가결 여부, 득표 차, 정족수 충족 여부 (마감 전이면 잠정 결과)
+pub async fn get_result(
+    State(repo): State<Agendas>,
+    Path(id): Path<String>,
+) -> Result<Json<ResultResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let response = service::get_result(repo.as_ref(), agenda_id).await?;
+
+    Ok(Json(response))
+}
+
+/// 안건 목록 조회 핸들러 (GET /agendas?cursor=...&limit=...)
+pub async fn list_agendas(
+    State(repo): State<Agendas>,
+    Query(query): Query<ListAgendasQuery>,
+) -> Result<Json<AgendaListResponse>, AppError> {
+    let response = service::list_agendas(repo.as_ref(), query.cursor, query.limit).await?;
+
+    Ok(Json(response))
+}
+
+/// 내가 만든 안건 목록 조회 핸들러 (GET /agendas/me)
+///
+/// - `claims`: 조회 대상 사용자 (인증 필수)
+pub async fn list_my_agendas(
+    State(repo): State<Agendas>,
+    claims: Claims,
+    Query(query): Query<ListAgendasQuery>,
+) -> Result<Json<AgendaListResponse>, AppError> {
+    let user_id = claims.user_id()?;
+    let response = service::list_my_agendas(repo.as_ref(), user_id, query.cursor, query.limit).await?;
+
+    Ok(Json(response))
+}
+
+/// 안건 시작 핸들러 (POST /agendas/:id/open, draft → open)
+pub async fn open_agenda(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    claims: Claims,
+) -> Result<Json<AgendaResponse>, AppError> {
+    change_status(repo.as_ref(), &live, id, claims, AgendaStatus::Open).await
+}
+
+/// 안건 마감 핸들러 (POST /agendas/:id/close, open → closed)
+pub async fn close_agenda(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    claims: Claims,
+) -> Result<Json<AgendaResponse>, AppError> {
+    change_status(repo.as_ref(), &live, id, claims, AgendaStatus::Closed).await
+}
+
+/// 안건 취소 핸들러 (POST /agendas/:id/cancel, draft/open → cancelled)
+pub async fn cancel_agenda(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    claims: Claims,
+) -> Result<Json<AgendaResponse>, AppError> {
+    change_status(repo.as_ref(), &live, id, claims, AgendaStatus::Cancelled).await
+}
+
+/// 관리자 마감 핸들러 (POST /agendas/:id/force-close, 관리자 전용)
+/// - 생성자가 아니어도 진행 중인 안건을 마감
+pub async fn force_close_agenda(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    _admin: RequireRole<Admin>,
+) -> Result<Json<AgendaResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let response = service::moderate_status(repo.as_ref(), agenda_id, AgendaStatus::Closed).await?;
+    live.mark(agenda_id);
+
+    Ok(Json(response))
+}
+
+/// 운영자 취소 핸들러 (POST /agendas/:id/force-cancel, 운영자 이상)
+/// - 부적절한 안건을 생성자 대신 취소
+pub async fn force_cancel_agenda(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    _moderator: RequireRole<Moderator>,
+) -> Result<Json<AgendaResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let response = service::moderate_status(repo.as_ref(), agenda_id, AgendaStatus::Cancelled).await?;
+    live.mark(agenda_id);
+
+    Ok(Json(response))
+}
+
+/// open/close/cancel 공통 처리 (생성자만 가능)
+/// - 실시간 집계 구독자도 바뀐 상태를 받도록 표시 (마감되면 `hide_results` 안건의 최종 집계 공개)
+async fn change_status(
+    repo: &dyn AgendaRepository,
+    live: &LiveTally,
+    id: String,
+    claims: Claims,
+    to: AgendaStatus,
+) -> Result<Json<AgendaResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let user_id = claims.user_id()?;
+
+    let response = service::change_status(repo, agenda_id, user_id, to).await?;
+    live.mark(agenda_id);
+
+    Ok(Json(response))
+}
+
+/// 투표 핸들러
+///
+/// - `id`: 경로 파라미터 (/agendas/:id/vote)
+/// - `claims`: 투표자 (인증 필수)
+/// - 성공: 201 Created + 갱신된 집계
+pub async fn vote(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    claims: Claims,
+    Json(payload): Json<VoteRequest>,
+) -> Result<(StatusCode, Json<VoteResponse>), AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let user_id = claims.user_id()?;
+
+    let response = service::vote(repo.as_ref(), agenda_id, user_id, payload).await?;
+    live.mark(agenda_id);
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 투표 변경 핸들러
+///
+/// - 기존 투표의 선택을 바꿉니다 (PUT /agendas/:id/vote)
+pub async fn change_vote(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    claims: Claims,
+    Json(payload): Json<VoteRequest>,
+) -> Result<Json<VoteResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let user_id = claims.user_id()?;
+
+    let response = service::change_vote(repo.as_ref(), agenda_id, user_id, payload).await?;
+    live.mark(agenda_id);
+
+    Ok(Json(response))
+}
+
+/// 투표 철회 핸들러
+///
+/// - 성공: 204 No Content (DELETE /agendas/:id/vote)
+pub async fn retract_vote(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    claims: Claims,
+) -> Result<StatusCode, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let user_id = claims.user_id()?;
+
+    service::retract_vote(repo.as_ref(), agenda_id, user_id).await?;
+    live.mark(agenda_id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 실시간 집계 SSE 핸들러 (GET /agendas/:id/stream)
+///
+/// - 연결 직후 최근 집계를 보내고, 이후 투표가 있을 때마다 `tally` 이벤트 전송 (id: seq)
+/// - 재연결: `Last-Event-ID` 헤더 또는 `?last_seq=` (최근 집계와 같으면 생략)
+pub async fn stream_tally(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    Query(query): Query<StreamQuery>,
+    headers: HeaderMap,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok());
+
+    let subscription = live.subscribe(repo.as_ref(), agenda_id, last_event_id.or(query.last_seq)).await?;
+    let events = stream::unfold(subscription, |mut subscription| async move {
+        let update = subscription.next().await?;
+        let event = Event::default().event("tally").id(update.seq.to_string()).json_data(&*update);
+        Some((event, subscription))
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// 실시간 집계 WebSocket 핸들러 (GET /agendas/:id/ws)
+///
+/// - SSE와 같은 업데이트를 JSON 텍스트 메시지로 전송 (재연결: `?last_seq=`)
+/// - 클라이언트가 보내는 메시지는 무시하고, Close를 받으면 종료
+pub async fn stream_tally_ws(
+    State(repo): State<Agendas>,
+    State(live): State<Arc<LiveTally>>,
+    Path(id): Path<String>,
+    Query(query): Query<StreamQuery>,
+    upgrade: WebSocketUpgrade,
+) -> Result<Response, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let subscription = live.subscribe(repo.as_ref(), agenda_id, query.last_seq).await?;
+
+    Ok(upgrade.on_upgrade(move |socket| forward_updates(socket, subscription)).into_response())
+}
+
+async fn forward_updates(mut socket: WebSocket, mut subscription: Subscription) {
+    loop {
+        tokio::select! {
+            update = subscription.next() => {
+                let Some(update) = update else { break };
+                let Ok(text) = serde_json::to_string(&*update) else { break };
+                if socket.send(Message::Text(text)).await.is_err() {
+                    break;
+                }
+            }
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {} // Ping에는 axum이 Pong으로 응답
+            },
+        }
+    }
+}
+
+/// 캐싱 카운터 점검 핸들러 (POST /agendas/reconcile?repair=true)
+///
+/// - votes를 다시 세어 agree/disagree/abstain_count, 선택지 vote_count와 비교
+/// - `repair=true`면 어긋난 카운터를 복구하고 고친 내역을 반환
+///   (이 서버의 버퍼만 반영하므로 서버가 여러 대면 다른 서버의 버퍼가 비었을 때 실행)
+/// - 관리자 전용
+pub async fn reconcile_counters(
+    State(repo): State<Agendas>,
+    _admin: RequireRole<Admin>,
+    Query(query): Query<ReconcileQuery>,
+) -> Result<Json<ReconcileReport>, AppError> {
+    let report = service::reconcile_counters(repo.as_ref(), query.repair).await?;
+
+    Ok(Json(report))
+}
+
+/// 감사 로그 체인 머리 핸들러 (GET /agendas/:id/audit/head)
+/// - 누구나 조회 (외부에 공개해 두었다가 나중에 검증 결과의 head와 비교하는 용도)
+pub async fn get_audit_head(
+    State(repo): State<Agendas>,
+    Path(id): Path<String>,
+) -> Result<Json<AuditHeadResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let response = service::audit_head(repo.as_ref(), agenda_id).await?;
+
+    Ok(Json(response))
+}
+
+/// 감사 로그 검증 핸들러 (GET /agendas/:id/audit/verify, 운영자 이상)
+/// - 체인을 다시 계산하고 votes와 대조해 처음 어긋난 곳을 보고
+pub async fn verify_audit(
+    State(repo): State<Agendas>,
+    Path(id): Path<String>,
+    _moderator: RequireRole<Moderator>,
+) -> Result<Json<AuditReport>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let report = service::verify_audit(repo.as_ref(), agenda_id).await?;
+
+    Ok(Json(report))
+}
+
+/// 투표 영수증 확인 핸들러 (GET /agendas/:id/receipts/:receipt)
+/// - 누구나 조회 (선택/투표자는 드러나지 않음), 마감 후에는 Merkle 포함 증명 포함
+pub async fn get_receipt(
+    State(repo): State<Agendas>,
+    Path((id, receipt)): Path<(String, String)>,
+) -> Result<Json<ReceiptResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let receipt = parse_receipt(&receipt)?;
+    let response = service::get_receipt(repo.as_ref(), agenda_id, &receipt).await?;
+
+    Ok(Json(response))
+}
+
+/// 영수증 Merkle 루트 핸들러 (GET /agendas/:id/receipts)
+/// - 마감 때 공개한 루트와 정렬된 영수증 전체 (오프라인 검증용)
+pub async fn get_receipt_root(
+    State(repo): State<Agendas>,
+    Path(id): Path<String>,
+) -> Result<Json<ReceiptRootResponse>, AppError> {
+    let agenda_id = parse_agenda_id(&id)?;
+    let response = service::get_receipt_root(repo.as_ref(), agenda_id).await?;
+
+    Ok(Json(response))
+}
+
+/// 경로의 안건 ID 파싱 (UUID 형식이 아니면 400)
+fn parse_agenda_id(id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(id).map_err(|_| AppError::BadRequest("Invalid agenda id".to_string(), vec![]))
+}
+
+/// 경로의 영수증 파싱 (hex 64자, 대문자도 허용)
+fn parse_receipt(receipt: &str) -> Result<String, AppError> {
+    let receipt = receipt.to_ascii_lowercase();
+    if !is_receipt(&receipt) {
+        return Err(AppError::BadRequest("Invalid receipt".to_string(), vec![]));
+    }
+    Ok(receipt)
+}
@@ -0,0 +1,195 @@
+//! 실시간 집계 전송 (SSE / WebSocket 공통)
+//!
+//! 투표/변경/철회와 상태 전이(시작/마감/취소, 관리자 마감/취소, 예약 전이)가 성공하면 안건을 `mark`만 해 두고,
+//! 백그라운드 작업이 `period` 마다 표시된 안건의 집계를 한 번 읽어 구독자에게 보냅니다.
+//! - 한 주기 안의 투표는 업데이트 하나로 합쳐지므로 투표가 몰려도 클라이언트로 가는 양은 일정합니다.
+//! - 업데이트는 전체 집계라서, 재연결한 클라이언트에는 가장 최근 업데이트 하나만 보내면 됩니다.
+//!   (`last_seq`가 최근 업데이트와 같으면 생략)
+//! - 구독자가 모두 나간 채널도 `IDLE_RETENTION` 동안은 최근 업데이트를 남겨 재연결에 쓰고, 그 뒤에 정리합니다.
+
+use std::collections::{hash_map::Entry, HashMap};
+use std::sync::{atomic::{AtomicU64, Ordering}, Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tokio::{sync::broadcast, task::JoinHandle, time::MissedTickBehavior};
+use uuid::Uuid;
+use crate::api::error::AppError;
+use crate::api::state::Agendas;
+use super::dtos::{AgendaResponse, TallyUpdate};
+use super::repository::traits::AgendaRepository;
+use super::service;
+
+/// 구독자별 수신 대기열 크기 (밀리면 건너뛰고 다음 업데이트부터 받음)
+const CHANNEL_CAPACITY: usize = 16;
+
+/// 구독자가 없는 채널을 남겨 두는 시간
+const IDLE_RETENTION: Duration = Duration::from_secs(5 * 60);
+
+/// 안건별 broadcast 채널 모음 (AppState가 `Arc`로 공유)
+#[derive(Default)]
+pub struct LiveTally {
+    channels: Mutex<HashMap<Uuid, Channel>>,
+    seq: AtomicU64,
+}
+
+struct Channel {
+    sender: broadcast::Sender<Arc<TallyUpdate>>,
+    latest: Arc<TallyUpdate>,
+    dirty: bool,                 // 마지막 업데이트 이후 투표가 있었음
+    idle_since: Option<Instant>, // 구독자가 모두 나간 시각
+}
+
+impl Channel {
+    fn new(latest: TallyUpdate) -> Self {
+        Self {
+            sender: broadcast::channel(CHANNEL_CAPACITY).0,
+            latest: Arc::new(latest),
+            dirty: false,
+            idle_since: None,
+        }
+    }
+
+    fn replace(&mut self, latest: TallyUpdate) {
+        self.latest = Arc::new(latest);
+        let _ = self.sender.send(self.latest.clone()); // 구독자가 없으면 실패 (무시)
+    }
+}
+
+/// 한 클라이언트의 구독
+pub struct Subscription {
+    pending: Option<Arc<TallyUpdate>>, // 연결 직후 보낼 최근 업데이트
+    receiver: broadcast::Receiver<Arc<TallyUpdate>>,
+    last_seq: Option<u64>,
+}
+
+impl LiveTally {
+    /// 집계나 상태가 바뀐 안건 표시 (채널이 없으면 무시)
+    pub fn mark(&self, agenda_id: Uuid) {
+        if let Some(channel) = self.lock().get_mut(&agenda_id) {
+            channel.dirty = true;
+        }
+    }
+
+    /// 구독 시작
+    ///
+    /// - 없는 안건 → 404 NotFound
+    /// - `last_seq`: 클라이언트가 마지막으로 받은 seq (최근 업데이트와 같으면 다시 보내지 않음)
+    pub async fn subscribe<R: AgendaRepository + ?Sized>(&self, repo: &R, agenda_id: Uuid, last_seq: Option<u64>) -> Result<Subscription, AppError> {
+        let agenda = service::get_agenda(repo, agenda_id).await?;
+
+        let mut channels = self.lock();
+        let channel = match channels.entry(agenda_id) {
+            Entry::Occupied(entry) => {
+                // 구독자가 없던 동안의 투표는 아직 보내지 않았으므로 지금 읽은 집계로 교체
+                // (표시는 남겨 두어, 읽은 뒤에 들어온 투표도 다음 주기에 전송)
+                let channel = entry.into_mut();
+                if channel.dirty {
+                    channel.replace(self.update(agenda));
+                }
+                channel
+            }
+            Entry::Vacant(entry) => entry.insert(Channel::new(self.update(agenda))),
+        };
+        channel.idle_since = None;
+
+        let latest = channel.latest.clone();
+        Ok(Subscription {
+            pending: (last_seq != Some(latest.seq)).then_some(latest),
+            receiver: channel.sender.subscribe(),
+            last_seq,
+        })
+    }
+
+    /// 표시된 안건의 집계를 읽어 구독자에게 전송, 오래 비어 있던 채널 정리
+    /// - 구독자가 없는 채널은 읽지 않고 표시만 남김 (다음 구독 때 읽음)
+    /// - 반환: 보낸 업데이트 수
+    pub async fn publish<R: AgendaRepository + ?Sized>(&self, repo: &R) -> usize {
+        let now = Instant::now();
+        let dirty: Vec<Uuid> = {
+            let mut channels = self.lock();
+            channels.retain(|_, channel| {
+                if channel.sender.receiver_count() > 0 {
+                    channel.idle_since = None;
+                    return true;
+                }
+                let idle_since = *channel.idle_since.get_or_insert(now);
+                now.duration_since(idle_since) < IDLE_RETENTION
+            });
+            channels
+                .iter_mut()
+                .filter(|(_, channel)| channel.dirty && channel.idle_since.is_none())
+                .map(|(id, channel)| {
+                    channel.dirty = false;
+                    *id
+                })
+                .collect()
+        };
+
+        let mut sent = 0;
+        for agenda_id in dirty {
+            let agenda = match service::get_agenda(repo, agenda_id).await {
+                Ok(agenda) => agenda,
+                Err(e) => {
+                    eprintln!("❌ Live tally load failed for {}: {:?}", agenda_id, e);
+                    continue;
+                }
+            };
+
+            if let Some(channel) = self.lock().get_mut(&agenda_id) {
+                channel.replace(self.update(agenda));
+                sent += 1;
+            }
+        }
+        sent
+    }
+
+    fn update(&self, agenda: AgendaResponse) -> TallyUpdate {
+        TallyUpdate {
+            seq: self.seq.fetch_add(1, Ordering::Relaxed) + 1,
+            agenda_id: agenda.id,
+            status: agenda.status,
+            agree_count: agenda.agree_count,
+            disagree_count: agenda.disagree_count,
+            abstain_count: agenda.abstain_count,
+            options: agenda.options,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<Uuid, Channel>> {
+        self.channels.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Subscription {
+    /// 다음 업데이트 (서버가 채널을 닫으면 None)
+    /// - 대기열이 밀려 놓친 업데이트는 건너뜀 (다음 업데이트가 전체 집계를 담고 있음)
+    pub async fn next(&mut self) -> Option<Arc<TallyUpdate>> {
+        if let Some(update) = self.pending.take() {
+            self.last_seq = Some(update.seq);
+            return Some(update);
+        }
+
+        loop {
+            match self.receiver.recv().await {
+                Ok(update) if Some(update.seq) > self.last_seq => {
+                    self.last_seq = Some(update.seq);
+                    return Some(update);
+                }
+                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+/// 백그라운드 전송 작업 시작
+pub fn spawn(repo: Agendas, live: Arc<LiveTally>, period: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            live.publish(repo.as_ref()).await;
+        }
+    })
+}
@@ -1,17 +1,52 @@
 use axum::Router;
-use sqlx::PgPool;
+use crate::api::state::AppState;
 
+mod decision;
 mod dtos;
 mod handlers;
-mod repository;
+pub mod repository;
 mod service;
+mod tally;
+pub mod scheduler;
+pub mod reconciler;
+pub mod flusher;
+pub mod live;
+pub mod audit; // 메모리 저장소도 같은 해시 체인을 씀
+pub mod receipt;
 #[cfg(test)]
-mod test;
+pub mod test;
 
-pub fn router() -> Router<PgPool> {
-    use axum::routing::post;
-    use handlers::create_agenda;
+pub use repository::counter::CounterBuffer;
+pub use live::LiveTally;
+pub use service::get_result; // 웹훅 `agenda.decided`
+
+pub fn router() -> Router<AppState> {
+    use axum::routing::{get, post};
+    use handlers::{
+        create_agenda, get_agenda, list_agendas, list_my_agendas,
+        open_agenda, close_agenda, cancel_agenda, force_close_agenda, force_cancel_agenda,
+        vote, change_vote, retract_vote, tally_agenda, get_result,
+        stream_tally, stream_tally_ws,
+        reconcile_counters, get_audit_head, verify_audit, get_receipt, get_receipt_root,
+    };
 
     Router::new()
-        .route("/", post(create_agenda))
+        .route("/", post(create_agenda).get(list_agendas))
+        .route("/me", get(list_my_agendas))
+        .route("/reconcile", post(reconcile_counters))
+        .route("/:id", get(get_agenda))
+        .route("/:id/open", post(open_agenda))
+        .route("/:id/close", post(close_agenda))
+        .route("/:id/cancel", post(cancel_agenda))
+        .route("/:id/force-close", post(force_close_agenda))
+        .route("/:id/force-cancel", post(force_cancel_agenda))
+        .route("/:id/vote", post(vote).put(change_vote).delete(retract_vote))
+        .route("/:id/tally", get(tally_agenda))
+        .route("/:id/result", get(get_result))
+        .route("/:id/stream", get(stream_tally))
+        .route("/:id/ws", get(stream_tally_ws))
+        .route("/:id/audit/head", get(get_audit_head))
+        .route("/:id/audit/verify", get(verify_audit))
+        .route("/:id/receipts", get(get_receipt_root))
+        .route("/:id/receipts/:receipt", get(get_receipt))
 }
@@ -0,0 +1,150 @@
+//! 투표 영수증 커밋먼트와 Merkle 트리 (DB와 무관한 순수 함수)
+//!
+//! - 영수증: salt(hex), 안건 ID, 투표 ID, 선택 JSON을 `\n`으로 이은 문자열의 SHA-256 (hex)
+//!   salt(256비트 난수)는 서버만 보관하므로 영수증만으로는 선택을 대입해 맞춰 볼 수 없습니다.
+//! - 마감 시 집계된 영수증을 오름차순으로 정렬해 Merkle 트리를 만들고 루트를 공개합니다.
+//!   - 잎: SHA-256(`0x00` ‖ 영수증 문자열), 노드: SHA-256(`0x01` ‖ 왼쪽 ‖ 오른쪽) (RFC 6962와 같은 구분 접두사)
+//!   - 짝이 없는 마지막 노드는 그대로 위 층으로 올림
+//!   - 빈 트리의 루트: SHA-256("")
+
+use rand::RngCore;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use super::audit;
+use super::repository::Choice;
+
+type Hash = [u8; 32];
+
+/// 포함 증명의 한 단계 (잎에서 루트 방향)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProofStep {
+    pub side: Side, // 형제 노드의 위치
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// 새 salt (256비트 난수)
+pub fn new_salt() -> Vec<u8> {
+    let mut salt = vec![0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut salt);
+    salt
+}
+
+/// 선택 + salt로 만든 영수증
+pub fn commit(salt: &[u8], agenda_id: Uuid, vote_id: Uuid, choice: &Choice) -> String {
+    let content = format!("{}\n{}\n{}\n{}", hex(salt), agenda_id, vote_id, audit::encode(choice));
+    hex(&Sha256::digest(content.as_bytes()))
+}
+
+/// 영수증 형식 (소문자 hex 64자)
+pub fn is_receipt(raw: &str) -> bool {
+    raw.len() == 64 && raw.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+/// 영수증들의 Merkle 루트 (순서와 무관 - 정렬 후 계산)
+pub fn merkle_root(receipts: &[String]) -> String {
+    match levels(receipts).last() {
+        Some(top) => hex(&top[0]),
+        None => hex(&Sha256::digest(b"")),
+    }
+}
+
+/// 영수증의 (정렬된 잎 위치, 포함 증명) - 트리에 없으면 `None`
+pub fn merkle_proof(receipts: &[String], receipt: &str) -> Option<(usize, Vec<ProofStep>)> {
+    let leaf_index = sorted(receipts).binary_search(&receipt).ok()?;
+    let mut index = leaf_index;
+    let mut proof = Vec::new();
+
+    for level in levels(receipts).iter().filter(|level| level.len() > 1) {
+        let sibling = index ^ 1;
+        if let Some(hash) = level.get(sibling) {
+            let side = if sibling < index { Side::Left } else { Side::Right };
+            proof.push(ProofStep { side, hash: hex(hash) });
+        }
+        index /= 2;
+    }
+
+    Some((leaf_index, proof))
+}
+
+/// 포함 증명 확인 (공개된 루트만으로 오프라인에서 하는 계산과 같음)
+#[cfg(test)]
+pub fn verify_proof(receipt: &str, proof: &[ProofStep], root: &str) -> bool {
+    let mut hash = leaf(receipt);
+    for step in proof {
+        let Some(sibling) = decode(&step.hash) else {
+            return false;
+        };
+        hash = match step.side {
+            Side::Left => node(&sibling, &hash),
+            Side::Right => node(&hash, &sibling),
+        };
+    }
+    hex(&hash) == root
+}
+
+/// 잎 층부터 루트 층까지 (빈 목록이면 층 없음)
+fn levels(receipts: &[String]) -> Vec<Vec<Hash>> {
+    let leaves: Vec<Hash> = sorted(receipts).into_iter().map(leaf).collect();
+    if leaves.is_empty() {
+        return Vec::new();
+    }
+
+    let mut levels = vec![leaves];
+    while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+        let next = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => node(left, right),
+                [single] => *single,
+                _ => unreachable!("chunks(2) yields one or two nodes"),
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+fn sorted(receipts: &[String]) -> Vec<&str> {
+    let mut sorted: Vec<&str> = receipts.iter().map(String::as_str).collect();
+    sorted.sort_unstable();
+    sorted
+}
+
+fn leaf(receipt: &str) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(receipt.as_bytes());
+    hasher.finalize().into()
+}
+
+fn node(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+fn decode(raw: &str) -> Option<Hash> {
+    if !is_receipt(raw) {
+        return None;
+    }
+    let mut hash = [0u8; 32];
+    for (i, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&raw[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(hash)
+}
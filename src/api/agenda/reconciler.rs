@@ -0,0 +1,43 @@
+//! 캐싱 카운터 점검 작업
+//!
+//! agree_count / disagree_count / abstain_count 와 선택지 vote_count는 서버별 쓰기 지연 버퍼
+//! (`CounterBuffer`)에 모였다가 주기적으로 반영되므로, 수동 DB 작업이나 버그로 어긋날 수 있습니다.
+//! 주기적으로 votes를 다시 세어 어긋난 카운터를 로그로 남기기만 합니다 (복구하지 않음).
+//!
+//! 점검은 이 서버의 버퍼만 먼저 반영하므로, 서버가 여러 대면 다른 서버가 아직 반영하지 않은 증감도
+//! 어긋남으로 보입니다. 이 상태에서 복구하면 나중에 반영될 증감이 이중으로 더해져 새로운 어긋남이 생기므로,
+//! 복구는 관리자가 `/agendas/reconcile?repair=true`로 직접 실행합니다.
+
+use std::time::Duration;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use super::repository::traits::AgendaRepository;
+use crate::api::state::Agendas;
+use super::service;
+
+/// 백그라운드 점검 시작 (`period` 마다 한 번씩 실행, 첫 실행은 한 주기 뒤)
+pub fn spawn(repo: Agendas, period: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            run_once(repo.as_ref()).await;
+        }
+    })
+}
+
+/// 점검 1회 실행 (보고만) - 실패해도 다음 주기에 다시 시도하므로 로그만 남깁니다.
+pub async fn run_once<R: AgendaRepository + ?Sized>(repo: &R) {
+    match service::reconcile_counters(repo, false).await {
+        Ok(report) => {
+            for drift in &report.drifts {
+                println!(
+                    "⚠️ Counter drift: agenda={} option={:?} counter={:?} cached={} actual={}",
+                    drift.agenda_id, drift.option_id, drift.counter, drift.cached, drift.actual
+                );
+            }
+        }
+        Err(e) => eprintln!("❌ Counter reconciliation failed: {:?}", e),
+    }
+}
@@ -0,0 +1,109 @@
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use uuid::Uuid;
+use crate::api::agenda::audit::link;
+use super::{AuditAction, AuditEntry, AuditTrail, Choice, VoteEntity, VotingMethod};
+
+/// 감사 로그 한 줄 추가 (투표 트랜잭션 안에서 `vote::append_event`로 기록 잠금을 잡은 뒤 호출)
+///
+/// 잠금이 체인 머리 조회 → 추가를 안건별로 한 줄씩 직렬화합니다.
+pub async fn append(
+    tx: &mut Transaction<'_, Postgres>,
+    agenda_id: Uuid,
+    action: AuditAction,
+    vote_id: Uuid,
+    user_id: Uuid,
+    choice: Option<&Choice>,
+) -> Result<(), sqlx::Error> {
+    let previous = last_entry(tx, agenda_id).await?;
+    let entry = link(previous.as_ref(), agenda_id, action, vote_id, user_id, choice);
+
+    sqlx::query!(
+        "INSERT INTO vote_audit_log (agenda_id, seq, action, vote_id, user_id, choice, created_at, prev_hash, hash)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+        entry.agenda_id,
+        entry.seq,
+        entry.action as AuditAction,
+        entry.vote_id,
+        entry.user_id,
+        entry.choice,
+        entry.created_at,
+        entry.prev_hash,
+        entry.hash
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// 안건 감사 로그의 마지막 줄 (체인 머리)
+pub async fn last_entry(conn: &mut PgConnection, agenda_id: Uuid) -> Result<Option<AuditEntry>, sqlx::Error> {
+    sqlx::query_as!(
+        AuditEntry,
+        r#"SELECT agenda_id, seq, action as "action: AuditAction", vote_id, user_id, choice, created_at, prev_hash, hash
+           FROM vote_audit_log
+           WHERE agenda_id = $1
+           ORDER BY seq DESC
+           LIMIT 1"#,
+        agenda_id
+    )
+    .fetch_optional(conn)
+    .await
+}
+
+/// 감사 로그 전체 + 현재 투표들 (REPEATABLE READ 읽기 전용 트랜잭션 - 두 조회가 같은 스냅샷을 봄)
+///
+/// 따로 읽으면 그 사이에 커밋된 투표가 한쪽에만 보여 정상 체인도 불일치로 보고될 수 있습니다.
+pub async fn find_trail(pool: &PgPool, agenda_id: Uuid) -> Result<AuditTrail, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query!("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+
+    let entries = sqlx::query_as!(
+        AuditEntry,
+        r#"SELECT agenda_id, seq, action as "action: AuditAction", vote_id, user_id, choice, created_at, prev_hash, hash
+           FROM vote_audit_log
+           WHERE agenda_id = $1
+           ORDER BY seq"#,
+        agenda_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let method = sqlx::query_scalar!(
+        r#"SELECT voting_method as "voting_method: VotingMethod" FROM agendas WHERE id = $1"#,
+        agenda_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .unwrap_or_default();
+
+    let rows = sqlx::query!(
+        r#"SELECT v.id, v.user_id, v.is_agree, v.option_id, v.abstain,
+                  COALESCE(
+                      ARRAY_AGG(s.option_id ORDER BY s.rank) FILTER (WHERE s.option_id IS NOT NULL),
+                      '{}'
+                  ) AS "selections!"
+           FROM votes v
+           LEFT JOIN vote_selections s ON s.vote_id = v.id
+           WHERE v.agenda_id = $1
+           GROUP BY v.id"#,
+        agenda_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    // 선택으로 해석할 수 없는 행은 빠지므로 로그와 대조할 때 `missing_vote`로 드러남
+    let votes = rows
+        .into_iter()
+        .filter_map(|row| {
+            let choice = Choice::from_columns(row.is_agree, row.option_id, row.abstain, row.selections, method)?;
+            Some(VoteEntity { id: row.id, user_id: row.user_id, choice })
+        })
+        .collect();
+
+    Ok(AuditTrail { entries, votes })
+}
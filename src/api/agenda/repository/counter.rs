@@ -0,0 +1,272 @@
+//! 캐싱 카운터 쓰기 지연(write-behind) 버퍼
+//!
+//! 인기 안건에 투표가 몰리면 매 투표의 `UPDATE agendas SET agree_count = agree_count + 1`이
+//! 같은 행을 두고 줄을 서게 됩니다. 투표(votes INSERT)는 즉시 저장하되 카운터 증감은
+//! 안건별로 메모리에 모아 두었다가 주기적으로(또는 일정 건수가 쌓이면) 한 번에 반영합니다.
+//!
+//! - 조회 경로는 DB 값에 아직 반영되지 않은 증감을 더해서 응답합니다.
+//! - 증감은 투표 트랜잭션이 안건 행 잠금을 잡은 채 커밋 직전에 기록하고, 커밋되지 않으면 되돌립니다.
+//!   (카운터 복구가 안건 행을 잠그면 커밋된 투표의 증감이 모두 버퍼에 있음이 보장됩니다)
+//! - 버퍼는 프로세스 메모리에 있으므로 종료 시 반드시 비워야 합니다. (`flush`)
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use sqlx::{PgConnection, PgPool};
+use tokio::sync::{Mutex as AsyncMutex, MutexGuard, Notify};
+use uuid::Uuid;
+use super::{AgendaEntity, AgendaOptionEntity, Choice};
+
+/// 안건 하나의 반영 대기 중인 증감
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PendingCounts {
+    pub agree: i32,
+    pub disagree: i32,
+    pub abstain: i32,
+    pub options: HashMap<Uuid, i32>, // 선택지 ID → vote_count 증감
+}
+
+impl PendingCounts {
+    /// 선택에 해당하는 카운터 증감 (선택지는 `Choice::counted_options` 기준)
+    fn add(&mut self, choice: &Choice, delta: i32) {
+        match choice {
+            Choice::Agree => self.agree += delta,
+            Choice::Disagree => self.disagree += delta,
+            Choice::Abstain => self.abstain += delta,
+            other => {
+                for id in other.counted_options() {
+                    *self.options.entry(*id).or_default() += delta;
+                }
+            }
+        }
+    }
+
+    fn absorb(&mut self, other: PendingCounts) {
+        self.agree += other.agree;
+        self.disagree += other.disagree;
+        self.abstain += other.abstain;
+        for (id, delta) in other.options {
+            *self.options.entry(id).or_default() += delta;
+        }
+    }
+}
+
+#[derive(Default)]
+struct Pending {
+    agendas: HashMap<Uuid, PendingCounts>,
+    recorded: usize, // 마지막으로 비운 뒤 기록된 투표 수
+}
+
+/// 안건별 카운터 증감 버퍼 (앱 전체에서 `Arc`로 공유)
+pub struct CounterBuffer {
+    pending: Mutex<Pending>,
+    batch_size: usize,
+    full: Notify,
+    flush_lock: AsyncMutex<()>,
+}
+
+impl CounterBuffer {
+    /// - `batch_size`: 이만큼 투표가 쌓이면 주기를 기다리지 않고 반영 요청
+    pub fn new(batch_size: usize) -> Self {
+        Self {
+            pending: Mutex::new(Pending::default()),
+            batch_size: batch_size.max(1),
+            full: Notify::new(),
+            flush_lock: AsyncMutex::new(()),
+        }
+    }
+
+    /// 투표 하나의 증감 기록 (예: 투표 변경은 `[(이전 선택, -1), (새 선택, 1)]`)
+    ///
+    /// 반환된 `Recorded`를 `commit` 하지 않고 버리면 기록한 증감을 되돌립니다.
+    pub fn record(&self, agenda_id: Uuid, changes: Vec<(Choice, i32)>) -> Recorded<'_> {
+        let full = {
+            let mut pending = self.lock();
+            let counts = pending.agendas.entry(agenda_id).or_default();
+            for (choice, delta) in &changes {
+                counts.add(choice, *delta);
+            }
+            pending.recorded += 1;
+            pending.recorded >= self.batch_size
+        };
+        if full {
+            self.full.notify_one();
+        }
+
+        Recorded {
+            buffer: self,
+            agenda_id,
+            changes,
+        }
+    }
+
+    /// 반영 대기 중인 증감을 안건 카운터에 더하기
+    pub fn merge_agenda(&self, agenda: &mut AgendaEntity) {
+        if let Some(counts) = self.lock().agendas.get(&agenda.id) {
+            agenda.agree_count += counts.agree;
+            agenda.disagree_count += counts.disagree;
+            agenda.abstain_count += counts.abstain;
+        }
+    }
+
+    /// 반영 대기 중인 증감을 선택지 득표에 더하기
+    pub fn merge_options(&self, options: &mut [AgendaOptionEntity]) {
+        let pending = self.lock();
+        for option in options {
+            let delta = pending
+                .agendas
+                .get(&option.agenda_id)
+                .and_then(|counts| counts.options.get(&option.id));
+            if let Some(delta) = delta {
+                option.vote_count += delta;
+            }
+        }
+    }
+
+    /// 쌓인 증감 전부 꺼내기
+    pub fn take_all(&self) -> HashMap<Uuid, PendingCounts> {
+        let mut pending = self.lock();
+        pending.recorded = 0;
+        std::mem::take(&mut pending.agendas)
+    }
+
+    /// 안건 하나의 증감만 꺼내기
+    pub fn take(&self, agenda_id: Uuid) -> Option<PendingCounts> {
+        self.lock().agendas.remove(&agenda_id)
+    }
+
+    /// 반영에 실패한 증감 되돌려 놓기 (그 사이 쌓인 증감과 합산)
+    pub fn restore(&self, counts: HashMap<Uuid, PendingCounts>) {
+        let mut pending = self.lock();
+        for (agenda_id, counts) in counts {
+            pending.agendas.entry(agenda_id).or_default().absorb(counts);
+        }
+    }
+
+    /// `batch_size`만큼 쌓일 때까지 대기
+    pub async fn filled(&self) {
+        self.full.notified().await
+    }
+
+    /// 반영/복구 작업 직렬화 - 꺼낸 증감이 DB에 쓰이기 전에 복구가 끼어들지 않도록
+    pub async fn lock_flush(&self) -> MutexGuard<'_, ()> {
+        self.flush_lock.lock().await
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Pending> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 기록된 증감 - 트랜잭션이 커밋되면 `commit`, 아니면 drop 시 되돌림
+#[must_use]
+pub struct Recorded<'a> {
+    buffer: &'a CounterBuffer,
+    agenda_id: Uuid,
+    changes: Vec<(Choice, i32)>,
+}
+
+impl Recorded<'_> {
+    pub fn commit(mut self) {
+        self.changes.clear();
+    }
+}
+
+impl Drop for Recorded<'_> {
+    fn drop(&mut self) {
+        if self.changes.is_empty() {
+            return;
+        }
+        let mut pending = self.buffer.lock();
+        let counts = pending.agendas.entry(self.agenda_id).or_default();
+        for (choice, delta) in &self.changes {
+            counts.add(choice, -delta);
+        }
+    }
+}
+
+/// 쌓인 증감을 DB에 반영 후 반영한 안건 수 반환 (실패하면 버퍼에 되돌려 놓음)
+pub async fn flush(pool: &PgPool, counters: &CounterBuffer) -> Result<usize, sqlx::Error> {
+    let _guard = counters.lock_flush().await;
+    write_pending(pool, counters).await
+}
+
+/// `flush`와 같지만 호출자가 `lock_flush`를 잡고 있어야 합니다.
+pub(super) async fn write_pending(pool: &PgPool, counters: &CounterBuffer) -> Result<usize, sqlx::Error> {
+    let pending = counters.take_all();
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let result = async {
+        let mut tx = pool.begin().await?;
+        apply(&mut tx, &pending).await?;
+        tx.commit().await
+    }
+    .await;
+
+    match result {
+        Ok(()) => Ok(pending.len()),
+        Err(e) => {
+            counters.restore(pending);
+            Err(e)
+        }
+    }
+}
+
+/// 증감을 캐싱 컬럼에 더하기 (안건 / 선택지 각각 한 번의 UPDATE)
+/// - 여러 행을 잠그므로 교착을 피하기 위해 ID 순서로 갱신합니다.
+pub(super) async fn apply(
+    conn: &mut PgConnection,
+    pending: &HashMap<Uuid, PendingCounts>,
+) -> Result<(), sqlx::Error> {
+    let mut agenda_ids: Vec<Uuid> = pending.keys().copied().collect();
+    agenda_ids.sort_unstable();
+
+    let (mut agree, mut disagree, mut abstain) = (Vec::new(), Vec::new(), Vec::new());
+    let (mut option_agendas, mut option_ids, mut option_deltas) = (Vec::new(), Vec::new(), Vec::new());
+    for id in &agenda_ids {
+        let counts = &pending[id];
+        agree.push(counts.agree);
+        disagree.push(counts.disagree);
+        abstain.push(counts.abstain);
+
+        let mut options: Vec<_> = counts.options.iter().collect();
+        options.sort_unstable();
+        for (option_id, delta) in options {
+            option_agendas.push(*id);
+            option_ids.push(*option_id);
+            option_deltas.push(*delta);
+        }
+    }
+
+    sqlx::query!(
+        "UPDATE agendas a
+         SET agree_count = a.agree_count + d.agree,
+             disagree_count = a.disagree_count + d.disagree,
+             abstain_count = a.abstain_count + d.abstain
+         FROM UNNEST($1::uuid[], $2::int[], $3::int[], $4::int[]) AS d(id, agree, disagree, abstain)
+         WHERE a.id = d.id AND (d.agree <> 0 OR d.disagree <> 0 OR d.abstain <> 0)",
+        &agenda_ids,
+        &agree,
+        &disagree,
+        &abstain
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    if !option_ids.is_empty() {
+        sqlx::query!(
+            "UPDATE agenda_options o
+             SET vote_count = o.vote_count + d.delta
+             FROM UNNEST($1::uuid[], $2::uuid[], $3::int[]) AS d(agenda_id, id, delta)
+             WHERE o.agenda_id = d.agenda_id AND o.id = d.id AND d.delta <> 0",
+            &option_agendas,
+            &option_ids,
+            &option_deltas
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
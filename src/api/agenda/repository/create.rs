@@ -1,18 +1,56 @@
 use sqlx::PgPool;
 use uuid::Uuid;
-use super::AgendaEntity;
+use crate::api::outbox::{event::DomainEvent, repository::append::append};
+use super::{AgendaEntity, AgendaStatus, NewAgenda, VotingMethod, DecisionRule, TiePolicy};
 
 /// 안건 생성 DB 로직
-pub async fn create(pool: &PgPool, title: &str, created_by: Uuid) -> Result<AgendaEntity, sqlx::Error> {
-    sqlx::query_as!(
+///
+/// 선택지가 있으면 안건과 같은 트랜잭션에서 agenda_options에 순서대로 저장하고,
+/// `agenda.created` 이벤트도 같은 트랜잭션에서 outbox에 기록합니다.
+pub async fn create(pool: &PgPool, agenda: &NewAgenda) -> Result<AgendaEntity, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let created = sqlx::query_as!(
         AgendaEntity,
-        "INSERT INTO agendas (id, title, created_by, created_at, agree_count, disagree_count) 
-         VALUES ($1, $2, $3, NOW(), 0, 0) 
-         RETURNING id, title, created_by, created_at, agree_count, disagree_count",
+        r#"INSERT INTO agendas (id, title, created_by, created_at, agree_count, disagree_count, status, opens_at, closes_at,
+                                voting_method, decision_rule, tie_policy, quorum, hide_results)
+           VALUES ($1, $2, $3, NOW(), 0, 0, $4, $5, $6, $7, $8, $9, $10, $11)
+           RETURNING id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                     status as "status: AgendaStatus", opens_at, closes_at,
+                     voting_method as "voting_method: VotingMethod",
+                     decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                     quorum, hide_results"#,
         Uuid::new_v4(),
-        title,
-        created_by
+        agenda.title,
+        agenda.created_by,
+        agenda.status as AgendaStatus,
+        agenda.opens_at,
+        agenda.closes_at,
+        agenda.voting_method as VotingMethod,
+        agenda.decision_rule as DecisionRule,
+        agenda.tie_policy as TiePolicy,
+        agenda.quorum,
+        agenda.hide_results
     )
-    .fetch_one(pool)
-    .await
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if !agenda.options.is_empty() {
+        // WITH ORDINALITY: 배열 순서(1부터)를 position(0부터)으로 사용
+        sqlx::query!(
+            "INSERT INTO agenda_options (agenda_id, position, label)
+             SELECT $1, (ord - 1)::int, label
+             FROM UNNEST($2::text[]) WITH ORDINALITY AS t(label, ord)",
+            created.id,
+            &agenda.options
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    append(&mut tx, &[DomainEvent::AgendaCreated((&created).into())]).await?;
+
+    tx.commit().await?;
+
+    Ok(created)
 }
@@ -0,0 +1,104 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::api::outbox::{event::DomainEvent, repository::append::append};
+use super::receipt::publish_root;
+use super::{AgendaEntity, AgendaStatus, ScheduleChanges, VotingMethod, DecisionRule, TiePolicy};
+
+/// 안건 상태 전이 DB 로직
+///
+/// `WHERE status = from` 조건으로 낙관적 동시성 제어를 합니다.
+/// 그 사이 스케줄러나 다른 요청이 상태를 바꿨다면 `None`을 반환합니다.
+/// - open 으로 전이: 예약 시작 시각을 지금으로 당김
+/// - closed 로 전이: 예약 마감 시각을 지금으로 당김
+///
+/// 전이했다면 같은 트랜잭션에서 상태 변경 이벤트를 outbox에 기록하고, 마감이면 영수증 Merkle 루트를 기록합니다.
+pub async fn update_status(
+    pool: &PgPool,
+    id: Uuid,
+    from: AgendaStatus,
+    to: AgendaStatus,
+) -> Result<Option<AgendaEntity>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let updated = sqlx::query_as!(
+        AgendaEntity,
+        r#"UPDATE agendas
+           SET status = $3,
+               opens_at = CASE WHEN $3 = 'open'::agenda_status AND (opens_at IS NULL OR opens_at > NOW())
+                               THEN NOW() ELSE opens_at END,
+               closes_at = CASE WHEN $3 = 'closed'::agenda_status AND (closes_at IS NULL OR closes_at > NOW())
+                                THEN NOW() ELSE closes_at END
+           WHERE id = $1 AND status = $2
+           RETURNING id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                     status as "status: AgendaStatus", opens_at, closes_at,
+                     voting_method as "voting_method: VotingMethod",
+                     decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                     quorum, hide_results"#,
+        id,
+        from as AgendaStatus,
+        to as AgendaStatus
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    if let Some(agenda) = &updated {
+        let events: Vec<_> = DomainEvent::status_changed(agenda).into_iter().collect();
+        append(&mut tx, &events).await?;
+        if agenda.status == AgendaStatus::Closed {
+            publish_root(&mut tx, agenda.id).await?;
+        }
+    }
+
+    tx.commit().await?;
+
+    Ok(updated)
+}
+
+/// 예약 시각이 지난 안건 상태 전이 (스케줄러용, 단일 트랜잭션)
+///
+/// 1. opens_at이 지난 draft → open
+/// 2. closes_at이 지난 open → closed (1에서 열린 안건도 마감 시각이 지났다면 함께 마감)
+/// 3. 전이마다 상태 변경 이벤트를 outbox에 기록 (열림 → 마감 순서)
+/// 4. 마감한 안건마다 영수증 Merkle 루트 기록
+pub async fn apply_schedule(pool: &PgPool) -> Result<ScheduleChanges, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let opened = sqlx::query_as!(
+        AgendaEntity,
+        r#"UPDATE agendas SET status = 'open'
+           WHERE status = 'draft' AND opens_at <= NOW()
+           RETURNING id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                     status as "status: AgendaStatus", opens_at, closes_at,
+                     voting_method as "voting_method: VotingMethod",
+                     decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                     quorum, hide_results"#
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let closed = sqlx::query_as!(
+        AgendaEntity,
+        r#"UPDATE agendas SET status = 'closed'
+           WHERE status = 'open' AND closes_at <= NOW()
+           RETURNING id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                     status as "status: AgendaStatus", opens_at, closes_at,
+                     voting_method as "voting_method: VotingMethod",
+                     decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                     quorum, hide_results"#
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let events: Vec<_> = opened.iter().chain(&closed).filter_map(DomainEvent::status_changed).collect();
+    append(&mut tx, &events).await?;
+    for agenda in &closed {
+        publish_root(&mut tx, agenda.id).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(ScheduleChanges {
+        opened: opened.iter().map(|agenda| agenda.id).collect(),
+        closed: closed.iter().map(|agenda| agenda.id).collect(),
+    })
+}
@@ -0,0 +1,433 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::error::ErrorKind;
+use uuid::Uuid;
+use super::traits::AgendaRepository;
+use super::{
+    AgendaCursor, AgendaEntity, AgendaOptionEntity, AgendaStatus, AuditAction, AuditEntry, AuditTrail, Choice,
+    CounterDrift, CounterKind, NewAgenda, ReceiptEntity, ReceiptRootEntity, ReceiptStatus, RecordedVote,
+    ScheduleChanges, VoteEntity,
+};
+use crate::api::memory::{violation, MemoryDb, Tables, VoteRow};
+use crate::api::outbox::event::DomainEvent;
+
+/// 메모리 Repository 구현체 (PostgreSQL 구현과 같은 제약/동작)
+///
+/// 카운터는 쓰기 지연 없이 투표와 함께 갱신하므로 `flush_counters`는 할 일이 없습니다.
+pub struct MemoryAgendaRepository {
+    db: Arc<MemoryDb>,
+}
+
+impl MemoryAgendaRepository {
+    pub fn new(db: &Arc<MemoryDb>) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+#[async_trait]
+impl AgendaRepository for MemoryAgendaRepository {
+    /// 생성자 외래 키, 예약 구간/정족수 check, 선택지 label unique 제약 확인 후 저장
+    async fn create(&self, agenda: &NewAgenda) -> Result<AgendaEntity, sqlx::Error> {
+        let mut tables = self.db.lock();
+
+        if !tables.user_exists(agenda.created_by) {
+            return Err(violation(ErrorKind::ForeignKeyViolation, "agendas_created_by_fkey"));
+        }
+        if let (Some(opens_at), Some(closes_at)) = (agenda.opens_at, agenda.closes_at) {
+            if opens_at >= closes_at {
+                return Err(violation(ErrorKind::CheckViolation, "ck_agenda_window"));
+            }
+        }
+        if agenda.quorum.is_some_and(|q| q <= 0) {
+            return Err(violation(ErrorKind::CheckViolation, "ck_agenda_quorum"));
+        }
+        let mut labels = agenda.options.iter().collect::<Vec<_>>();
+        labels.sort();
+        if labels.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(violation(ErrorKind::UniqueViolation, "uk_option_agenda_label"));
+        }
+
+        let created = AgendaEntity {
+            id: Uuid::new_v4(),
+            title: agenda.title.clone(),
+            created_by: agenda.created_by,
+            created_at: Utc::now(),
+            agree_count: 0,
+            disagree_count: 0,
+            abstain_count: 0,
+            status: agenda.status,
+            opens_at: agenda.opens_at,
+            closes_at: agenda.closes_at,
+            voting_method: agenda.voting_method,
+            decision_rule: agenda.decision_rule,
+            tie_policy: agenda.tie_policy,
+            quorum: agenda.quorum,
+            hide_results: agenda.hide_results,
+        };
+
+        for (position, label) in agenda.options.iter().enumerate() {
+            tables.options.push(AgendaOptionEntity {
+                id: Uuid::new_v4(),
+                agenda_id: created.id,
+                position: position as i32,
+                label: label.clone(),
+                vote_count: 0,
+            });
+        }
+        tables.agendas.push(created.clone());
+        tables.append_events([DomainEvent::AgendaCreated((&created).into())]);
+
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AgendaEntity>, sqlx::Error> {
+        Ok(self.db.lock().agendas.iter().find(|a| a.id == id).cloned())
+    }
+
+    async fn list(&self, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+        Ok(page(self.db.lock().agendas.iter(), cursor, limit))
+    }
+
+    async fn list_by_creator(&self, created_by: Uuid, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+        Ok(page(self.db.lock().agendas.iter().filter(|a| a.created_by == created_by), cursor, limit))
+    }
+
+    async fn find_options(&self, agenda_ids: &[Uuid]) -> Result<Vec<AgendaOptionEntity>, sqlx::Error> {
+        let mut options: Vec<AgendaOptionEntity> = self
+            .db
+            .lock()
+            .options
+            .iter()
+            .filter(|o| agenda_ids.contains(&o.agenda_id))
+            .cloned()
+            .collect();
+        options.sort_by_key(|o| (o.agenda_id, o.position));
+        Ok(options)
+    }
+
+    /// 선택지 투표만 (찬/반/기권 제외)
+    async fn find_ballots(&self, agenda_id: Uuid) -> Result<Vec<Vec<Uuid>>, sqlx::Error> {
+        Ok(self
+            .db
+            .lock()
+            .votes
+            .iter()
+            .filter(|v| v.agenda_id == agenda_id)
+            .filter_map(|v| match &v.choice {
+                Choice::Option(id) => Some(vec![*id]),
+                Choice::Approval(ids) | Choice::Ranking(ids) => Some(ids.clone()),
+                Choice::Agree | Choice::Disagree | Choice::Abstain => None,
+            })
+            .collect())
+    }
+
+    /// `vote::cast`와 같은 검사 순서
+    /// 투표 기간 → 사용자 외래 키 → 중복 투표(`uk_vote_user_agenda`) → 선택지 외래 키
+    async fn vote(&self, agenda_id: Uuid, user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error> {
+        let mut tables = self.db.lock();
+
+        open_agenda(&tables, agenda_id)?;
+        if !tables.user_exists(user_id) {
+            return Err(violation(ErrorKind::ForeignKeyViolation, "votes_user_id_fkey"));
+        }
+        if tables.votes.iter().any(|v| v.agenda_id == agenda_id && v.user_id == user_id) {
+            return Err(violation(ErrorKind::UniqueViolation, "uk_vote_user_agenda"));
+        }
+        check_choice(&tables, agenda_id, &choice)?;
+
+        adjust(&mut tables, agenda_id, &choice, 1);
+        let id = Uuid::new_v4();
+        tables.append_events([DomainEvent::vote_cast(agenda_id, user_id, &choice)]);
+        tables.append_audit(agenda_id, AuditAction::Cast, id, user_id, Some(&choice));
+        let receipt = tables.issue_receipt(agenda_id, id, &choice);
+        tables.votes.push(VoteRow { id, user_id, agenda_id, choice });
+
+        Ok(RecordedVote { agenda: agenda(&tables, agenda_id), receipt })
+    }
+
+    /// 같은 선택이면 카운터를 건드리지 않음
+    async fn change_vote(&self, agenda_id: Uuid, user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error> {
+        let mut tables = self.db.lock();
+
+        open_agenda(&tables, agenda_id)?;
+        let index = find_vote(&tables, agenda_id, user_id)?;
+        let previous = tables.votes[index].choice.clone();
+        let vote_id = tables.votes[index].id;
+
+        let receipt = if previous != choice {
+            check_choice(&tables, agenda_id, &choice)?;
+            adjust(&mut tables, agenda_id, &previous, -1);
+            adjust(&mut tables, agenda_id, &choice, 1);
+            tables.append_events([DomainEvent::vote_changed(agenda_id, user_id, &previous, &choice)]);
+            tables.append_audit(agenda_id, AuditAction::Changed, vote_id, user_id, Some(&choice));
+            tables.revoke_receipt(vote_id, ReceiptStatus::Superseded);
+            let receipt = tables.issue_receipt(agenda_id, vote_id, &choice);
+            tables.votes[index].choice = choice;
+            receipt
+        } else {
+            match tables.current_receipt(vote_id) {
+                Some(receipt) => receipt,
+                None => tables.issue_receipt(agenda_id, vote_id, &choice),
+            }
+        };
+
+        Ok(RecordedVote { agenda: agenda(&tables, agenda_id), receipt })
+    }
+
+    async fn retract_vote(&self, agenda_id: Uuid, user_id: Uuid) -> Result<AgendaEntity, sqlx::Error> {
+        let mut tables = self.db.lock();
+
+        open_agenda(&tables, agenda_id)?;
+        let index = find_vote(&tables, agenda_id, user_id)?;
+        let removed = tables.votes.remove(index);
+        let previous = removed.choice;
+        adjust(&mut tables, agenda_id, &previous, -1);
+        tables.append_events([DomainEvent::vote_retracted(agenda_id, user_id, &previous)]);
+        tables.append_audit(agenda_id, AuditAction::Retracted, removed.id, user_id, None);
+        tables.revoke_receipt(removed.id, ReceiptStatus::Retracted);
+
+        Ok(agenda(&tables, agenda_id))
+    }
+
+    /// `lifecycle::update_status`와 같이 열 때/마감할 때 예약 시각을 지금으로 당김
+    async fn update_status(&self, id: Uuid, from: AgendaStatus, to: AgendaStatus) -> Result<Option<AgendaEntity>, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let now = Utc::now();
+
+        let Some(agenda) = tables.agendas.iter_mut().find(|a| a.id == id && a.status == from) else {
+            return Ok(None);
+        };
+
+        agenda.status = to;
+        if to == AgendaStatus::Open && agenda.opens_at.is_none_or(|t| t > now) {
+            agenda.opens_at = Some(now);
+        }
+        if to == AgendaStatus::Closed && agenda.closes_at.is_none_or(|t| t > now) {
+            agenda.closes_at = Some(now);
+        }
+
+        let updated = agenda.clone();
+        tables.append_events(DomainEvent::status_changed(&updated));
+        if to == AgendaStatus::Closed {
+            tables.publish_receipt_root(id);
+        }
+        Ok(Some(updated))
+    }
+
+    async fn apply_schedule(&self) -> Result<ScheduleChanges, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let now = Utc::now();
+        let mut changes = ScheduleChanges::default();
+        let mut events = Vec::new();
+
+        for agenda in tables.agendas.iter_mut() {
+            if agenda.status == AgendaStatus::Draft && agenda.opens_at.is_some_and(|t| t <= now) {
+                agenda.status = AgendaStatus::Open;
+                changes.opened.push(agenda.id);
+                events.extend(DomainEvent::status_changed(agenda));
+            }
+            if agenda.status == AgendaStatus::Open && agenda.closes_at.is_some_and(|t| t <= now) {
+                agenda.status = AgendaStatus::Closed;
+                changes.closed.push(agenda.id);
+                events.extend(DomainEvent::status_changed(agenda));
+            }
+        }
+        tables.append_events(events);
+        for &agenda_id in &changes.closed {
+            tables.publish_receipt_root(agenda_id);
+        }
+
+        Ok(changes)
+    }
+
+    async fn find_counter_drifts(&self) -> Result<Vec<CounterDrift>, sqlx::Error> {
+        Ok(drifts(&self.db.lock(), None))
+    }
+
+    async fn repair_counters(&self, agenda_id: Uuid) -> Result<Vec<CounterDrift>, sqlx::Error> {
+        let mut tables = self.db.lock();
+        if !tables.agendas.iter().any(|a| a.id == agenda_id) {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        let drifts = drifts(&tables, Some(agenda_id));
+        for drift in &drifts {
+            let actual = drift.actual as i32;
+            if let Some(option_id) = drift.option_id {
+                if let Some(option) = tables.options.iter_mut().find(|o| o.id == option_id) {
+                    option.vote_count = actual;
+                }
+                continue;
+            }
+            if let Some(agenda) = tables.agendas.iter_mut().find(|a| a.id == agenda_id) {
+                match drift.counter {
+                    CounterKind::Agree => agenda.agree_count = actual,
+                    CounterKind::Disagree => agenda.disagree_count = actual,
+                    CounterKind::Abstain => agenda.abstain_count = actual,
+                    CounterKind::Option => {}
+                }
+            }
+        }
+
+        Ok(drifts)
+    }
+
+    async fn flush_counters(&self) -> Result<usize, sqlx::Error> {
+        Ok(0)
+    }
+
+    async fn find_audit_head(&self, agenda_id: Uuid) -> Result<Option<AuditEntry>, sqlx::Error> {
+        Ok(self.db.lock().vote_audit.iter().rev().find(|e| e.agenda_id == agenda_id).cloned())
+    }
+
+    async fn find_audit_trail(&self, agenda_id: Uuid) -> Result<AuditTrail, sqlx::Error> {
+        let tables = self.db.lock();
+        Ok(AuditTrail {
+            entries: tables.vote_audit.iter().filter(|e| e.agenda_id == agenda_id).cloned().collect(),
+            votes: tables
+                .votes
+                .iter()
+                .filter(|v| v.agenda_id == agenda_id)
+                .map(|v| VoteEntity { id: v.id, user_id: v.user_id, choice: v.choice.clone() })
+                .collect(),
+        })
+    }
+
+    async fn find_receipt(&self, agenda_id: Uuid, receipt: &str) -> Result<Option<ReceiptEntity>, sqlx::Error> {
+        Ok(self
+            .db
+            .lock()
+            .receipts
+            .iter()
+            .find(|r| r.entity.receipt == receipt && r.entity.agenda_id == agenda_id)
+            .map(|r| r.entity.clone()))
+    }
+
+    async fn find_receipt_root(&self, agenda_id: Uuid) -> Result<Option<ReceiptRootEntity>, sqlx::Error> {
+        Ok(self.db.lock().receipt_roots.iter().find(|r| r.agenda_id == agenda_id).cloned())
+    }
+
+    async fn find_counted_receipts(&self, agenda_id: Uuid) -> Result<Vec<String>, sqlx::Error> {
+        Ok(self.db.lock().counted_receipts(agenda_id))
+    }
+}
+
+/// 투표 기간인 안건인지 (예약 시각 반영) - 아니면 `RowNotFound`
+fn open_agenda(tables: &Tables, agenda_id: Uuid) -> Result<(), sqlx::Error> {
+    tables
+        .agendas
+        .iter()
+        .find(|a| a.id == agenda_id && a.effective_status(Utc::now()) == AgendaStatus::Open)
+        .map(|_| ())
+        .ok_or(sqlx::Error::RowNotFound)
+}
+
+/// 기존 투표 위치 - 없으면 `RowNotFound`
+fn find_vote(tables: &Tables, agenda_id: Uuid, user_id: Uuid) -> Result<usize, sqlx::Error> {
+    tables
+        .votes
+        .iter()
+        .position(|v| v.agenda_id == agenda_id && v.user_id == user_id)
+        .ok_or(sqlx::Error::RowNotFound)
+}
+
+/// 선택지가 이 안건의 것인지 (`fk_vote_option` / `fk_selection_option`),
+/// 같은 선택지를 두 번 고르지 않았는지 (`uk_selection_vote_option`)
+fn check_choice(tables: &Tables, agenda_id: Uuid, choice: &Choice) -> Result<(), sqlx::Error> {
+    let belongs = |id: &Uuid| tables.options.iter().any(|o| o.id == *id && o.agenda_id == agenda_id);
+
+    if let Choice::Option(id) = choice {
+        if !belongs(id) {
+            return Err(violation(ErrorKind::ForeignKeyViolation, "fk_vote_option"));
+        }
+    }
+
+    let selections = choice.selections();
+    if !selections.iter().all(belongs) {
+        return Err(violation(ErrorKind::ForeignKeyViolation, "fk_selection_option"));
+    }
+    if selections.iter().enumerate().any(|(i, id)| selections[..i].contains(id)) {
+        return Err(violation(ErrorKind::UniqueViolation, "uk_selection_vote_option"));
+    }
+
+    Ok(())
+}
+
+/// 선택에 해당하는 카운터 증감 (선택지는 `Choice::counted_options` 기준)
+fn adjust(tables: &mut Tables, agenda_id: Uuid, choice: &Choice, delta: i32) {
+    if let Some(agenda) = tables.agendas.iter_mut().find(|a| a.id == agenda_id) {
+        match choice {
+            Choice::Agree => agenda.agree_count += delta,
+            Choice::Disagree => agenda.disagree_count += delta,
+            Choice::Abstain => agenda.abstain_count += delta,
+            _ => {}
+        }
+    }
+    for id in choice.counted_options() {
+        if let Some(option) = tables.options.iter_mut().find(|o| o.id == *id) {
+            option.vote_count += delta;
+        }
+    }
+}
+
+fn agenda(tables: &Tables, agenda_id: Uuid) -> AgendaEntity {
+    tables
+        .agendas
+        .iter()
+        .find(|a| a.id == agenda_id)
+        .cloned()
+        .expect("agenda checked above")
+}
+
+/// 캐싱 카운터 vs votes 재계산 비교 (`agenda_id`가 없으면 전체 안건)
+fn drifts(tables: &Tables, agenda_id: Option<Uuid>) -> Vec<CounterDrift> {
+    let mut drifts = Vec::new();
+
+    for agenda in tables.agendas.iter().filter(|a| agenda_id.is_none_or(|id| a.id == id)) {
+        let votes: Vec<&Choice> = tables.votes.iter().filter(|v| v.agenda_id == agenda.id).map(|v| &v.choice).collect();
+        let count = |target: &Choice| votes.iter().filter(|c| **c == target).count() as i64;
+
+        for (counter, cached, actual) in [
+            (CounterKind::Agree, agenda.agree_count, count(&Choice::Agree)),
+            (CounterKind::Disagree, agenda.disagree_count, count(&Choice::Disagree)),
+            (CounterKind::Abstain, agenda.abstain_count, count(&Choice::Abstain)),
+        ] {
+            if i64::from(cached) != actual {
+                drifts.push(CounterDrift { agenda_id: agenda.id, option_id: None, counter, cached, actual });
+            }
+        }
+
+        let mut options: Vec<&AgendaOptionEntity> = tables.options.iter().filter(|o| o.agenda_id == agenda.id).collect();
+        options.sort_by_key(|o| o.position);
+        for option in options {
+            let actual = votes.iter().filter(|c| c.counted_options().contains(&option.id)).count() as i64;
+            if i64::from(option.vote_count) != actual {
+                drifts.push(CounterDrift {
+                    agenda_id: agenda.id,
+                    option_id: Some(option.id),
+                    counter: CounterKind::Option,
+                    cached: option.vote_count,
+                    actual,
+                });
+            }
+        }
+    }
+
+    drifts
+}
+
+/// Keyset 페이지네이션 (created_at DESC, id DESC 정렬 후 커서 이후만)
+fn page<'a>(
+    agendas: impl Iterator<Item = &'a AgendaEntity>,
+    cursor: Option<AgendaCursor>,
+    limit: i64,
+) -> Vec<AgendaEntity> {
+    let mut sorted: Vec<AgendaEntity> = agendas
+        .filter(|a| cursor.is_none_or(|c| (a.created_at, a.id) < (c.created_at, c.id)))
+        .cloned()
+        .collect();
+    sorted.sort_by_key(|a| std::cmp::Reverse((a.created_at, a.id)));
+    sorted.truncate(limit.max(0) as usize);
+    sorted
+}
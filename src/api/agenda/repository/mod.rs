@@ -1,9 +1,17 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
 pub mod traits;
 pub mod create;
+pub mod vote;
+pub mod read;
+pub mod lifecycle;
+pub mod reconcile;
+pub mod counter;
+pub mod audit;
+pub mod receipt;
+pub mod memory; // PostgreSQL 없이 실행할 때의 메모리 구현체
 
 // Agenda Entity
 #[derive(Debug, Clone, Serialize)]
@@ -15,24 +23,435 @@ pub struct AgendaEntity {
     pub created_at: DateTime<Utc>,
     pub agree_count: i32,
     pub disagree_count: i32,
+    pub abstain_count: i32,
+    pub status: AgendaStatus,
+    pub opens_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub voting_method: VotingMethod,
+    pub decision_rule: DecisionRule,
+    pub tie_policy: TiePolicy,
+    pub quorum: Option<i32>,
+    pub hide_results: bool,
+}
+
+/// 안건 상태 (PostgreSQL `agenda_status` ENUM)
+///
+/// ```text
+/// draft ──→ open ──→ closed
+///   │        │
+///   └────────┴──→ cancelled
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "agenda_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum AgendaStatus {
+    Draft,
+    Open,
+    Closed,
+    Cancelled,
+}
+
+impl AgendaStatus {
+    /// 상태 전이 허용 여부
+    pub fn can_transition_to(self, next: AgendaStatus) -> bool {
+        use AgendaStatus::*;
+        matches!(
+            (self, next),
+            (Draft, Open) | (Draft, Cancelled) | (Open, Closed) | (Open, Cancelled)
+        )
+    }
+}
+
+/// 투표 방식 (PostgreSQL `voting_method` ENUM)
+///
+/// - `single_choice`: 선택지 하나 (찬/반 안건은 항상 이 방식)
+/// - `approval`: 마음에 드는 선택지를 여러 개 승인
+/// - `instant_runoff`: 순위 투표, 최하위 탈락을 반복해 과반 득표자 선출
+/// - `borda`: 순위 투표, 순위별 점수 합산
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "voting_method", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum VotingMethod {
+    #[default]
+    SingleChoice,
+    Approval,
+    InstantRunoff,
+    Borda,
+}
+
+impl VotingMethod {
+    /// 순위를 매기는 투표 방식인지
+    pub fn is_ranked(self) -> bool {
+        matches!(self, VotingMethod::InstantRunoff | VotingMethod::Borda)
+    }
+}
+
+/// 가결 규칙 (PostgreSQL `decision_rule` ENUM)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "decision_rule", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionRule {
+    #[default]
+    SimpleMajority, // 과반
+    TwoThirds,      // 2/3 이상
+}
+
+/// 동률 처리 (PostgreSQL `tie_policy` ENUM)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "tie_policy", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TiePolicy {
+    #[default]
+    Fail, // 동률이면 부결 (현상 유지)
+    Tie,  // 결정 없이 동률로 보고
+}
+
+impl AgendaEntity {
+    /// 예약 시각을 반영한 현재 상태
+    ///
+    /// 스케줄러가 DB의 status를 옮기기 전이라도 opens_at/closes_at이 지났다면
+    /// 그 시각 기준의 상태를 돌려줍니다. (응답/투표 가능 여부 판단에 사용)
+    pub fn effective_status(&self, now: DateTime<Utc>) -> AgendaStatus {
+        let opened = self.opens_at.is_some_and(|t| t <= now);
+        let closed = self.closes_at.is_some_and(|t| t <= now);
+
+        match self.status {
+            AgendaStatus::Draft if opened && closed => AgendaStatus::Closed,
+            AgendaStatus::Draft if opened => AgendaStatus::Open,
+            AgendaStatus::Open if closed => AgendaStatus::Closed,
+            status => status,
+        }
+    }
+
+    /// 집계 공개 여부 (hide_results 안건은 마감 후에만 공개)
+    pub fn results_visible(&self, now: DateTime<Utc>) -> bool {
+        !self.hide_results || self.effective_status(now) == AgendaStatus::Closed
+    }
+}
+
+/// 다지선다 안건의 선택지 (agenda_options)
+#[derive(Debug, Clone, Serialize)]
+pub struct AgendaOptionEntity {
+    pub id: Uuid,
+    pub agenda_id: Uuid,
+    pub position: i32,
+    pub label: String,
+    pub vote_count: i32,
+}
+
+/// 한 표의 선택
+///
+/// 선택지가 없는 안건은 찬/반(`Agree`/`Disagree`),
+/// 선택지가 있는 안건은 투표 방식에 따라 `Option` / `Approval` / `Ranking`으로 투표합니다.
+/// 기권(`Abstain`)은 모든 안건에서 가능하며 투표 참여 수에만 포함됩니다.
+/// 이벤트 JSON: `"agree"` / `"abstain"` / `{ "option": id }` / `{ "approval": [id, ...] }` / `{ "ranking": [id, ...] }`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Choice {
+    Agree,
+    Disagree,
+    Abstain,
+    Option(Uuid),
+    Approval(Vec<Uuid>), // 승인한 선택지들
+    Ranking(Vec<Uuid>),  // 선호 순서대로 나열한 선택지들
+}
+
+impl Choice {
+    /// votes 행의 (is_agree, option_id, abstain) 컬럼 + vote_selections → Choice
+    /// - selections가 있으면 투표 방식에 따라 승인/순위 투표로 해석
+    pub fn from_columns(
+        is_agree: Option<bool>,
+        option_id: Option<Uuid>,
+        abstain: bool,
+        selections: Vec<Uuid>,
+        method: VotingMethod,
+    ) -> Option<Choice> {
+        if abstain {
+            return Some(Choice::Abstain);
+        }
+
+        match (is_agree, option_id) {
+            (Some(true), None) => Some(Choice::Agree),
+            (Some(false), None) => Some(Choice::Disagree),
+            (None, Some(id)) => Some(Choice::Option(id)),
+            (None, None) if selections.is_empty() => None,
+            (None, None) if method.is_ranked() => Some(Choice::Ranking(selections)),
+            (None, None) => Some(Choice::Approval(selections)),
+            _ => None,
+        }
+    }
+
+    /// Choice → votes 행의 (is_agree, option_id) 컬럼
+    pub fn to_columns(&self) -> (Option<bool>, Option<Uuid>) {
+        match self {
+            Choice::Agree => (Some(true), None),
+            Choice::Disagree => (Some(false), None),
+            Choice::Option(id) => (None, Some(*id)),
+            Choice::Abstain | Choice::Approval(_) | Choice::Ranking(_) => (None, None),
+        }
+    }
+
+    /// vote_selections에 저장할 선택 목록 (승인/순위 투표가 아니면 빈 슬라이스)
+    pub fn selections(&self) -> &[Uuid] {
+        match self {
+            Choice::Approval(ids) | Choice::Ranking(ids) => ids,
+            _ => &[],
+        }
+    }
+
+    /// agenda_options.vote_count를 올릴 선택지들
+    /// - 단일/승인 투표: 선택한 모든 선택지, 순위 투표: 1순위 선택지만
+    pub fn counted_options(&self) -> &[Uuid] {
+        match self {
+            Choice::Option(id) => std::slice::from_ref(id),
+            Choice::Approval(ids) => ids,
+            Choice::Ranking(ids) => &ids[..ids.len().min(1)],
+            Choice::Agree | Choice::Disagree | Choice::Abstain => &[],
+        }
+    }
+}
+
+/// 안건 생성 파라미터
+#[derive(Debug, Clone)]
+pub struct NewAgenda {
+    pub title: String,
+    pub created_by: Uuid,
+    pub status: AgendaStatus,
+    pub opens_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub options: Vec<String>, // 비어 있으면 찬/반 안건
+    pub voting_method: VotingMethod,
+    pub decision_rule: DecisionRule,
+    pub tie_policy: TiePolicy,
+    pub quorum: Option<i32>,
+    pub hide_results: bool,
+}
+
+/// 스케줄러 한 번 실행 결과 (상태가 바뀐 안건 ID)
+#[derive(Debug, Default)]
+pub struct ScheduleChanges {
+    pub opened: Vec<Uuid>,
+    pub closed: Vec<Uuid>,
+}
+
+/// 어긋난 캐싱 카운터 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CounterKind {
+    Agree,    // agendas.agree_count
+    Disagree, // agendas.disagree_count
+    Abstain,  // agendas.abstain_count
+    Option,   // agenda_options.vote_count
+}
+
+/// votes 재계산 결과와 다른 캐싱 카운터 하나
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CounterDrift {
+    pub agenda_id: Uuid,
+    pub option_id: Option<Uuid>, // CounterKind::Option일 때만
+    pub counter: CounterKind,
+    pub cached: i32,             // 캐싱 컬럼 값
+    pub actual: i64,             // votes 기준 재계산 값
+}
+
+/// 투표 감사 로그 동작 (PostgreSQL `vote_audit_action` ENUM)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "vote_audit_action", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Cast,
+    Changed,
+    Retracted,
+}
+
+impl AuditAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditAction::Cast => "cast",
+            AuditAction::Changed => "changed",
+            AuditAction::Retracted => "retracted",
+        }
+    }
+}
+
+/// 투표 감사 로그 한 줄 (vote_audit_log)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEntry {
+    pub agenda_id: Uuid,
+    pub seq: i64,                  // 안건별 1부터 연속
+    pub action: AuditAction,
+    pub vote_id: Uuid,
+    pub user_id: Uuid,
+    pub choice: Option<String>,    // 선택 JSON 원문 (retracted는 None)
+    pub created_at: DateTime<Utc>, // 마이크로초 단위 (DB 정밀도)
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+/// 현재 투표 하나 (votes + vote_selections)
+#[derive(Debug, Clone)]
+pub struct VoteEntity {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub choice: Choice,
+}
+
+/// 같은 시점에 읽은 안건의 감사 로그 전체와 현재 투표들 (검증 대상)
+#[derive(Debug, Clone, Default)]
+pub struct AuditTrail {
+    pub entries: Vec<AuditEntry>, // seq 순
+    pub votes: Vec<VoteEntity>,
+}
+
+/// 투표/변경 결과 (갱신된 안건 + 지금 집계 중인 영수증)
+#[derive(Debug, Clone)]
+pub struct RecordedVote {
+    pub agenda: AgendaEntity,
+    pub receipt: String,
+}
+
+/// 영수증 상태 (PostgreSQL `receipt_status` ENUM)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "receipt_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ReceiptStatus {
+    Counted,    // 집계 중인 투표의 영수증
+    Superseded, // 투표를 변경해 새 영수증으로 대체됨
+    Retracted,  // 투표를 철회함
+}
+
+/// 투표 영수증 (vote_receipts, salt 제외)
+#[derive(Debug, Clone)]
+pub struct ReceiptEntity {
+    pub receipt: String,
+    pub agenda_id: Uuid,
+    pub status: ReceiptStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 마감 때 공개한 영수증 Merkle 루트 (receipt_roots)
+#[derive(Debug, Clone)]
+pub struct ReceiptRootEntity {
+    pub agenda_id: Uuid,
+    pub root: String,
+    pub leaf_count: i32,
+    pub published_at: DateTime<Utc>,
+}
+
+/// Keyset 페이지네이션 커서
+/// 직전 페이지 마지막 안건의 (created_at, id) - created_at이 같은 안건은 id로 순서를 정합니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AgendaCursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
 }
 
 pub struct PgAgendaRepository {
     pool: sqlx::PgPool,
+    counters: Arc<CounterBuffer>,
 }
 
 impl PgAgendaRepository {
-    pub fn new(pool: &sqlx::PgPool) -> Self {
-        Self { pool: pool.clone() }
+    pub fn new(pool: &sqlx::PgPool, counters: &Arc<CounterBuffer>) -> Self {
+        Self { pool: pool.clone(), counters: counters.clone() }
+    }
+
+    /// DB 카운터 + 반영 대기 중인 증감
+    fn merged(&self, mut agenda: AgendaEntity) -> AgendaEntity {
+        self.counters.merge_agenda(&mut agenda);
+        agenda
+    }
+
+    fn merged_all(&self, agendas: Vec<AgendaEntity>) -> Vec<AgendaEntity> {
+        agendas.into_iter().map(|agenda| self.merged(agenda)).collect()
     }
 }
 
+use std::sync::Arc;
 use async_trait::async_trait;
+use self::counter::CounterBuffer;
 use self::traits::AgendaRepository;
 
 #[async_trait]
 impl AgendaRepository for PgAgendaRepository {
-    async fn create(&self, title: &str, created_by: Uuid) -> Result<AgendaEntity, sqlx::Error> {
-        self::create::create(&self.pool, title, created_by).await
+    async fn create(&self, agenda: &NewAgenda) -> Result<AgendaEntity, sqlx::Error> {
+        self::create::create(&self.pool, agenda).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AgendaEntity>, sqlx::Error> {
+        Ok(self::read::find_by_id(&self.pool, id).await?.map(|agenda| self.merged(agenda)))
+    }
+
+    async fn list(&self, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+        Ok(self.merged_all(self::read::list(&self.pool, cursor, limit).await?))
+    }
+
+    async fn list_by_creator(&self, created_by: Uuid, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+        Ok(self.merged_all(self::read::list_by_creator(&self.pool, created_by, cursor, limit).await?))
+    }
+
+    async fn find_options(&self, agenda_ids: &[Uuid]) -> Result<Vec<AgendaOptionEntity>, sqlx::Error> {
+        let mut options = self::read::find_options(&self.pool, agenda_ids).await?;
+        self.counters.merge_options(&mut options);
+        Ok(options)
+    }
+
+    async fn find_ballots(&self, agenda_id: Uuid) -> Result<Vec<Vec<Uuid>>, sqlx::Error> {
+        self::read::find_ballots(&self.pool, agenda_id).await
+    }
+
+    async fn vote(&self, agenda_id: Uuid, user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error> {
+        let (agenda, receipt) = self::vote::cast(&self.pool, &self.counters, agenda_id, user_id, choice).await?;
+        Ok(RecordedVote { agenda: self.merged(agenda), receipt })
+    }
+
+    async fn change_vote(&self, agenda_id: Uuid, user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error> {
+        let (agenda, receipt) = self::vote::change(&self.pool, &self.counters, agenda_id, user_id, choice).await?;
+        Ok(RecordedVote { agenda: self.merged(agenda), receipt })
+    }
+
+    async fn retract_vote(&self, agenda_id: Uuid, user_id: Uuid) -> Result<AgendaEntity, sqlx::Error> {
+        Ok(self.merged(self::vote::retract(&self.pool, &self.counters, agenda_id, user_id).await?))
+    }
+
+    async fn update_status(&self, id: Uuid, from: AgendaStatus, to: AgendaStatus) -> Result<Option<AgendaEntity>, sqlx::Error> {
+        Ok(self::lifecycle::update_status(&self.pool, id, from, to).await?.map(|agenda| self.merged(agenda)))
+    }
+
+    async fn apply_schedule(&self) -> Result<ScheduleChanges, sqlx::Error> {
+        self::lifecycle::apply_schedule(&self.pool).await
+    }
+
+    async fn find_counter_drifts(&self) -> Result<Vec<CounterDrift>, sqlx::Error> {
+        self::reconcile::find_drifts(&self.pool, &self.counters).await
+    }
+
+    async fn repair_counters(&self, agenda_id: Uuid) -> Result<Vec<CounterDrift>, sqlx::Error> {
+        self::reconcile::repair(&self.pool, &self.counters, agenda_id).await
+    }
+
+    async fn flush_counters(&self) -> Result<usize, sqlx::Error> {
+        self::counter::flush(&self.pool, &self.counters).await
+    }
+
+    async fn find_audit_head(&self, agenda_id: Uuid) -> Result<Option<AuditEntry>, sqlx::Error> {
+        let mut conn = self.pool.acquire().await?;
+        self::audit::last_entry(&mut conn, agenda_id).await
+    }
+
+    async fn find_audit_trail(&self, agenda_id: Uuid) -> Result<AuditTrail, sqlx::Error> {
+        self::audit::find_trail(&self.pool, agenda_id).await
+    }
+
+    async fn find_receipt(&self, agenda_id: Uuid, receipt: &str) -> Result<Option<ReceiptEntity>, sqlx::Error> {
+        self::receipt::find(&self.pool, agenda_id, receipt).await
+    }
+
+    async fn find_receipt_root(&self, agenda_id: Uuid) -> Result<Option<ReceiptRootEntity>, sqlx::Error> {
+        self::receipt::find_root(&self.pool, agenda_id).await
+    }
+
+    async fn find_counted_receipts(&self, agenda_id: Uuid) -> Result<Vec<String>, sqlx::Error> {
+        self::receipt::find_counted(&self.pool, agenda_id).await
     }
 }
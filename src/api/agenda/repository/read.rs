@@ -0,0 +1,117 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::{AgendaCursor, AgendaEntity, AgendaOptionEntity, AgendaStatus, VotingMethod, DecisionRule, TiePolicy};
+
+/// 안건 단건 조회
+pub async fn find_by_id(pool: &PgPool, id: Uuid) -> Result<Option<AgendaEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        AgendaEntity,
+        r#"SELECT id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                  status as "status: AgendaStatus", opens_at, closes_at,
+                  voting_method as "voting_method: VotingMethod",
+                  decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                  quorum, hide_results
+           FROM agendas
+           WHERE id = $1"#,
+        id
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+/// 안건 목록 조회 (Keyset 페이지네이션, 최신순)
+///
+/// `cursor`가 있으면 그 안건보다 "이전"(created_at, id 기준)인 안건만 조회합니다.
+/// OFFSET 방식과 달리 페이지가 깊어져도 idx_agendas_created_at 인덱스 탐색 한 번으로 끝납니다.
+pub async fn list(
+    pool: &PgPool,
+    cursor: Option<AgendaCursor>,
+    limit: i64,
+) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+    let (cursor_at, cursor_id) = cursor.map(|c| (c.created_at, c.id)).unzip();
+
+    sqlx::query_as!(
+        AgendaEntity,
+        r#"SELECT id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                  status as "status: AgendaStatus", opens_at, closes_at,
+                  voting_method as "voting_method: VotingMethod",
+                  decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                  quorum, hide_results
+           FROM agendas
+           WHERE $1::timestamptz IS NULL OR (created_at, id) < ($1, $2)
+           ORDER BY created_at DESC, id DESC
+           LIMIT $3"#,
+        cursor_at,
+        cursor_id,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// 특정 사용자가 만든 안건 목록 조회 (Keyset 페이지네이션, 최신순)
+///
+/// `created_by` 조건은 idx_agendas_created_by 인덱스를 사용합니다.
+pub async fn list_by_creator(
+    pool: &PgPool,
+    created_by: Uuid,
+    cursor: Option<AgendaCursor>,
+    limit: i64,
+) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+    let (cursor_at, cursor_id) = cursor.map(|c| (c.created_at, c.id)).unzip();
+
+    sqlx::query_as!(
+        AgendaEntity,
+        r#"SELECT id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                  status as "status: AgendaStatus", opens_at, closes_at,
+                  voting_method as "voting_method: VotingMethod",
+                  decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                  quorum, hide_results
+           FROM agendas
+           WHERE created_by = $1
+             AND ($2::timestamptz IS NULL OR (created_at, id) < ($2, $3))
+           ORDER BY created_at DESC, id DESC
+           LIMIT $4"#,
+        created_by,
+        cursor_at,
+        cursor_id,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// 여러 안건의 선택지 한 번에 조회 (목록 응답의 N+1 쿼리 방지)
+pub async fn find_options(pool: &PgPool, agenda_ids: &[Uuid]) -> Result<Vec<AgendaOptionEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        AgendaOptionEntity,
+        "SELECT id, agenda_id, position, label, vote_count
+         FROM agenda_options
+         WHERE agenda_id = ANY($1)
+         ORDER BY agenda_id, position",
+        agenda_ids
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// 안건의 선택지 투표 용지 전체 조회 (집계용, 기권 제외)
+///
+/// 한 표 = 선호 순서대로 나열한 선택지 ID 목록
+/// - 단일 선택: `[option_id]`
+/// - 승인/순위 투표: vote_selections를 rank 순으로
+pub async fn find_ballots(pool: &PgPool, agenda_id: Uuid) -> Result<Vec<Vec<Uuid>>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT COALESCE(
+                      ARRAY_AGG(s.option_id ORDER BY s.rank) FILTER (WHERE s.option_id IS NOT NULL),
+                      ARRAY[v.option_id]
+                  ) AS "ballot!"
+           FROM votes v
+           LEFT JOIN vote_selections s ON s.vote_id = v.id
+           WHERE v.agenda_id = $1 AND v.is_agree IS NULL AND NOT v.abstain
+           GROUP BY v.id, v.option_id"#,
+        agenda_id
+    )
+    .fetch_all(pool)
+    .await
+}
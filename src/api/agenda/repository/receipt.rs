@@ -0,0 +1,116 @@
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+use crate::api::agenda::receipt::{commit, merkle_root, new_salt};
+use super::{Choice, ReceiptEntity, ReceiptRootEntity, ReceiptStatus};
+
+/// 새 영수증 발급 (투표/변경 트랜잭션 안에서 호출)
+pub async fn issue(
+    tx: &mut Transaction<'_, Postgres>,
+    agenda_id: Uuid,
+    vote_id: Uuid,
+    choice: &Choice,
+) -> Result<String, sqlx::Error> {
+    let salt = new_salt();
+    let receipt = commit(&salt, agenda_id, vote_id, choice);
+
+    sqlx::query!(
+        "INSERT INTO vote_receipts (receipt, agenda_id, vote_id, salt) VALUES ($1, $2, $3, $4)",
+        receipt,
+        agenda_id,
+        vote_id,
+        salt
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(receipt)
+}
+
+/// 집계 중인 영수증을 `status`(superseded / retracted)로 변경
+pub async fn revoke(tx: &mut Transaction<'_, Postgres>, vote_id: Uuid, status: ReceiptStatus) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE vote_receipts SET status = $2 WHERE vote_id = $1 AND status = 'counted'",
+        vote_id,
+        status as ReceiptStatus
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+/// 투표의 집계 중인 영수증
+/// - 영수증 도입 전의 투표라 없으면 지금 선택으로 새로 발급
+pub async fn current(
+    tx: &mut Transaction<'_, Postgres>,
+    agenda_id: Uuid,
+    vote_id: Uuid,
+    choice: &Choice,
+) -> Result<String, sqlx::Error> {
+    let receipt = sqlx::query_scalar!(
+        "SELECT receipt FROM vote_receipts WHERE vote_id = $1 AND status = 'counted'",
+        vote_id
+    )
+    .fetch_optional(&mut **tx)
+    .await?;
+
+    match receipt {
+        Some(receipt) => Ok(receipt),
+        None => issue(tx, agenda_id, vote_id, choice).await,
+    }
+}
+
+/// 마감한 안건의 영수증 Merkle 루트 기록 (마감 트랜잭션 안에서 호출, 이미 있으면 그대로 둠)
+/// - 마감 UPDATE가 안건 행을 배타 잠금하므로 진행 중인 투표가 끝난 뒤의 영수증 목록으로 계산됩니다.
+pub async fn publish_root(tx: &mut Transaction<'_, Postgres>, agenda_id: Uuid) -> Result<(), sqlx::Error> {
+    let receipts = sqlx::query_scalar!(
+        "SELECT receipt FROM vote_receipts WHERE agenda_id = $1 AND status = 'counted'",
+        agenda_id
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO receipt_roots (agenda_id, root, leaf_count) VALUES ($1, $2, $3)
+         ON CONFLICT (agenda_id) DO NOTHING",
+        agenda_id,
+        merkle_root(&receipts),
+        receipts.len() as i32
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn find(pool: &PgPool, agenda_id: Uuid, receipt: &str) -> Result<Option<ReceiptEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ReceiptEntity,
+        r#"SELECT receipt, agenda_id, status as "status: ReceiptStatus", created_at
+           FROM vote_receipts
+           WHERE receipt = $1 AND agenda_id = $2"#,
+        receipt,
+        agenda_id
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn find_root(pool: &PgPool, agenda_id: Uuid) -> Result<Option<ReceiptRootEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ReceiptRootEntity,
+        "SELECT agenda_id, root, leaf_count, published_at FROM receipt_roots WHERE agenda_id = $1",
+        agenda_id
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn find_counted(pool: &PgPool, agenda_id: Uuid) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar!(
+        "SELECT receipt FROM vote_receipts WHERE agenda_id = $1 AND status = 'counted'",
+        agenda_id
+    )
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use super::counter::{self, CounterBuffer, PendingCounts};
+use super::{CounterDrift, CounterKind};
+
+/// 캐싱 카운터가 votes와 어긋난 안건 찾기 (전체 스캔)
+/// - 반영 대기 중인 증감을 먼저 DB에 반영한 뒤 비교합니다.
+pub async fn find_drifts(pool: &PgPool, counters: &CounterBuffer) -> Result<Vec<CounterDrift>, sqlx::Error> {
+    let _guard = counters.lock_flush().await;
+    counter::write_pending(pool, counters).await?;
+
+    let mut conn = pool.acquire().await?;
+    drifts(&mut conn, None).await
+}
+
+/// 안건 하나의 카운터를 votes 기준으로 복구 후 고친 내역 반환
+///
+/// 투표 트랜잭션이 공유 잠금을 잡는 안건 행을 배타 잠금한 뒤 버퍼의 증감을 반영하고 다시 계산하므로,
+/// 진행 중인 투표나 아직 반영되지 않은 증감 때문에 정상 카운터를 덮어쓰지 않습니다.
+/// 잠금을 잡은 시점에 이미 맞다면 빈 목록을 반환합니다.
+/// 단, 비우는 버퍼는 이 서버의 것뿐이므로 다른 서버에 남은 증감은 복구 후 반영되어 다시 어긋납니다.
+pub async fn repair(pool: &PgPool, counters: &CounterBuffer, agenda_id: Uuid) -> Result<Vec<CounterDrift>, sqlx::Error> {
+    let _guard = counters.lock_flush().await;
+    let mut tx = pool.begin().await?;
+
+    sqlx::query_scalar!("SELECT id FROM agendas WHERE id = $1 FOR UPDATE", agenda_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    let pending: HashMap<_, _> = counters.take(agenda_id).map(|p| (agenda_id, p)).into_iter().collect();
+    let result = async {
+        let drifts = fix(&mut tx, agenda_id, &pending).await?;
+        tx.commit().await?;
+        Ok(drifts)
+    }
+    .await;
+
+    if result.is_err() {
+        counters.restore(pending);
+    }
+    result
+}
+
+/// 버퍼의 증감 반영 후 어긋난 카운터를 재계산 값으로 덮어쓰기
+async fn fix(
+    conn: &mut PgConnection,
+    agenda_id: Uuid,
+    pending: &HashMap<Uuid, PendingCounts>,
+) -> Result<Vec<CounterDrift>, sqlx::Error> {
+    counter::apply(&mut *conn, pending).await?;
+
+    let drifts = drifts(&mut *conn, Some(agenda_id)).await?;
+    for drift in &drifts {
+        let actual = drift.actual as i32;
+        match drift.counter {
+            CounterKind::Agree => {
+                sqlx::query!("UPDATE agendas SET agree_count = $2 WHERE id = $1", agenda_id, actual)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+            CounterKind::Disagree => {
+                sqlx::query!("UPDATE agendas SET disagree_count = $2 WHERE id = $1", agenda_id, actual)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+            CounterKind::Abstain => {
+                sqlx::query!("UPDATE agendas SET abstain_count = $2 WHERE id = $1", agenda_id, actual)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+            CounterKind::Option => {
+                sqlx::query!(
+                    "UPDATE agenda_options SET vote_count = $3 WHERE agenda_id = $1 AND id = $2",
+                    agenda_id,
+                    drift.option_id,
+                    actual
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
+        }
+    }
+
+    Ok(drifts)
+}
+
+/// 캐싱 카운터 vs votes 재계산 비교 (`agenda_id`가 없으면 전체 안건)
+///
+/// - 찬/반/기권: votes의 is_agree / abstain 건수
+/// - 선택지: 단일 선택(option_id) + 승인 투표의 모든 선택 + 순위 투표의 1순위
+async fn drifts(conn: &mut PgConnection, agenda_id: Option<Uuid>) -> Result<Vec<CounterDrift>, sqlx::Error> {
+    let agendas = sqlx::query!(
+        r#"SELECT a.id, a.agree_count, a.disagree_count, a.abstain_count,
+                  COUNT(v.id) FILTER (WHERE v.is_agree) AS "agree!",
+                  COUNT(v.id) FILTER (WHERE NOT v.is_agree) AS "disagree!",
+                  COUNT(v.id) FILTER (WHERE v.abstain) AS "abstain!"
+           FROM agendas a
+           LEFT JOIN votes v ON v.agenda_id = a.id
+           WHERE $1::uuid IS NULL OR a.id = $1
+           GROUP BY a.id
+           HAVING a.agree_count <> COUNT(v.id) FILTER (WHERE v.is_agree)
+               OR a.disagree_count <> COUNT(v.id) FILTER (WHERE NOT v.is_agree)
+               OR a.abstain_count <> COUNT(v.id) FILTER (WHERE v.abstain)"#,
+        agenda_id
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let options = sqlx::query!(
+        r#"SELECT o.id, o.agenda_id, o.vote_count, c.actual AS "actual!"
+           FROM agenda_options o
+           JOIN agendas a ON a.id = o.agenda_id
+           CROSS JOIN LATERAL (
+               SELECT (SELECT COUNT(*) FROM votes v WHERE v.option_id = o.id)
+                    + (SELECT COUNT(*) FROM vote_selections s
+                       WHERE s.option_id = o.id
+                         AND (a.voting_method = 'approval' OR s.rank = 0)) AS actual
+           ) c
+           WHERE ($1::uuid IS NULL OR o.agenda_id = $1)
+             AND o.vote_count <> c.actual
+           ORDER BY o.agenda_id, o.position"#,
+        agenda_id
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut drifts = Vec::new();
+    for row in agendas {
+        for (counter, cached, actual) in [
+            (CounterKind::Agree, row.agree_count, row.agree),
+            (CounterKind::Disagree, row.disagree_count, row.disagree),
+            (CounterKind::Abstain, row.abstain_count, row.abstain),
+        ] {
+            if i64::from(cached) != actual {
+                drifts.push(CounterDrift { agenda_id: row.id, option_id: None, counter, cached, actual });
+            }
+        }
+    }
+    for row in options {
+        drifts.push(CounterDrift {
+            agenda_id: row.agenda_id,
+            option_id: Some(row.id),
+            counter: CounterKind::Option,
+            cached: row.vote_count,
+            actual: row.actual,
+        });
+    }
+
+    Ok(drifts)
+}
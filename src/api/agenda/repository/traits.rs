@@ -1,9 +1,72 @@
 use async_trait::async_trait;
 use uuid::Uuid;
-use super::AgendaEntity;
+use super::{
+    AgendaCursor, AgendaEntity, AgendaOptionEntity, AgendaStatus, AuditEntry, AuditTrail, Choice, CounterDrift,
+    NewAgenda, ReceiptEntity, ReceiptRootEntity, RecordedVote, ScheduleChanges,
+};
 
 #[async_trait]
 pub trait AgendaRepository: Send + Sync {
-    /// 안건 생성
-    async fn create(&self, title: &str, created_by: Uuid) -> Result<AgendaEntity, sqlx::Error>;
+    /// 안건 생성 (선택지가 있으면 함께 저장)
+    async fn create(&self, agenda: &NewAgenda) -> Result<AgendaEntity, sqlx::Error>;
+
+    /// 안건 단건 조회
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AgendaEntity>, sqlx::Error>;
+
+    /// 안건 목록 조회 (최신순, `cursor` 이후 최대 `limit`개)
+    async fn list(&self, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error>;
+
+    /// 특정 사용자가 만든 안건 목록 조회 (최신순, `cursor` 이후 최대 `limit`개)
+    async fn list_by_creator(&self, created_by: Uuid, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error>;
+
+    /// 여러 안건의 선택지 조회 (agenda_id, position 순)
+    async fn find_options(&self, agenda_ids: &[Uuid]) -> Result<Vec<AgendaOptionEntity>, sqlx::Error>;
+
+    /// 선택지 투표 용지 전체 조회 (한 표 = 선호 순서대로 나열한 선택지 ID 목록)
+    async fn find_ballots(&self, agenda_id: Uuid) -> Result<Vec<Vec<Uuid>>, sqlx::Error>;
+
+    /// 투표 후 갱신된 안건 + 새 영수증 반환
+    /// - 안건 없음 또는 투표 기간이 아님: `sqlx::Error::RowNotFound`
+    /// - 중복 투표: unique 제약(`uk_vote_user_agenda`) 위반
+    async fn vote(&self, agenda_id: Uuid, user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error>;
+
+    /// 기존 투표의 선택 변경 후 갱신된 안건 + 영수증 반환 (선택이 바뀌면 새 영수증, 이전 영수증은 superseded)
+    /// - 투표 내역 없음 또는 투표 기간이 아님: `sqlx::Error::RowNotFound`
+    async fn change_vote(&self, agenda_id: Uuid, user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error>;
+
+    /// 투표 철회 후 갱신된 안건 반환 (영수증은 retracted)
+    /// - 투표 내역 없음 또는 투표 기간이 아님: `sqlx::Error::RowNotFound`
+    async fn retract_vote(&self, agenda_id: Uuid, user_id: Uuid) -> Result<AgendaEntity, sqlx::Error>;
+
+    /// 상태 전이 (현재 DB 상태가 `from`일 때만 `to`로 변경, 마감이면 영수증 Merkle 루트 공개)
+    /// - `None`: 안건이 없거나 그 사이 다른 요청이 상태를 바꿈
+    async fn update_status(&self, id: Uuid, from: AgendaStatus, to: AgendaStatus) -> Result<Option<AgendaEntity>, sqlx::Error>;
+
+    /// 예약 시각이 지난 안건의 상태 전이 (draft → open, open → closed + 영수증 Merkle 루트 공개)
+    async fn apply_schedule(&self) -> Result<ScheduleChanges, sqlx::Error>;
+
+    /// 캐싱 카운터(찬/반/기권, 선택지 득표)를 votes에서 다시 세어 어긋난 것만 반환
+    async fn find_counter_drifts(&self) -> Result<Vec<CounterDrift>, sqlx::Error>;
+
+    /// 안건 하나의 캐싱 카운터를 votes 기준으로 복구 후 고친 내역 반환
+    /// - 안건 없음: `sqlx::Error::RowNotFound`
+    async fn repair_counters(&self, agenda_id: Uuid) -> Result<Vec<CounterDrift>, sqlx::Error>;
+
+    /// 쓰기 지연 중인 카운터 증감을 DB에 반영 후 반영한 안건 수 반환
+    async fn flush_counters(&self) -> Result<usize, sqlx::Error>;
+
+    /// 감사 로그의 마지막 줄 (체인 머리, 기록이 없으면 `None`)
+    async fn find_audit_head(&self, agenda_id: Uuid) -> Result<Option<AuditEntry>, sqlx::Error>;
+
+    /// 감사 로그 전체와 현재 투표들을 같은 시점 기준으로 조회
+    async fn find_audit_trail(&self, agenda_id: Uuid) -> Result<AuditTrail, sqlx::Error>;
+
+    /// 안건의 영수증 하나 (다른 안건의 영수증이면 `None`)
+    async fn find_receipt(&self, agenda_id: Uuid, receipt: &str) -> Result<Option<ReceiptEntity>, sqlx::Error>;
+
+    /// 마감 때 공개한 영수증 Merkle 루트 (마감 전이면 `None`)
+    async fn find_receipt_root(&self, agenda_id: Uuid) -> Result<Option<ReceiptRootEntity>, sqlx::Error>;
+
+    /// 집계 중인(counted) 영수증 전체
+    async fn find_counted_receipts(&self, agenda_id: Uuid) -> Result<Vec<String>, sqlx::Error>;
 }
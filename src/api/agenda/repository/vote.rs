@@ -0,0 +1,270 @@
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+use crate::api::outbox::{event::DomainEvent, repository::append::append};
+use super::audit;
+use super::receipt;
+use super::counter::CounterBuffer;
+use super::{AgendaEntity, AgendaStatus, AuditAction, Choice, ReceiptStatus, VotingMethod, DecisionRule, TiePolicy};
+
+/// 투표 DB 로직 (단일 트랜잭션)
+///
+/// 1. 투표 기간인 안건 행 공유 잠금 - 안건이 없거나 투표 기간이 아니면 `RowNotFound`
+/// 2. votes INSERT - 이미 투표했다면 `uk_vote_user_agenda` 위반,
+///    다른 안건의 선택지라면 `fk_vote_option` 위반으로 실패
+/// 3. 승인/순위 투표면 vote_selections INSERT (`fk_selection_option`으로 같은 검증)
+/// 4. 영수증 발급
+/// 5. 선택에 해당하는 카운터 +1을 버퍼에 기록 (캐싱 컬럼은 flush 때 반영)
+/// 6. 커밋 직전에 안건별 기록 잠금 후 `vote.cast` 이벤트를 outbox에, `cast` 줄을 감사 로그에 기록
+///
+/// 하나라도 실패하면 트랜잭션이 롤백되고 버퍼에 기록한 증감도 되돌려집니다.
+/// 반환되는 안건의 카운터는 DB 값 그대로이므로 호출자가 버퍼의 증감을 더해야 합니다.
+pub async fn cast(
+    pool: &PgPool,
+    counters: &CounterBuffer,
+    agenda_id: Uuid,
+    user_id: Uuid,
+    choice: Choice,
+) -> Result<(AgendaEntity, String), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    lock_open_agenda(&mut tx, agenda_id).await?;
+
+    let vote_id = Uuid::new_v4();
+    let (is_agree, option_id) = choice.to_columns();
+    sqlx::query!(
+        "INSERT INTO votes (id, user_id, agenda_id, is_agree, option_id, abstain, created_at) VALUES ($1, $2, $3, $4, $5, $6, NOW())",
+        vote_id,
+        user_id,
+        agenda_id,
+        is_agree,
+        option_id,
+        choice == Choice::Abstain
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    insert_selections(&mut tx, vote_id, agenda_id, &choice).await?;
+    let receipt = receipt::issue(&mut tx, agenda_id, vote_id, &choice).await?;
+    let agenda = fetch_agenda(&mut tx, agenda_id).await?;
+    let event = DomainEvent::vote_cast(agenda_id, user_id, &choice);
+    let recorded = counters.record(agenda_id, vec![(choice.clone(), 1)]);
+
+    append_event(&mut tx, agenda_id, event).await?;
+    audit::append(&mut tx, agenda_id, AuditAction::Cast, vote_id, user_id, Some(&choice)).await?;
+
+    tx.commit().await?;
+    recorded.commit();
+
+    Ok((agenda, receipt))
+}
+
+/// 투표 변경 DB 로직 (단일 트랜잭션)
+///
+/// 1. 투표 기간인 안건 + 기존 투표 조회 (행 잠금) - 둘 중 하나라도 없으면 `RowNotFound`
+/// 2. 선택이 바뀐 경우에만 votes UPDATE + vote_selections 교체
+///    + 이전 영수증은 superseded, 새 영수증 발급
+///    + 이전 선택 카운터 -1, 새 선택 카운터 +1을 버퍼에 기록
+///    + 커밋 직전에 안건별 기록 잠금 후 `vote.changed` 이벤트와 `changed` 감사 로그 기록
+///
+/// 같은 선택으로 다시 요청하면 카운터를 건드리지 않고 현재 안건 상태와 지금 영수증만 반환합니다.
+pub async fn change(
+    pool: &PgPool,
+    counters: &CounterBuffer,
+    agenda_id: Uuid,
+    user_id: Uuid,
+    choice: Choice,
+) -> Result<(AgendaEntity, String), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let method = lock_open_agenda(&mut tx, agenda_id).await?;
+    let (vote_id, previous) = lock_vote(&mut tx, agenda_id, user_id, method).await?;
+
+    let mut event = None;
+    let receipt = if previous != choice {
+        let (is_agree, option_id) = choice.to_columns();
+        sqlx::query!(
+            "UPDATE votes SET is_agree = $2, option_id = $3, abstain = $4 WHERE id = $1",
+            vote_id,
+            is_agree,
+            option_id,
+            choice == Choice::Abstain
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!("DELETE FROM vote_selections WHERE vote_id = $1", vote_id)
+            .execute(&mut *tx)
+            .await?;
+        insert_selections(&mut tx, vote_id, agenda_id, &choice).await?;
+        receipt::revoke(&mut tx, vote_id, ReceiptStatus::Superseded).await?;
+        let receipt = receipt::issue(&mut tx, agenda_id, vote_id, &choice).await?;
+
+        event = Some(DomainEvent::vote_changed(agenda_id, user_id, &previous, &choice));
+        receipt
+    } else {
+        receipt::current(&mut tx, agenda_id, vote_id, &choice).await?
+    };
+
+    let agenda = fetch_agenda(&mut tx, agenda_id).await?;
+    let recorded = match event {
+        Some(event) => {
+            let recorded = counters.record(agenda_id, vec![(previous, -1), (choice.clone(), 1)]);
+            append_event(&mut tx, agenda_id, event).await?;
+            audit::append(&mut tx, agenda_id, AuditAction::Changed, vote_id, user_id, Some(&choice)).await?;
+            Some(recorded)
+        }
+        None => None,
+    };
+
+    tx.commit().await?;
+    if let Some(recorded) = recorded {
+        recorded.commit();
+    }
+
+    Ok((agenda, receipt))
+}
+
+/// 투표 철회 DB 로직 (단일 트랜잭션)
+///
+/// 1. 투표 기간인 안건 잠금 - 없으면 `RowNotFound`
+/// 2. 기존 투표 조회 후 votes DELETE (vote_selections는 CASCADE) - 투표 내역이 없으면 `RowNotFound`
+/// 3. 영수증은 retracted
+/// 4. 삭제된 투표의 선택에 해당하는 카운터 -1을 버퍼에 기록
+/// 5. 커밋 직전에 안건별 기록 잠금 후 `vote.retracted` 이벤트와 `retracted` 감사 로그 기록
+pub async fn retract(
+    pool: &PgPool,
+    counters: &CounterBuffer,
+    agenda_id: Uuid,
+    user_id: Uuid,
+) -> Result<AgendaEntity, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let method = lock_open_agenda(&mut tx, agenda_id).await?;
+    let (vote_id, previous) = lock_vote(&mut tx, agenda_id, user_id, method).await?;
+
+    sqlx::query!("DELETE FROM votes WHERE id = $1", vote_id)
+        .execute(&mut *tx)
+        .await?;
+    receipt::revoke(&mut tx, vote_id, ReceiptStatus::Retracted).await?;
+    let agenda = fetch_agenda(&mut tx, agenda_id).await?;
+    let event = DomainEvent::vote_retracted(agenda_id, user_id, &previous);
+    let recorded = counters.record(agenda_id, vec![(previous, -1)]);
+
+    append_event(&mut tx, agenda_id, event).await?;
+    audit::append(&mut tx, agenda_id, AuditAction::Retracted, vote_id, user_id, None).await?;
+
+    tx.commit().await?;
+    recorded.commit();
+
+    Ok(agenda)
+}
+
+/// 투표 기간인 안건 행 공유 잠금 후 투표 방식 반환
+/// - 마감 처리, 카운터 반영/복구(UPDATE)와는 엇갈리지 않음
+/// - 같은 안건의 투표끼리는 커밋 직전 기록 잠금(`append_event`)을 잡기 전까지 동시에 진행
+/// - 안건이 없거나 투표 기간이 아니면 `RowNotFound`
+async fn lock_open_agenda(tx: &mut Transaction<'_, Postgres>, agenda_id: Uuid) -> Result<VotingMethod, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT voting_method as "voting_method: VotingMethod" FROM agendas
+           WHERE id = $1
+             AND (status = 'open' OR (status = 'draft' AND opens_at <= NOW()))
+             AND (closes_at IS NULL OR closes_at > NOW())
+           FOR SHARE"#,
+        agenda_id
+    )
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(sqlx::Error::RowNotFound)
+}
+
+/// 안건별 기록 잠금(advisory lock, 트랜잭션이 끝날 때 해제)을 잡은 뒤 이벤트를 outbox에 기록
+///
+/// 같은 안건의 투표끼리는 안건 행 공유 잠금만 잡고 동시에 진행되므로, outbox id를 매기기 전에 한 트랜잭션씩 줄 세웁니다.
+/// 잠금 없이 먼저 id를 받은 트랜잭션이 늦게 커밋하면 relay가 뒤 id의 이벤트를 먼저 발행합니다.
+/// 이어서 기록하는 감사 로그도 이 잠금으로 체인 머리 조회 → 추가가 직렬화됩니다.
+///
+/// 잠금은 커밋할 때까지 유지되므로, 같은 안건의 투표가 줄 서는 구간이 짧도록
+/// 다른 쓰기와 조회를 모두 마친 뒤 커밋 직전에 (감사 로그 추가만 남기고) 호출합니다.
+pub async fn append_event(
+    tx: &mut Transaction<'_, Postgres>,
+    agenda_id: Uuid,
+    event: DomainEvent,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!("SELECT pg_advisory_xact_lock(hashtextextended($1::uuid::text, 0))", agenda_id)
+        .execute(&mut **tx)
+        .await?;
+
+    append(tx, &[event]).await
+}
+
+/// 기존 투표 행 잠금 후 (vote_id, 선택) 반환
+/// - 투표 내역이 없으면 `RowNotFound`
+async fn lock_vote(
+    tx: &mut Transaction<'_, Postgres>,
+    agenda_id: Uuid,
+    user_id: Uuid,
+    method: VotingMethod,
+) -> Result<(Uuid, Choice), sqlx::Error> {
+    let row = sqlx::query!(
+        "SELECT id, is_agree, option_id, abstain FROM votes WHERE agenda_id = $1 AND user_id = $2 FOR UPDATE",
+        agenda_id,
+        user_id
+    )
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or(sqlx::Error::RowNotFound)?;
+
+    let selections = sqlx::query_scalar!(
+        "SELECT option_id FROM vote_selections WHERE vote_id = $1 ORDER BY rank",
+        row.id
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+
+    let choice = Choice::from_columns(row.is_agree, row.option_id, row.abstain, selections, method)
+        .ok_or(sqlx::Error::RowNotFound)?;
+
+    Ok((row.id, choice))
+}
+
+/// 승인/순위 투표의 선택 목록 저장 (rank: 배열 순서, 0부터)
+async fn insert_selections(
+    tx: &mut Transaction<'_, Postgres>,
+    vote_id: Uuid,
+    agenda_id: Uuid,
+    choice: &Choice,
+) -> Result<(), sqlx::Error> {
+    let selections = choice.selections();
+    if selections.is_empty() {
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "INSERT INTO vote_selections (vote_id, agenda_id, option_id, rank)
+         SELECT $1, $2, option_id, (ord - 1)::int
+         FROM UNNEST($3::uuid[]) WITH ORDINALITY AS t(option_id, ord)",
+        vote_id,
+        agenda_id,
+        selections
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
+
+async fn fetch_agenda(tx: &mut Transaction<'_, Postgres>, agenda_id: Uuid) -> Result<AgendaEntity, sqlx::Error> {
+    sqlx::query_as!(
+        AgendaEntity,
+        r#"SELECT id, title, created_by, created_at, agree_count, disagree_count, abstain_count,
+                  status as "status: AgendaStatus", opens_at, closes_at,
+                  voting_method as "voting_method: VotingMethod",
+                  decision_rule as "decision_rule: DecisionRule", tie_policy as "tie_policy: TiePolicy",
+                  quorum, hide_results
+           FROM agendas
+           WHERE id = $1"#,
+        agenda_id
+    )
+    .fetch_one(&mut **tx)
+    .await
+}
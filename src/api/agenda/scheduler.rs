@@ -0,0 +1,43 @@
+//! 안건 예약 상태 전이 스케줄러
+//!
+//! opens_at / closes_at이 지난 안건을 주기적으로 draft → open, open → closed로 옮깁니다.
+//! 응답의 status는 예약 시각을 반영해 계산되지만(`effective_status`),
+//! DB의 status 컬럼도 실제 시각을 따라가야 목록/통계 쿼리가 정확해집니다.
+//! 옮긴 안건은 실시간 집계에도 표시해 구독자가 바뀐 상태(마감이면 최종 집계)를 받게 합니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use super::live::LiveTally;
+use super::repository::traits::AgendaRepository;
+use crate::api::state::Agendas;
+
+/// 백그라운드 스케줄러 시작 (`period` 마다 한 번씩 실행)
+pub fn spawn(repo: Agendas, live: Arc<LiveTally>, period: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            run_once(repo.as_ref(), &live).await;
+        }
+    })
+}
+
+/// 스케줄러 1회 실행 - 실패해도 다음 주기에 다시 시도하므로 로그만 남깁니다.
+pub async fn run_once<R: AgendaRepository + ?Sized>(repo: &R, live: &LiveTally) {
+    match repo.apply_schedule().await {
+        Ok(changes) => {
+            for id in &changes.opened {
+                println!("⏰ Agenda opened: {}", id);
+                live.mark(*id);
+            }
+            for id in &changes.closed {
+                println!("⏰ Agenda closed: {}", id);
+                live.mark(*id);
+            }
+        }
+        Err(e) => eprintln!("❌ Agenda scheduler failed: {}", e),
+    }
+}
@@ -1,27 +1,742 @@
+use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use crate::api::error::AppError;
-use super::repository::traits::AgendaRepository;
-use super::dtos::AgendaResponse;
+use crate::api::validation::Validate;
+use super::repository::{
+    traits::AgendaRepository, AgendaCursor, AgendaEntity, AgendaOptionEntity, AgendaStatus, AuditEntry, Choice,
+    CounterDrift, NewAgenda, ReceiptStatus, VotingMethod,
+};
+use super::dtos::{
+    AgendaListResponse, AgendaOptionResponse, AgendaResponse, AuditHeadResponse, AuditReport, CounterDriftResponse,
+    CreateAgendaRequest, InclusionProof, ReceiptResponse, ReceiptRootResponse, ReconcileReport, ResultResponse,
+    TallyResponse, TallyRoundResponse, TallyScoreResponse, VoteRequest, VoteResponse,
+};
+use super::audit::{self, GENESIS_HASH};
+use super::decision::{self, Rules};
+use super::receipt;
+use super::tally::{self, Tally};
+
+/// 목록 조회 기본/최대 페이지 크기
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
 
 /// 안건 생성 비즈니스 로직
-pub async fn create_agenda<R: AgendaRepository>(
+///
+/// - 제목/선택지는 `CreateAgendaRequest`의 검증 규칙으로 정규화 후 검사
+/// - `opens_at`이 미래면 draft, 아니면 바로 open 상태로 생성
+/// - `closes_at`은 미래여야 하고 `opens_at`보다 뒤여야 함 (아니면 400 BadRequest)
+/// - `options`가 비어 있으면 찬/반 안건, 있으면 2~10개의 서로 다른 선택지
+/// - 찬/반 안건은 single_choice만 가능 (아니면 400 BadRequest)
+/// - `quorum`은 1 이상 (아니면 400 BadRequest)
+pub async fn create_agenda<R: AgendaRepository + ?Sized>(
     repo: &R,
-    title: String,
+    payload: CreateAgendaRequest,
     created_by: Uuid // Renamed for consistency
 ) -> Result<AgendaResponse, AppError> {
-    
+    let payload = payload.validated()?;
+    let now = Utc::now();
+
+    if let Some(closes_at) = payload.closes_at {
+        if closes_at <= now {
+            return Err(AppError::BadRequest("closes_at must be in the future".to_string(), vec![]));
+        }
+        if payload.opens_at.is_some_and(|opens_at| opens_at >= closes_at) {
+            return Err(AppError::BadRequest("opens_at must be before closes_at".to_string(), vec![]));
+        }
+    }
+
+    let options = payload.options;
+    if options.is_empty() && payload.voting_method != VotingMethod::SingleChoice {
+        return Err(AppError::BadRequest("voting_method requires options".to_string(), vec![]));
+    }
+    if payload.quorum.is_some_and(|quorum| quorum < 1) {
+        return Err(AppError::BadRequest("quorum must be at least 1".to_string(), vec![]));
+    }
+
+    let status = match payload.opens_at {
+        Some(opens_at) if opens_at > now => AgendaStatus::Draft,
+        _ => AgendaStatus::Open,
+    };
+
+    let new_agenda = NewAgenda {
+        title: payload.title,
+        created_by,
+        status,
+        opens_at: payload.opens_at,
+        closes_at: payload.closes_at,
+        options,
+        voting_method: payload.voting_method,
+        decision_rule: payload.decision_rule,
+        tie_policy: payload.tie_policy,
+        quorum: payload.quorum,
+        hide_results: payload.hide_results,
+    };
+
     // DB 저장
-    let agenda = repo.create(&title, created_by)
-        .await
-        .map_err(|e| AppError::InternalServerError(format!("Database error: {}", e)))?;
+    let agenda = repo.create(&new_agenda)
+        .await?;
 
     // 응답 변환
-    Ok(AgendaResponse {
+    let options = load_options(repo, &agenda).await?;
+    Ok(to_response(agenda, options))
+}
+
+/// 안건 단건 조회 비즈니스 로직
+///
+/// - 안건 없음 → 404 NotFound
+pub async fn get_agenda<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    id: Uuid,
+) -> Result<AgendaResponse, AppError> {
+    let agenda = find_agenda(repo, id).await?;
+    let options = load_options(repo, &agenda).await?;
+
+    Ok(to_response(agenda, options))
+}
+
+/// 안건 목록 조회 비즈니스 로직 (Keyset 페이지네이션)
+///
+/// - `cursor`: 직전 페이지의 `next_cursor` (형식이 잘못되면 400 BadRequest)
+/// - `limit`: 1 ~ 100 (기본 20)
+pub async fn list_agendas<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    cursor: Option<String>,
+    limit: Option<i64>,
+) -> Result<AgendaListResponse, AppError> {
+    let cursor = cursor.as_deref().map(decode_cursor).transpose()?;
+    let limit = page_size(limit);
+
+    // 다음 페이지 존재 여부를 알기 위해 1개 더 조회
+    let agendas = repo.list(cursor, limit + 1)
+        .await?;
+
+    to_page(repo, agendas, limit).await
+}
+
+/// 내가 만든 안건 목록 조회 비즈니스 로직 (Keyset 페이지네이션)
+pub async fn list_my_agendas<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    user_id: Uuid,
+    cursor: Option<String>,
+    limit: Option<i64>,
+) -> Result<AgendaListResponse, AppError> {
+    let cursor = cursor.as_deref().map(decode_cursor).transpose()?;
+    let limit = page_size(limit);
+
+    let agendas = repo.list_by_creator(user_id, cursor, limit + 1)
+        .await?;
+
+    to_page(repo, agendas, limit).await
+}
+
+/// 안건 상태 전이 비즈니스 로직 (open / close / cancel)
+///
+/// - 안건 없음 → 404 NotFound
+/// - 생성자가 아님 → 403 Forbidden
+/// - 허용되지 않는 전이 (예: closed → open) → 409 Conflict
+pub async fn change_status<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+    user_id: Uuid,
+    to: AgendaStatus,
+) -> Result<AgendaResponse, AppError> {
+    let agenda = find_agenda(repo, agenda_id).await?;
+
+    if agenda.created_by != user_id {
+        return Err(AppError::Forbidden("Only the creator can change the agenda status".to_string()));
+    }
+
+    transition(repo, agenda, to).await
+}
+
+/// 관리자/운영자의 안건 상태 전이 (생성자가 아니어도 가능, 역할은 Extractor에서 확인)
+///
+/// - 안건 없음 → 404 NotFound
+/// - 허용되지 않는 전이 → 409 Conflict
+pub async fn moderate_status<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+    to: AgendaStatus,
+) -> Result<AgendaResponse, AppError> {
+    let agenda = find_agenda(repo, agenda_id).await?;
+    transition(repo, agenda, to).await
+}
+
+async fn transition<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda: AgendaEntity,
+    to: AgendaStatus,
+) -> Result<AgendaResponse, AppError> {
+    let agenda_id = agenda.id;
+    let current = agenda.effective_status(Utc::now());
+    if !current.can_transition_to(to) {
+        return Err(AppError::Conflict(format!(
+            "Cannot change agenda status from {:?} to {:?}",
+            current, to
+        )));
+    }
+
+    // 조회 시점의 DB 상태 기준으로 전이 (그 사이 스케줄러가 바꿨다면 None)
+    let updated = repo.update_status(agenda_id, agenda.status, to)
+        .await?
+        .ok_or(AppError::Conflict("Agenda status changed concurrently, please retry".to_string()))?;
+
+    let options = load_options(repo, &updated).await?;
+    Ok(to_response(updated, options))
+}
+
+/// 투표 비즈니스 로직
+///
+/// - 안건 없음 → 404 NotFound
+/// - 투표 기간이 아님 → 403 AgendaClosed
+/// - 안건 종류와 맞지 않는 선택 (찬/반 안건에 option_id 등) → 400 BadRequest
+/// - 이미 투표함 (unique 제약 위반) → 409 AlreadyVoted
+pub async fn vote<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+    user_id: Uuid,
+    payload: VoteRequest,
+) -> Result<VoteResponse, AppError> {
+    let agenda = ensure_accepting_votes(repo, agenda_id).await?;
+    let options = load_options(repo, &agenda).await?;
+    let choice = to_choice(&payload, agenda.voting_method, &options)?;
+
+    let recorded = repo.vote(agenda_id, user_id, choice.clone())
+        .await
+        .map_err(|e| match e {
+            // 존재는 이미 확인했으므로, 그 사이 마감된 경우
+            sqlx::Error::RowNotFound => AppError::AgendaClosed("Voting is closed for this agenda".to_string()),
+            // uk_vote_user_agenda 위반 → AlreadyVoted
+            e => AppError::from(e),
+        })?;
+
+    to_vote_response(repo, recorded.agenda, choice, recorded.receipt, !options.is_empty()).await
+}
+
+/// 투표 변경 비즈니스 로직
+///
+/// - 투표 기간이 아님 → 403 AgendaClosed
+/// - 안건 종류와 맞지 않는 선택 → 400 BadRequest
+/// - 투표 내역 없음 → 404 NotFound
+pub async fn change_vote<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+    user_id: Uuid,
+    payload: VoteRequest,
+) -> Result<VoteResponse, AppError> {
+    let agenda = ensure_accepting_votes(repo, agenda_id).await?;
+    let options = load_options(repo, &agenda).await?;
+    let choice = to_choice(&payload, agenda.voting_method, &options)?;
+
+    let recorded = repo.change_vote(agenda_id, user_id, choice.clone())
+        .await
+        .map_err(map_vote_not_found)?;
+
+    to_vote_response(repo, recorded.agenda, choice, recorded.receipt, !options.is_empty()).await
+}
+
+/// 투표 철회 비즈니스 로직
+///
+/// - 투표 기간이 아님 → 403 AgendaClosed
+/// - 투표 내역 없음 → 404 NotFound
+pub async fn retract_vote<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), AppError> {
+    ensure_accepting_votes(repo, agenda_id).await?;
+
+    repo.retract_vote(agenda_id, user_id)
+        .await
+        .map_err(map_vote_not_found)?;
+
+    Ok(())
+}
+
+/// 선택지 투표 집계 비즈니스 로직
+///
+/// - 안건 없음 → 404 NotFound
+/// - 마감 전 비공개 안건 → 403 Forbidden
+/// - 찬/반 안건 (선택지 없음) → 400 BadRequest
+pub async fn tally_agenda<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+) -> Result<TallyResponse, AppError> {
+    let agenda = find_visible_agenda(repo, agenda_id).await?;
+    let options = load_options(repo, &agenda).await?;
+    if options.is_empty() {
+        return Err(AppError::BadRequest("Only agendas with options can be tallied".to_string(), vec![]));
+    }
+
+    let result = compute_tally(repo, &agenda, &options).await?;
+
+    let label = |id: Uuid| {
+        options
+            .iter()
+            .find(|o| o.id == id)
+            .map(|o| o.label.clone())
+            .unwrap_or_default()
+    };
+
+    Ok(TallyResponse {
+        agenda_id,
+        voting_method: result.method,
+        total_ballots: result.total_ballots,
+        rounds: result
+            .rounds
+            .into_iter()
+            .map(|round| TallyRoundResponse {
+                scores: round
+                    .scores
+                    .into_iter()
+                    .map(|s| TallyScoreResponse {
+                        option_id: s.option_id,
+                        label: label(s.option_id),
+                        score: s.score,
+                    })
+                    .collect(),
+                eliminated: round.eliminated,
+                exhausted: round.exhausted,
+            })
+            .collect(),
+        winners: result.winners,
+    })
+}
+
+/// 가결 판정 비즈니스 로직
+///
+/// - 안건 없음 → 404 NotFound
+/// - 마감 전 비공개 안건 → 403 Forbidden
+/// - 마감 전이면 현재 시점의 잠정 결과 (`is_final: false`)
+pub async fn get_result<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+) -> Result<ResultResponse, AppError> {
+    let agenda = find_visible_agenda(repo, agenda_id).await?;
+    let options = load_options(repo, &agenda).await?;
+
+    let rules = Rules {
+        rule: agenda.decision_rule,
+        tie_policy: agenda.tie_policy,
+        quorum: agenda.quorum.map(|q| q as u64),
+    };
+    let decision = if options.is_empty() {
+        decision::decide_motion(
+            &rules,
+            agenda.agree_count as u64,
+            agenda.disagree_count as u64,
+            agenda.abstain_count as u64,
+        )
+    } else {
+        let tally = compute_tally(repo, &agenda, &options).await?;
+        decision::decide_election(&rules, &tally, agenda.abstain_count as u64)
+    };
+
+    let status = agenda.effective_status(Utc::now());
+    Ok(ResultResponse {
+        agenda_id,
+        status,
+        is_final: status == AgendaStatus::Closed,
+        outcome: decision.outcome,
+        margin: decision.margin,
+        turnout: decision.turnout,
+        quorum: agenda.quorum,
+        quorum_met: decision.quorum_met,
+        decision_rule: agenda.decision_rule,
+        winner: decision.winner,
+    })
+}
+
+/// 캐싱 카운터 점검/복구 비즈니스 로직
+///
+/// - `repair`가 false면 어긋난 카운터를 보고만 합니다 (dry run)
+/// - true면 어긋난 안건마다 행 잠금 후 다시 계산해 복구하고, 실제로 고친 내역을 보고합니다
+///   (점검과 복구 사이에 사라진 안건은 건너뜀)
+pub async fn reconcile_counters<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    repair: bool,
+) -> Result<ReconcileReport, AppError> {
+    let checked_at = Utc::now();
+    let mut drifts = repo.find_counter_drifts().await?;
+
+    if repair {
+        let mut seen = HashSet::new();
+        let agenda_ids: Vec<Uuid> = drifts
+            .iter()
+            .map(|d| d.agenda_id)
+            .filter(|id| seen.insert(*id))
+            .collect();
+
+        drifts = Vec::new();
+        for agenda_id in agenda_ids {
+            match repo.repair_counters(agenda_id).await {
+                Ok(repaired) => drifts.extend(repaired),
+                Err(sqlx::Error::RowNotFound) => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    let agendas_affected = drifts.iter().map(|d| d.agenda_id).collect::<HashSet<_>>().len();
+    Ok(ReconcileReport {
+        checked_at,
+        repaired: repair,
+        agendas_affected,
+        drifts: drifts.into_iter().map(to_drift_response).collect(),
+    })
+}
+
+/// 감사 로그 체인 머리 조회 비즈니스 로직
+/// - 기록이 없으면 seq 0과 첫 줄의 직전 hash를 반환
+pub async fn audit_head<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+) -> Result<AuditHeadResponse, AppError> {
+    find_agenda(repo, agenda_id).await?;
+    let head = repo.find_audit_head(agenda_id).await?;
+
+    Ok(to_head_response(agenda_id, head.as_ref()))
+}
+
+/// 감사 로그 검증 비즈니스 로직
+///
+/// 같은 시점의 감사 로그와 votes를 읽어 체인을 다시 계산하고 로그를 재생한 결과를 votes와 대조합니다.
+/// 불일치는 에러가 아니라 보고서(`valid: false` + 처음 어긋난 곳)로 돌려줍니다.
+pub async fn verify_audit<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+) -> Result<AuditReport, AppError> {
+    find_agenda(repo, agenda_id).await?;
+    let verified_at = Utc::now();
+    let trail = repo.find_audit_trail(agenda_id).await?;
+    let inconsistency = audit::verify(&trail).err();
+
+    Ok(AuditReport {
+        verified_at,
+        valid: inconsistency.is_none(),
+        entries: trail.entries.len(),
+        votes: trail.votes.len(),
+        head: to_head_response(agenda_id, trail.entries.last()),
+        inconsistency,
+    })
+}
+
+/// 투표 영수증 확인 비즈니스 로직 (누구나)
+///
+/// - 안건 또는 그 안건의 영수증이 없으면 404
+/// - 선택/투표자는 드러내지 않고 상태(counted / superseded / retracted)만 반환
+/// - 마감 후 Merkle 루트가 공개되었고 집계된 영수증이면 포함 증명을 함께 반환
+pub async fn get_receipt<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+    receipt: &str,
+) -> Result<ReceiptResponse, AppError> {
+    find_agenda(repo, agenda_id).await?;
+    let found = repo
+        .find_receipt(agenda_id, receipt)
+        .await?
+        .ok_or(AppError::NotFound("Receipt not found".to_string()))?;
+
+    let inclusion = match (found.status, repo.find_receipt_root(agenda_id).await?) {
+        (ReceiptStatus::Counted, Some(root)) => {
+            let receipts = repo.find_counted_receipts(agenda_id).await?;
+            receipt::merkle_proof(&receipts, &found.receipt).map(|(leaf_index, proof)| InclusionProof {
+                root: root.root,
+                leaf_count: root.leaf_count,
+                leaf_index,
+                proof,
+            })
+        }
+        _ => None,
+    };
+
+    Ok(ReceiptResponse {
+        agenda_id,
+        receipt: found.receipt,
+        status: found.status,
+        recorded_at: found.created_at,
+        inclusion,
+    })
+}
+
+/// 영수증 Merkle 루트 조회 비즈니스 로직 (누구나)
+/// - 마감 전이거나 아직 마감 처리 전이면 404
+pub async fn get_receipt_root<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda_id: Uuid,
+) -> Result<ReceiptRootResponse, AppError> {
+    find_agenda(repo, agenda_id).await?;
+    let root = repo
+        .find_receipt_root(agenda_id)
+        .await?
+        .ok_or(AppError::NotFound("Receipt root is published when the agenda closes".to_string()))?;
+
+    let mut receipts = repo.find_counted_receipts(agenda_id).await?;
+    receipts.sort_unstable();
+
+    Ok(ReceiptRootResponse {
+        agenda_id,
+        root: root.root,
+        leaf_count: root.leaf_count,
+        published_at: root.published_at,
+        receipts,
+    })
+}
+
+/// 저장된 투표 용지로 선택지 집계
+async fn compute_tally<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda: &AgendaEntity,
+    options: &[AgendaOptionEntity],
+) -> Result<Tally, AppError> {
+    let ballots = repo.find_ballots(agenda.id).await?;
+    let option_ids: Vec<Uuid> = options.iter().map(|o| o.id).collect();
+
+    Ok(tally::tally(agenda.voting_method, &option_ids, &ballots))
+}
+
+/// 집계를 볼 수 있는 안건 조회 (hide_results 안건은 마감 전 403)
+async fn find_visible_agenda<R: AgendaRepository + ?Sized>(repo: &R, id: Uuid) -> Result<AgendaEntity, AppError> {
+    let agenda = find_agenda(repo, id).await?;
+
+    if !agenda.results_visible(Utc::now()) {
+        return Err(AppError::Forbidden("Results are hidden until the agenda closes".to_string()));
+    }
+
+    Ok(agenda)
+}
+
+/// 안건 조회 (없으면 404)
+async fn find_agenda<R: AgendaRepository + ?Sized>(repo: &R, id: Uuid) -> Result<AgendaEntity, AppError> {
+    repo.find_by_id(id)
+        .await?
+        .ok_or(AppError::NotFound("Agenda not found".to_string()))
+}
+
+/// 안건의 선택지 조회 (찬/반 안건이면 빈 목록)
+async fn load_options<R: AgendaRepository + ?Sized>(repo: &R, agenda: &AgendaEntity) -> Result<Vec<AgendaOptionEntity>, AppError> {
+    repo.find_options(&[agenda.id])
+        .await
+        .map_err(AppError::from)
+}
+
+/// 투표 기간인지 확인 후 안건 반환
+/// - draft: 아직 시작 전, closed/cancelled: 종료 → 403 AgendaClosed
+async fn ensure_accepting_votes<R: AgendaRepository + ?Sized>(repo: &R, agenda_id: Uuid) -> Result<AgendaEntity, AppError> {
+    let agenda = find_agenda(repo, agenda_id).await?;
+
+    match agenda.effective_status(Utc::now()) {
+        AgendaStatus::Open => Ok(agenda),
+        AgendaStatus::Draft => Err(AppError::AgendaClosed("Voting has not started yet".to_string())),
+        AgendaStatus::Closed | AgendaStatus::Cancelled => {
+            Err(AppError::AgendaClosed("Voting is closed for this agenda".to_string()))
+        }
+    }
+}
+
+/// 기존 투표를 대상으로 하는 작업의 DB 에러 변환 (없으면 404)
+fn map_vote_not_found(e: sqlx::Error) -> AppError {
+    match e {
+        sqlx::Error::RowNotFound => AppError::NotFound("Vote not found".to_string()),
+        e => AppError::from(e),
+    }
+}
+
+/// 투표 요청 → Choice
+/// - 찬/반 안건: `is_agree`만 허용
+/// - single_choice: 이 안건의 `option_id`만 허용
+/// - approval / instant_runoff / borda: 이 안건의 선택지로 이루어진, 중복 없는 `option_ids`만 허용
+/// - 기권: 모든 안건에서 `abstain: true` 단독으로 허용
+fn to_choice(
+    payload: &VoteRequest,
+    method: VotingMethod,
+    options: &[AgendaOptionEntity],
+) -> Result<Choice, AppError> {
+    let known = |id: &Uuid| options.iter().any(|o| o.id == *id);
+
+    if payload.abstain {
+        return match (payload.is_agree, payload.option_id, &payload.option_ids) {
+            (None, None, None) => Ok(Choice::Abstain),
+            _ => Err(AppError::BadRequest("abstain cannot be combined with another choice".to_string(), vec![])),
+        };
+    }
+
+    match (payload.is_agree, payload.option_id, &payload.option_ids) {
+        (Some(is_agree), None, None) if options.is_empty() => {
+            Ok(if is_agree { Choice::Agree } else { Choice::Disagree })
+        }
+        _ if options.is_empty() => Err(AppError::BadRequest("This agenda accepts only is_agree".to_string(), vec![])),
+        (None, Some(option_id), None) if method == VotingMethod::SingleChoice => {
+            if known(&option_id) {
+                Ok(Choice::Option(option_id))
+            } else {
+                Err(AppError::BadRequest("Unknown option for this agenda".to_string(), vec![]))
+            }
+        }
+        _ if method == VotingMethod::SingleChoice => {
+            Err(AppError::BadRequest("This agenda accepts only option_id".to_string(), vec![]))
+        }
+        (None, None, Some(ids)) => {
+            if ids.is_empty() {
+                return Err(AppError::BadRequest("option_ids must not be empty".to_string(), vec![]));
+            }
+            if !ids.iter().all(known) {
+                return Err(AppError::BadRequest("Unknown option for this agenda".to_string(), vec![]));
+            }
+            if ids.iter().collect::<HashSet<_>>().len() != ids.len() {
+                return Err(AppError::BadRequest("option_ids must be unique".to_string(), vec![]));
+            }
+
+            Ok(if method.is_ranked() {
+                Choice::Ranking(ids.clone())
+            } else {
+                Choice::Approval(ids.clone())
+            })
+        }
+        _ => Err(AppError::BadRequest("This agenda accepts only option_ids".to_string(), vec![])),
+    }
+}
+
+/// 투표 결과 응답 (다지선다 안건이면 갱신된 선택지 득표 포함)
+async fn to_vote_response<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    agenda: AgendaEntity,
+    choice: Choice,
+    receipt: String,
+    has_options: bool,
+) -> Result<VoteResponse, AppError> {
+    let options = if has_options { load_options(repo, &agenda).await? } else { Vec::new() };
+    let visible = agenda.results_visible(Utc::now());
+    let (is_agree, option_id) = choice.to_columns();
+    let abstain = choice == Choice::Abstain;
+    let option_ids = match choice {
+        Choice::Approval(ids) | Choice::Ranking(ids) => Some(ids),
+        _ => None,
+    };
+
+    Ok(VoteResponse {
+        agenda_id: agenda.id,
+        is_agree,
+        option_id,
+        option_ids,
+        abstain,
+        agree_count: visible.then_some(agenda.agree_count),
+        disagree_count: visible.then_some(agenda.disagree_count),
+        abstain_count: visible.then_some(agenda.abstain_count),
+        options: options.into_iter().map(|o| to_option_response(o, visible)).collect(),
+        receipt,
+    })
+}
+
+fn to_head_response(agenda_id: Uuid, head: Option<&AuditEntry>) -> AuditHeadResponse {
+    AuditHeadResponse {
+        agenda_id,
+        seq: head.map_or(0, |h| h.seq),
+        hash: head.map_or(GENESIS_HASH, |h| h.hash.as_str()).to_string(),
+        recorded_at: head.map(|h| h.created_at),
+    }
+}
+
+fn to_drift_response(drift: CounterDrift) -> CounterDriftResponse {
+    CounterDriftResponse {
+        agenda_id: drift.agenda_id,
+        option_id: drift.option_id,
+        counter: drift.counter,
+        cached: drift.cached,
+        actual: drift.actual,
+    }
+}
+
+/// 선택지 → 응답 DTO (`visible`이 false면 득표 숨김)
+fn to_option_response(option: AgendaOptionEntity, visible: bool) -> AgendaOptionResponse {
+    AgendaOptionResponse {
+        id: option.id,
+        label: option.label,
+        vote_count: visible.then_some(option.vote_count),
+    }
+}
+
+/// Entity → 응답 DTO 변환
+fn to_response(agenda: AgendaEntity, options: Vec<AgendaOptionEntity>) -> AgendaResponse {
+    let now = Utc::now();
+    let status = agenda.effective_status(now);
+    let visible = agenda.results_visible(now);
+
+    AgendaResponse {
         id: agenda.id,
         title: agenda.title,
-        created_by: agenda.created_by.to_string(), // UUID -> String
+        created_by: agenda.created_by.to_string(),
         created_at: agenda.created_at,
-        agree_count: 0,
-        disagree_count: 0,
+        agree_count: visible.then_some(agenda.agree_count),
+        disagree_count: visible.then_some(agenda.disagree_count),
+        abstain_count: visible.then_some(agenda.abstain_count),
+        status,
+        opens_at: agenda.opens_at,
+        closes_at: agenda.closes_at,
+        options: options.into_iter().map(|o| to_option_response(o, visible)).collect(),
+        voting_method: agenda.voting_method,
+        decision_rule: agenda.decision_rule,
+        tie_policy: agenda.tie_policy,
+        quorum: agenda.quorum,
+        hide_results: agenda.hide_results,
+    }
+}
+
+/// `limit + 1`개 조회 결과 → 페이지 응답
+/// 초과분이 있으면 잘라내고 마지막 항목을 다음 커서로 사용합니다.
+/// 선택지는 페이지 전체에 대해 한 번에 조회합니다.
+async fn to_page<R: AgendaRepository + ?Sized>(
+    repo: &R,
+    mut agendas: Vec<AgendaEntity>,
+    limit: i64,
+) -> Result<AgendaListResponse, AppError> {
+    let has_more = agendas.len() as i64 > limit;
+    agendas.truncate(limit as usize);
+
+    let next_cursor = if has_more {
+        agendas.last().map(|a| encode_cursor(&AgendaCursor { created_at: a.created_at, id: a.id }))
+    } else {
+        None
+    };
+
+    let ids: Vec<Uuid> = agendas.iter().map(|a| a.id).collect();
+    let mut options_by_agenda: HashMap<Uuid, Vec<AgendaOptionEntity>> = HashMap::new();
+    for option in repo.find_options(&ids).await? {
+        options_by_agenda.entry(option.agenda_id).or_default().push(option);
+    }
+
+    Ok(AgendaListResponse {
+        items: agendas
+            .into_iter()
+            .map(|a| {
+                let options = options_by_agenda.remove(&a.id).unwrap_or_default();
+                to_response(a, options)
+            })
+            .collect(),
+        next_cursor,
+    })
+}
+
+fn page_size(limit: Option<i64>) -> i64 {
+    limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+}
+
+/// 커서 문자열 형식: `<created_at 마이크로초>_<id>`
+/// PostgreSQL TIMESTAMPTZ 정밀도가 마이크로초이므로 손실 없이 왕복됩니다.
+fn encode_cursor(cursor: &AgendaCursor) -> String {
+    format!("{}_{}", cursor.created_at.timestamp_micros(), cursor.id)
+}
+
+fn decode_cursor(raw: &str) -> Result<AgendaCursor, AppError> {
+    let invalid = || AppError::BadRequest("Invalid cursor".to_string(), vec![]);
+
+    let (micros, id) = raw.split_once('_').ok_or_else(invalid)?;
+    let micros = micros.parse::<i64>().map_err(|_| invalid())?;
+
+    Ok(AgendaCursor {
+        created_at: DateTime::from_timestamp_micros(micros).ok_or_else(invalid)?,
+        id: Uuid::parse_str(id).map_err(|_| invalid())?,
     })
 }
+
@@ -0,0 +1,155 @@
+//! 선택지 투표 집계 (DB와 무관한 순수 함수)
+//!
+//! 투표 용지(ballot)는 선호 순서대로 나열한 선택지 ID 목록입니다.
+//! - single_choice: 첫 번째 선택지만 1표
+//! - approval: 나열한 모든 선택지에 1표씩
+//! - borda: 선택지가 n개일 때 k순위(0부터)에 `n - 1 - k`점, 순위에 없는 선택지는 0점
+//! - instant_runoff: 남은 선택지 중 1순위 득표를 세고, 과반이 없으면 최하위를 탈락시키며 반복
+//!
+//! 안건에 없는 선택지와 한 용지 안의 중복 선택지는 무시합니다.
+
+use std::collections::HashSet;
+use serde::Serialize;
+use uuid::Uuid;
+use super::repository::VotingMethod;
+
+/// 집계 결과
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Tally {
+    pub method: VotingMethod,
+    pub total_ballots: u64,
+    /// 라운드별 결과 (instant_runoff 외에는 한 라운드)
+    pub rounds: Vec<Round>,
+    /// 최고 득표 선택지 (동률이면 여러 개, 유효표가 없으면 비어 있음)
+    pub winners: Vec<Uuid>,
+}
+
+/// 한 라운드의 집계
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Round {
+    /// 이 라운드에 남아 있는 선택지별 점수 (안건의 선택지 순서)
+    pub scores: Vec<Score>,
+    /// 이 라운드 후 탈락한 선택지 (instant_runoff, 최하위 동률이면 함께 탈락)
+    pub eliminated: Vec<Uuid>,
+    /// 남은 선택지가 하나도 없어 집계에서 빠진 용지 수 (instant_runoff)
+    pub exhausted: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Score {
+    pub option_id: Uuid,
+    pub score: u64,
+}
+
+/// 투표 방식에 맞춰 집계
+///
+/// - `options`: 안건의 선택지 (표시 순서)
+/// - `ballots`: 투표 용지들 (선호 순서대로 나열한 선택지 ID)
+pub fn tally(method: VotingMethod, options: &[Uuid], ballots: &[Vec<Uuid>]) -> Tally {
+    let ballots: Vec<Vec<Uuid>> = ballots.iter().map(|b| normalize(b, options)).collect();
+
+    let rounds = match method {
+        VotingMethod::InstantRunoff => instant_runoff(options, &ballots),
+        _ => vec![Round {
+            scores: options
+                .iter()
+                .map(|&option_id| Score {
+                    option_id,
+                    score: ballots.iter().map(|b| points(method, options.len(), b, option_id)).sum(),
+                })
+                .collect(),
+            eliminated: Vec::new(),
+            exhausted: 0,
+        }],
+    };
+
+    // instant_runoff도 마지막 라운드는 과반 득표자 하나 또는 남은 선택지 전원 동률
+    let winners = rounds.last().map(|last| top_scorers(&last.scores)).unwrap_or_default();
+
+    Tally {
+        method,
+        total_ballots: ballots.len() as u64,
+        rounds,
+        winners,
+    }
+}
+
+/// 안건에 없는 선택지와 중복 선택지 제거 (순서 유지)
+fn normalize(ballot: &[Uuid], options: &[Uuid]) -> Vec<Uuid> {
+    let mut seen = HashSet::new();
+    ballot
+        .iter()
+        .copied()
+        .filter(|id| options.contains(id) && seen.insert(*id))
+        .collect()
+}
+
+/// 한 용지가 선택지에 주는 점수 (instant_runoff 제외)
+fn points(method: VotingMethod, option_count: usize, ballot: &[Uuid], option_id: Uuid) -> u64 {
+    let rank = ballot.iter().position(|&id| id == option_id);
+    match (method, rank) {
+        (_, None) => 0,
+        (VotingMethod::SingleChoice, Some(rank)) => u64::from(rank == 0),
+        (VotingMethod::Approval, Some(_)) => 1,
+        (VotingMethod::Borda, Some(rank)) => (option_count - 1 - rank) as u64,
+        (VotingMethod::InstantRunoff, Some(rank)) => u64::from(rank == 0),
+    }
+}
+
+/// 즉석 결선 투표 라운드 진행
+///
+/// 매 라운드 남은 선택지 중 각 용지의 최상위 선택지에 1표를 줍니다.
+/// 유효표(소진되지 않은 용지)의 과반을 얻은 선택지가 있거나 남은 선택지가 모두 동률이면 종료,
+/// 아니면 최하위 득표 선택지(동률이면 모두)를 탈락시키고 다음 라운드로 넘어갑니다.
+fn instant_runoff(options: &[Uuid], ballots: &[Vec<Uuid>]) -> Vec<Round> {
+    let mut continuing: Vec<Uuid> = options.to_vec();
+    let mut rounds = Vec::new();
+
+    while !continuing.is_empty() {
+        let mut scores: Vec<Score> = continuing
+            .iter()
+            .map(|&option_id| Score { option_id, score: 0 })
+            .collect();
+        let mut exhausted = 0;
+
+        for ballot in ballots {
+            match ballot.iter().find(|id| continuing.contains(id)) {
+                Some(top) => {
+                    if let Some(score) = scores.iter_mut().find(|s| s.option_id == *top) {
+                        score.score += 1;
+                    }
+                }
+                None => exhausted += 1,
+            }
+        }
+
+        let active = ballots.len() as u64 - exhausted;
+        let max = scores.iter().map(|s| s.score).max().unwrap_or(0);
+        let min = scores.iter().map(|s| s.score).min().unwrap_or(0);
+
+        let decided = active == 0 || max * 2 > active || max == min;
+        let eliminated: Vec<Uuid> = if decided {
+            Vec::new()
+        } else {
+            scores.iter().filter(|s| s.score == min).map(|s| s.option_id).collect()
+        };
+
+        continuing.retain(|id| !eliminated.contains(id));
+        rounds.push(Round { scores, eliminated, exhausted });
+
+        if decided {
+            break;
+        }
+    }
+
+    rounds
+}
+
+/// 최고 점수 선택지들 (모두 0점이면 없음)
+fn top_scorers(scores: &[Score]) -> Vec<Uuid> {
+    let max = scores.iter().map(|s| s.score).max().unwrap_or(0);
+    if max == 0 {
+        return Vec::new();
+    }
+    scores.iter().filter(|s| s.score == max).map(|s| s.option_id).collect()
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::api::agenda::audit::{self, Inconsistency, GENESIS_HASH};
+use crate::api::agenda::repository::{memory::MemoryAgendaRepository, traits::AgendaRepository, Choice};
+use crate::api::agenda::service::{audit_head, change_vote, create_agenda, retract_vote, verify_audit, vote};
+use crate::api::auth::repository::{memory::MemoryUserRepository, traits::UserRepository};
+use crate::api::error::AppError;
+use crate::api::memory::{MemoryDb, VoteRow};
+use super::memory::request;
+use super::{ballot, MockAgendaRepository};
+
+/// 메모리 DB + 찬/반 안건 하나 + 사용자 3명이 투표/변경/철회한 기록 (감사 로그 4줄)
+async fn setup() -> (Arc<MemoryDb>, MemoryAgendaRepository, Uuid, Vec<Uuid>) {
+    let db = Arc::new(MemoryDb::default());
+    let users = MemoryUserRepository::new(&db);
+    let mut ids = Vec::new();
+    for i in 0..3 {
+        ids.push(users.save(&format!("user{}", i), "hash").await.unwrap());
+    }
+    let repo = MemoryAgendaRepository::new(&db);
+
+    let agenda = create_agenda(&repo, request("Agenda", &[]), ids[0]).await.unwrap();
+    vote(&repo, agenda.id, ids[0], ballot(true)).await.unwrap();
+    vote(&repo, agenda.id, ids[1], ballot(true)).await.unwrap();
+    change_vote(&repo, agenda.id, ids[1], ballot(false)).await.unwrap();
+    vote(&repo, agenda.id, ids[2], ballot(false)).await.unwrap();
+    retract_vote(&repo, agenda.id, ids[2]).await.unwrap();
+
+    (db, repo, agenda.id, ids)
+}
+
+async fn inconsistency(repo: &MemoryAgendaRepository, agenda_id: Uuid) -> Option<Inconsistency> {
+    verify_audit(repo, agenda_id).await.unwrap().inconsistency
+}
+
+// 테스트 1: 투표/변경/철회가 한 줄씩 체인으로 이어지고 그대로면 검증 통과
+#[tokio::test]
+async fn test_audit_chain_verifies() {
+    let (_db, repo, agenda_id, _) = setup().await;
+
+    let report = verify_audit(&repo, agenda_id).await.unwrap();
+    assert!(report.valid);
+    assert_eq!((report.entries, report.votes), (5, 2));
+    assert_eq!(report.inconsistency, None);
+
+    let head = audit_head(&repo, agenda_id).await.unwrap();
+    assert_eq!(head.seq, 5);
+    assert_eq!(head.hash, report.head.hash);
+    assert_ne!(head.hash, GENESIS_HASH);
+}
+
+// 테스트 2: 실패한 투표(중복 투표)는 기록되지 않고, 같은 선택으로 변경해도 기록되지 않음
+#[tokio::test]
+async fn test_audit_skips_rejected_and_noop_votes() {
+    let (_db, repo, agenda_id, users) = setup().await;
+
+    assert!(matches!(vote(&repo, agenda_id, users[0], ballot(false)).await, Err(AppError::AlreadyVoted(_))));
+    change_vote(&repo, agenda_id, users[0], ballot(true)).await.unwrap();
+
+    assert_eq!(audit_head(&repo, agenda_id).await.unwrap().seq, 5);
+}
+
+// 테스트 3: 기록된 줄의 내용을 고치면 그 줄의 해시 불일치
+#[tokio::test]
+async fn test_audit_detects_altered_entry() {
+    let (db, repo, agenda_id, _) = setup().await;
+
+    db.lock().vote_audit[2].choice = Some("\"agree\"".to_string());
+
+    assert_eq!(inconsistency(&repo, agenda_id).await, Some(Inconsistency::HashMismatch { seq: 3 }));
+}
+
+// 테스트 4: 고친 줄의 해시까지 다시 계산하면 다음 줄의 연결이 끊김
+#[tokio::test]
+async fn test_audit_detects_rehashed_entry() {
+    let (db, repo, agenda_id, _) = setup().await;
+
+    {
+        let mut tables = db.lock();
+        let entry = &mut tables.vote_audit[2];
+        entry.choice = Some("\"agree\"".to_string());
+        entry.hash = audit::hash(entry);
+    }
+
+    assert_eq!(inconsistency(&repo, agenda_id).await, Some(Inconsistency::BrokenLink { seq: 4 }));
+}
+
+// 테스트 5: 중간 줄을 지우면 seq가 비어 있음
+#[tokio::test]
+async fn test_audit_detects_deleted_entry() {
+    let (db, repo, agenda_id, _) = setup().await;
+
+    db.lock().vote_audit.remove(1);
+
+    assert_eq!(
+        inconsistency(&repo, agenda_id).await,
+        Some(Inconsistency::SequenceGap { seq: 3, expected: 2 })
+    );
+}
+
+// 테스트 6: 로그를 거치지 않고 votes를 넣거나/고치거나/지우면 각각 보고
+#[tokio::test]
+async fn test_audit_detects_tampered_votes() {
+    let (db, repo, agenda_id, users) = setup().await;
+
+    // 사후 삽입 (철회한 사용자의 표를 되살림)
+    db.lock().votes.push(VoteRow { id: Uuid::new_v4(), user_id: users[2], agenda_id, choice: Choice::Agree });
+    assert_eq!(inconsistency(&repo, agenda_id).await, Some(Inconsistency::UnloggedVote { user_id: users[2] }));
+    db.lock().votes.pop();
+
+    // 사후 수정
+    let index = db.lock().votes.iter().position(|v| v.user_id == users[1]).unwrap();
+    db.lock().votes[index].choice = Choice::Agree;
+    assert_eq!(inconsistency(&repo, agenda_id).await, Some(Inconsistency::AlteredVote { user_id: users[1] }));
+
+    // 사후 삭제
+    db.lock().votes.remove(index);
+    assert_eq!(inconsistency(&repo, agenda_id).await, Some(Inconsistency::MissingVote { user_id: users[1] }));
+}
+
+// 테스트 7: 기록이 없는 안건의 체인 머리는 seq 0, 없는 안건은 404
+#[tokio::test]
+async fn test_audit_head_of_empty_and_missing_agenda() {
+    let agenda = super::open_agenda();
+    let agenda_id = agenda.id;
+    let repo = MockAgendaRepository::with_agenda(agenda);
+
+    let head = audit_head(&repo, agenda_id).await.unwrap();
+    assert_eq!((head.seq, head.hash.as_str(), head.recorded_at), (0, GENESIS_HASH, None));
+    assert!(verify_audit(&repo, agenda_id).await.unwrap().valid);
+
+    assert!(matches!(audit_head(&repo, Uuid::new_v4()).await, Err(AppError::NotFound(_))));
+    assert!(matches!(verify_audit(&repo, Uuid::new_v4()).await, Err(AppError::NotFound(_))));
+    assert!(repo.find_audit_head(agenda_id).await.unwrap().is_none());
+}
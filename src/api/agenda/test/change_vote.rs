@@ -0,0 +1,106 @@
+use crate::api::agenda::repository::{AgendaEntity, AgendaStatus, Choice};
+use crate::api::agenda::service::{change_vote, retract_vote};
+use crate::api::error::AppError;
+use super::{ballot, open_agenda, MockAgendaRepository};
+use uuid::Uuid;
+
+// 테스트 1: 찬성 → 반대로 변경
+#[tokio::test]
+async fn test_change_vote_success() {
+    let agenda = AgendaEntity {
+        agree_count: 1,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        existing_vote: Some(Choice::Agree),
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let result = change_vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(false)).await;
+
+    let response = result.unwrap();
+    assert_eq!(response.is_agree, Some(false));
+    assert_eq!(response.agree_count, Some(0));
+    assert_eq!(response.disagree_count, Some(1));
+}
+
+// 테스트 2: 투표한 적 없는 안건의 변경 → NotFound
+#[tokio::test]
+async fn test_change_vote_not_voted() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = change_vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(true)).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+}
+
+// 테스트 3: 투표 철회 성공
+#[tokio::test]
+async fn test_retract_vote_success() {
+    let agenda = AgendaEntity {
+        disagree_count: 1,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        existing_vote: Some(Choice::Disagree),
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let result = retract_vote(&mock_repo, agenda_id, Uuid::new_v4()).await;
+
+    assert!(result.is_ok());
+}
+
+// 테스트 4: 투표한 적 없는 안건의 철회 → NotFound
+#[tokio::test]
+async fn test_retract_vote_not_voted() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = retract_vote(&mock_repo, agenda_id, Uuid::new_v4()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+}
+
+// 테스트 5: 마감된 안건의 투표 변경 → AgendaClosed
+#[tokio::test]
+async fn test_change_vote_closed_agenda() {
+    let agenda = AgendaEntity {
+        status: AgendaStatus::Closed,
+        agree_count: 1,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        existing_vote: Some(Choice::Agree),
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let result = change_vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(false)).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::AgendaClosed(_)));
+}
+
+// 기권 → 찬성으로 변경
+#[tokio::test]
+async fn test_change_vote_from_abstain() {
+    let agenda = AgendaEntity {
+        abstain_count: 1,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        existing_vote: Some(Choice::Abstain),
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let response = change_vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(true)).await.unwrap();
+
+    assert_eq!(response.abstain_count, Some(0));
+    assert_eq!(response.agree_count, Some(1));
+}
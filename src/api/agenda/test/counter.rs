@@ -0,0 +1,84 @@
+use crate::api::agenda::repository::counter::CounterBuffer;
+use crate::api::agenda::repository::{AgendaEntity, Choice};
+use super::{open_agenda, options_for};
+use std::time::Duration;
+
+// 테스트 1: 커밋된 증감은 조회 시 DB 카운터에 더해짐
+#[test]
+fn test_merge_committed_deltas() {
+    let counters = CounterBuffer::new(100);
+    let mut agenda = AgendaEntity {
+        agree_count: 3,
+        ..open_agenda()
+    };
+
+    counters.record(agenda.id, vec![(Choice::Agree, 1)]).commit();
+    counters.record(agenda.id, vec![(Choice::Agree, 1)]).commit();
+    counters.record(agenda.id, vec![(Choice::Abstain, 1)]).commit();
+    counters.merge_agenda(&mut agenda);
+
+    assert_eq!(agenda.agree_count, 5);
+    assert_eq!(agenda.disagree_count, 0);
+    assert_eq!(agenda.abstain_count, 1);
+}
+
+// 테스트 2: 커밋하지 않고 버린 기록은 되돌려짐 (트랜잭션 롤백)
+#[test]
+fn test_uncommitted_record_is_reverted() {
+    let counters = CounterBuffer::new(100);
+    let mut agenda = open_agenda();
+
+    counters.record(agenda.id, vec![(Choice::Agree, 1)]).commit();
+    drop(counters.record(agenda.id, vec![(Choice::Agree, -1), (Choice::Disagree, 1)]));
+    counters.merge_agenda(&mut agenda);
+
+    assert_eq!(agenda.agree_count, 1);
+    assert_eq!(agenda.disagree_count, 0);
+}
+
+// 테스트 3: 선택지 증감 - 순위 투표는 1순위만 반영
+#[test]
+fn test_merge_option_deltas() {
+    let counters = CounterBuffer::new(100);
+    let agenda = open_agenda();
+    let mut options = options_for(agenda.id, &["Alice", "Bob"]);
+    let (alice, bob) = (options[0].id, options[1].id);
+
+    counters.record(agenda.id, vec![(Choice::Ranking(vec![bob, alice]), 1)]).commit();
+    counters.record(agenda.id, vec![(Choice::Option(alice), -1), (Choice::Option(bob), 1)]).commit();
+    counters.merge_options(&mut options);
+
+    assert_eq!(options[0].vote_count, -1);
+    assert_eq!(options[1].vote_count, 2);
+}
+
+// 테스트 4: 꺼낸 증감은 버퍼에서 빠지고, 반영 실패 시 되돌려 놓으면 새 증감과 합산
+#[test]
+fn test_take_and_restore() {
+    let counters = CounterBuffer::new(100);
+    let mut agenda = open_agenda();
+
+    counters.record(agenda.id, vec![(Choice::Agree, 1)]).commit();
+    let pending = counters.take_all();
+    assert_eq!(pending[&agenda.id].agree, 1);
+    assert!(counters.take_all().is_empty());
+
+    counters.record(agenda.id, vec![(Choice::Agree, 1)]).commit();
+    counters.restore(pending);
+    counters.merge_agenda(&mut agenda);
+
+    assert_eq!(agenda.agree_count, 2);
+}
+
+// 테스트 5: 배치 크기만큼 쌓이면 반영 작업을 깨움
+#[tokio::test]
+async fn test_batch_size_wakes_flusher() {
+    let counters = CounterBuffer::new(2);
+    let agenda = open_agenda();
+
+    counters.record(agenda.id, vec![(Choice::Agree, 1)]).commit();
+    counters.record(agenda.id, vec![(Choice::Disagree, 1)]).commit();
+
+    let woke = tokio::time::timeout(Duration::from_millis(100), counters.filled()).await;
+    assert!(woke.is_ok());
+}
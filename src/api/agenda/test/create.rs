@@ -1,5 +1,9 @@
+use crate::api::agenda::dtos::CreateAgendaRequest;
+use crate::api::agenda::repository::AgendaStatus;
 use crate::api::agenda::service::create_agenda;
+use crate::api::error::AppError;
 use super::MockAgendaRepository;
+use chrono::{Duration, Utc};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -8,20 +12,118 @@ async fn test_create_agenda_success() {
     let creator_id = Uuid::new_v4();
     let title = "New Agenda".to_string();
 
-    let result = create_agenda(&mock_repo, title.clone(), creator_id).await;
+    let payload = CreateAgendaRequest {
+        title: title.clone(),
+        ..Default::default()
+    };
+    let result = create_agenda(&mock_repo, payload, creator_id).await;
     
     assert!(result.is_ok());
     let response = result.unwrap();
     assert_eq!(response.title, title);
     assert_eq!(response.created_by, creator_id.to_string());
+    assert_eq!(response.status, AgendaStatus::Open);
 }
 
 #[tokio::test]
 async fn test_create_agenda_failure() {
-    let mock_repo = MockAgendaRepository { should_fail: true };
+    let mock_repo = MockAgendaRepository {
+        should_fail: true,
+        ..Default::default()
+    };
     let creator_id = Uuid::new_v4();
     
-    let result = create_agenda(&mock_repo, "Fail".to_string(), creator_id).await;
+    let payload = CreateAgendaRequest {
+        title: "Fail".to_string(),
+        ..Default::default()
+    };
+    let result = create_agenda(&mock_repo, payload, creator_id).await;
     
     assert!(result.is_err());
 }
+
+// 미래의 opens_at → draft로 생성
+#[tokio::test]
+async fn test_create_scheduled_agenda_is_draft() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let payload = CreateAgendaRequest {
+        title: "Scheduled".to_string(),
+        opens_at: Some(Utc::now() + Duration::hours(1)),
+        closes_at: Some(Utc::now() + Duration::hours(2)),
+        ..Default::default()
+    };
+    let result = create_agenda(&mock_repo, payload, Uuid::new_v4()).await;
+
+    assert_eq!(result.unwrap().status, AgendaStatus::Draft);
+}
+
+// 마감 시각이 시작 시각보다 빠름 → BadRequest
+#[tokio::test]
+async fn test_create_agenda_invalid_window() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let payload = CreateAgendaRequest {
+        title: "Invalid".to_string(),
+        opens_at: Some(Utc::now() + Duration::hours(2)),
+        closes_at: Some(Utc::now() + Duration::hours(1)),
+        ..Default::default()
+    };
+    let result = create_agenda(&mock_repo, payload, Uuid::new_v4()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 제목: 앞뒤 공백 제거 후 빈 값 / 200자 초과 / 제어 문자 → BadRequest + title 필드 상세
+#[tokio::test]
+async fn test_create_agenda_invalid_title() {
+    let mock_repo = MockAgendaRepository::default();
+
+    for (title, code) in [("   ", "too_short"), (&"a".repeat(201)[..], "too_long"), ("line\nbreak", "invalid_characters")] {
+        let payload = CreateAgendaRequest {
+            title: title.to_string(),
+            ..Default::default()
+        };
+        let result = create_agenda(&mock_repo, payload, Uuid::new_v4()).await;
+
+        match result.unwrap_err() {
+            AppError::BadRequest(_, errors) => {
+                assert_eq!(errors[0].field, "title");
+                assert_eq!(errors[0].code, code);
+            }
+            other => panic!("expected BadRequest, got {:?}", other),
+        }
+    }
+}
+
+// 제목은 정규화된 값으로 저장 (앞뒤 공백 제거, 조합형 한글 → 완성형)
+#[tokio::test]
+async fn test_create_agenda_normalizes_title() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let payload = CreateAgendaRequest {
+        title: "  \u{1100}\u{1161}\u{11A8} 안건 ".to_string(), // ㄱ + ㅏ + ㄱ (조합형 "각")
+        ..Default::default()
+    };
+    let result = create_agenda(&mock_repo, payload, Uuid::new_v4()).await;
+
+    assert_eq!(result.unwrap().title, "각 안건");
+}
+
+// 선택지 라벨 오류는 항목 위치와 함께 보고
+#[tokio::test]
+async fn test_create_agenda_option_field_details() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let payload = CreateAgendaRequest {
+        title: "Lunch".to_string(),
+        options: vec!["Pizza".to_string(), " ".to_string(), "Sushi".to_string()],
+        ..Default::default()
+    };
+    let result = create_agenda(&mock_repo, payload, Uuid::new_v4()).await;
+
+    match result.unwrap_err() {
+        AppError::BadRequest(_, errors) => assert_eq!(errors[0].field, "options[1]"),
+        other => panic!("expected BadRequest, got {:?}", other),
+    }
+}
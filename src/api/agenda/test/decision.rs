@@ -0,0 +1,188 @@
+use crate::api::agenda::decision::{decide_election, decide_motion, Outcome, Rules};
+use crate::api::agenda::repository::{DecisionRule, TiePolicy, VotingMethod};
+use crate::api::agenda::tally::tally;
+use uuid::Uuid;
+
+fn rules(rule: DecisionRule) -> Rules {
+    Rules {
+        rule,
+        tie_policy: TiePolicy::Fail,
+        quorum: None,
+    }
+}
+
+// 테스트 1: 과반 - 찬성이 반대보다 많으면 가결
+#[test]
+fn test_simple_majority_passes() {
+    let decision = decide_motion(&rules(DecisionRule::SimpleMajority), 6, 4, 0);
+
+    assert_eq!(decision.outcome, Outcome::Passed);
+    assert_eq!(decision.margin, 2);
+    assert_eq!(decision.turnout, 10);
+    assert!(decision.quorum_met);
+}
+
+// 테스트 2: 2/3 - 경계값 포함, 미달이면 부결
+#[test]
+fn test_two_thirds_threshold() {
+    let two_thirds = rules(DecisionRule::TwoThirds);
+
+    assert_eq!(decide_motion(&two_thirds, 6, 3, 0).outcome, Outcome::Passed);
+    assert_eq!(decide_motion(&two_thirds, 6, 4, 0).outcome, Outcome::Failed);
+}
+
+// 테스트 3: 동률 - 규칙에 따라 부결 또는 동률 보고
+#[test]
+fn test_tie_policy() {
+    let fail = rules(DecisionRule::SimpleMajority);
+    let tie = Rules {
+        tie_policy: TiePolicy::Tie,
+        ..fail
+    };
+
+    assert_eq!(decide_motion(&fail, 5, 5, 0).outcome, Outcome::Failed);
+    assert_eq!(decide_motion(&tie, 5, 5, 0).outcome, Outcome::Tied);
+    assert_eq!(decide_motion(&tie, 5, 5, 0).margin, 0);
+}
+
+// 테스트 4: 정족수 미달이면 득표와 관계없이 no_quorum
+#[test]
+fn test_quorum_not_met() {
+    let with_quorum = Rules {
+        quorum: Some(10),
+        ..rules(DecisionRule::SimpleMajority)
+    };
+
+    let short = decide_motion(&with_quorum, 9, 0, 0);
+    let enough = decide_motion(&with_quorum, 9, 1, 0);
+
+    assert_eq!(short.outcome, Outcome::NoQuorum);
+    assert!(!short.quorum_met);
+    assert_eq!(enough.outcome, Outcome::Passed);
+}
+
+// 테스트 5: 표가 없으면 동률이 아니라 부결
+#[test]
+fn test_no_votes_fails() {
+    let tie = Rules {
+        tie_policy: TiePolicy::Tie,
+        ..rules(DecisionRule::SimpleMajority)
+    };
+
+    assert_eq!(decide_motion(&tie, 0, 0, 0).outcome, Outcome::Failed);
+}
+
+// 테스트 6: 선택지 안건 - 최다 득표가 과반이면 가결, 당선 선택지 반환
+#[test]
+fn test_election_majority_winner() {
+    let c: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+    let ballots = vec![vec![c[0]], vec![c[0]], vec![c[0]], vec![c[1]], vec![c[2]]];
+
+    let decision = decide_election(
+        &rules(DecisionRule::SimpleMajority),
+        &tally(VotingMethod::SingleChoice, &c, &ballots),
+        0,
+    );
+
+    assert_eq!(decision.outcome, Outcome::Passed);
+    assert_eq!(decision.winner, Some(c[0]));
+    assert_eq!(decision.margin, 2);
+}
+
+// 테스트 7: 선택지 안건 - 최다 득표라도 과반이 아니면 부결
+#[test]
+fn test_election_plurality_without_majority() {
+    let c: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+    let ballots = vec![vec![c[0]], vec![c[0]], vec![c[1]], vec![c[2]]];
+
+    let decision = decide_election(
+        &rules(DecisionRule::SimpleMajority),
+        &tally(VotingMethod::SingleChoice, &c, &ballots),
+        0,
+    );
+
+    assert_eq!(decision.outcome, Outcome::Failed);
+    assert_eq!(decision.winner, None);
+}
+
+// 테스트 8: 즉석 결선 - 마지막 라운드 기준으로 과반 판정
+#[test]
+fn test_election_instant_runoff_final_round() {
+    let c: Vec<Uuid> = (0..3).map(|_| Uuid::new_v4()).collect();
+    let ballots = vec![vec![c[0]], vec![c[0]], vec![c[1], c[0]], vec![c[2], c[1]], vec![c[1]]];
+
+    let decision = decide_election(
+        &rules(DecisionRule::SimpleMajority),
+        &tally(VotingMethod::InstantRunoff, &c, &ballots),
+        0,
+    );
+
+    assert_eq!(decision.outcome, Outcome::Passed);
+    assert_eq!(decision.winner, Some(c[1]));
+    assert_eq!(decision.margin, 1);
+}
+
+// 테스트 9: 승인 투표 - 투표 용지 수 대비 승인 비율로 판정
+#[test]
+fn test_election_approval_uses_ballot_count() {
+    let c: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+    let ballots = vec![vec![c[0], c[1]], vec![c[0], c[1]], vec![c[0]]];
+
+    let decision = decide_election(
+        &rules(DecisionRule::TwoThirds),
+        &tally(VotingMethod::Approval, &c, &ballots),
+        0,
+    );
+
+    // c0: 3/3 승인
+    assert_eq!(decision.outcome, Outcome::Passed);
+    assert_eq!(decision.winner, Some(c[0]));
+}
+
+// 테스트 10: 선택지 안건 동률
+#[test]
+fn test_election_tie() {
+    let c: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+    let ballots = vec![vec![c[0]], vec![c[1]]];
+    let result = tally(VotingMethod::SingleChoice, &c, &ballots);
+
+    let fail = rules(DecisionRule::SimpleMajority);
+    let tie = Rules {
+        tie_policy: TiePolicy::Tie,
+        ..fail
+    };
+
+    assert_eq!(decide_election(&fail, &result, 0).outcome, Outcome::Failed);
+    assert_eq!(decide_election(&tie, &result, 0).outcome, Outcome::Tied);
+}
+
+// 테스트 11: 기권은 정족수에는 포함되지만 가결 비율의 분모에는 빠짐
+#[test]
+fn test_abstain_counts_toward_quorum_only() {
+    let with_quorum = Rules {
+        quorum: Some(10),
+        ..rules(DecisionRule::TwoThirds)
+    };
+
+    // 찬성 4, 반대 2, 기권 4 → 참여 10 (정족수 충족), 4/6 = 2/3 → 가결
+    let decision = decide_motion(&with_quorum, 4, 2, 4);
+
+    assert_eq!(decision.turnout, 10);
+    assert!(decision.quorum_met);
+    assert_eq!(decision.outcome, Outcome::Passed);
+}
+
+// 테스트 12: 선택지 안건도 기권이 정족수에 포함
+#[test]
+fn test_election_abstain_meets_quorum() {
+    let c: Vec<Uuid> = (0..2).map(|_| Uuid::new_v4()).collect();
+    let ballots = vec![vec![c[0]], vec![c[0]]];
+    let with_quorum = Rules {
+        quorum: Some(4),
+        ..rules(DecisionRule::SimpleMajority)
+    };
+    let result = tally(VotingMethod::SingleChoice, &c, &ballots);
+
+    assert_eq!(decide_election(&with_quorum, &result, 1).outcome, Outcome::NoQuorum);
+    assert_eq!(decide_election(&with_quorum, &result, 2).outcome, Outcome::Passed);
+}
@@ -0,0 +1,110 @@
+use crate::api::agenda::repository::{AgendaEntity, AgendaStatus};
+use crate::api::agenda::service::{change_status, moderate_status};
+use crate::api::error::AppError;
+use super::{open_agenda, MockAgendaRepository};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+// 테스트 1: 허용되는 상태 전이만 통과
+#[test]
+fn test_status_transitions() {
+    use AgendaStatus::*;
+
+    assert!(Draft.can_transition_to(Open));
+    assert!(Draft.can_transition_to(Cancelled));
+    assert!(Open.can_transition_to(Closed));
+    assert!(Open.can_transition_to(Cancelled));
+
+    assert!(!Draft.can_transition_to(Closed));
+    assert!(!Open.can_transition_to(Draft));
+    assert!(!Closed.can_transition_to(Open));
+    assert!(!Cancelled.can_transition_to(Open));
+}
+
+// 테스트 2: 예약 시각을 반영한 현재 상태
+#[test]
+fn test_effective_status_follows_schedule() {
+    let now = Utc::now();
+
+    let scheduled = AgendaEntity {
+        status: AgendaStatus::Draft,
+        opens_at: Some(now + Duration::hours(1)),
+        closes_at: Some(now + Duration::hours(2)),
+        ..open_agenda()
+    };
+    assert_eq!(scheduled.effective_status(now), AgendaStatus::Draft);
+    assert_eq!(scheduled.effective_status(now + Duration::minutes(90)), AgendaStatus::Open);
+    assert_eq!(scheduled.effective_status(now + Duration::hours(3)), AgendaStatus::Closed);
+
+    let cancelled = AgendaEntity {
+        status: AgendaStatus::Cancelled,
+        ..scheduled
+    };
+    assert_eq!(cancelled.effective_status(now + Duration::minutes(90)), AgendaStatus::Cancelled);
+}
+
+// 테스트 3: 생성자가 안건 마감
+#[tokio::test]
+async fn test_close_agenda_success() {
+    let agenda = open_agenda();
+    let (agenda_id, creator) = (agenda.id, agenda.created_by);
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = change_status(&mock_repo, agenda_id, creator, AgendaStatus::Closed).await;
+
+    assert_eq!(result.unwrap().status, AgendaStatus::Closed);
+}
+
+// 테스트 4: 생성자가 아닌 사용자 → Forbidden
+#[tokio::test]
+async fn test_close_agenda_not_creator() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = change_status(&mock_repo, agenda_id, Uuid::new_v4(), AgendaStatus::Closed).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+}
+
+// 테스트 5: 이미 마감된 안건 다시 열기 → Conflict
+#[tokio::test]
+async fn test_reopen_closed_agenda() {
+    let agenda = AgendaEntity {
+        status: AgendaStatus::Closed,
+        ..open_agenda()
+    };
+    let (agenda_id, creator) = (agenda.id, agenda.created_by);
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = change_status(&mock_repo, agenda_id, creator, AgendaStatus::Open).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Conflict(_)));
+}
+
+// 테스트 6: 관리자 마감 - 생성자가 아니어도 마감 가능
+#[tokio::test]
+async fn test_moderate_close_any_agenda() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = moderate_status(&mock_repo, agenda_id, AgendaStatus::Closed).await;
+
+    assert_eq!(result.unwrap().status, AgendaStatus::Closed);
+}
+
+// 테스트 7: 관리자 마감도 상태 전이 규칙은 지켜야 함 (취소된 안건 → Conflict)
+#[tokio::test]
+async fn test_moderate_status_respects_transitions() {
+    let agenda = AgendaEntity {
+        status: AgendaStatus::Cancelled,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = moderate_status(&mock_repo, agenda_id, AgendaStatus::Closed).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Conflict(_)));
+}
@@ -0,0 +1,128 @@
+use std::time::Duration;
+use uuid::Uuid;
+use crate::api::agenda::dtos::CreateAgendaRequest;
+use crate::api::agenda::live::{LiveTally, Subscription};
+use crate::api::agenda::repository::AgendaStatus;
+use crate::api::agenda::scheduler;
+use crate::api::agenda::service::{create_agenda, vote};
+use crate::api::error::AppError;
+use super::ballot;
+use super::memory::{request, setup};
+
+/// 대기 중인 업데이트가 없는지 확인
+async fn assert_idle(subscription: &mut Subscription) {
+    let next = tokio::time::timeout(Duration::from_millis(50), subscription.next()).await;
+    assert!(next.is_err(), "unexpected update");
+}
+
+// 테스트 1: 구독 직후 현재 집계를 받음
+#[tokio::test]
+async fn test_subscribe_sends_current_tally() {
+    let (repo, users) = setup(1).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+    vote(&repo, agenda.id, users[0], ballot(true)).await.unwrap();
+    let live = LiveTally::default();
+
+    let mut subscription = live.subscribe(&repo, agenda.id, None).await.unwrap();
+    let update = subscription.next().await.unwrap();
+
+    assert_eq!(update.agenda_id, agenda.id);
+    assert_eq!(update.agree_count, Some(1));
+    assert_idle(&mut subscription).await;
+}
+
+// 테스트 2: 한 주기 안의 투표 여러 개는 업데이트 하나로 합쳐짐
+#[tokio::test]
+async fn test_votes_coalesced_into_one_update() {
+    let (repo, users) = setup(3).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+    let live = LiveTally::default();
+    let mut subscription = live.subscribe(&repo, agenda.id, None).await.unwrap();
+    let initial = subscription.next().await.unwrap();
+
+    for (user, is_agree) in users.iter().zip([true, true, false]) {
+        vote(&repo, agenda.id, *user, ballot(is_agree)).await.unwrap();
+        live.mark(agenda.id);
+    }
+    assert_eq!(live.publish(&repo).await, 1);
+
+    let update = subscription.next().await.unwrap();
+    assert!(update.seq > initial.seq);
+    assert_eq!((update.agree_count, update.disagree_count), (Some(2), Some(1)));
+    assert_idle(&mut subscription).await;
+
+    // 표시된 안건이 없으면 보내지 않음
+    assert_eq!(live.publish(&repo).await, 0);
+}
+
+// 테스트 3: 마지막으로 받은 seq로 재연결하면 놓친 업데이트만 받음
+#[tokio::test]
+async fn test_resume_from_last_seq() {
+    let (repo, users) = setup(1).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+    let live = LiveTally::default();
+    let mut first = live.subscribe(&repo, agenda.id, None).await.unwrap();
+    let seen = first.next().await.unwrap().seq;
+
+    // 구독자가 모두 나가도 최근 업데이트는 남아 있어, 바뀐 게 없으면 다시 보내지 않음
+    drop(first);
+    live.publish(&repo).await;
+    let mut resumed = live.subscribe(&repo, agenda.id, Some(seen)).await.unwrap();
+    assert_idle(&mut resumed).await;
+    drop(resumed);
+
+    // 끊긴 사이의 투표는 재연결 시 최근 집계 하나로 받음
+    live.publish(&repo).await;
+    vote(&repo, agenda.id, users[0], ballot(false)).await.unwrap();
+    live.mark(agenda.id);
+    let mut reconnected = live.subscribe(&repo, agenda.id, Some(seen)).await.unwrap();
+    let update = reconnected.next().await.unwrap();
+
+    assert!(update.seq > seen);
+    assert_eq!(update.disagree_count, Some(1));
+}
+
+// 테스트 4: 구독자가 없는 안건은 표시해도 집계를 읽지 않음
+#[tokio::test]
+async fn test_unsubscribed_agenda_not_published() {
+    let (repo, users) = setup(1).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+    let live = LiveTally::default();
+
+    drop(live.subscribe(&repo, agenda.id, None).await.unwrap());
+    vote(&repo, agenda.id, users[0], ballot(true)).await.unwrap();
+    live.mark(agenda.id);
+
+    assert_eq!(live.publish(&repo).await, 0);
+}
+
+// 테스트 5: 없는 안건 구독 → NotFound
+#[tokio::test]
+async fn test_subscribe_unknown_agenda() {
+    let (repo, _) = setup(0).await;
+
+    let result = LiveTally::default().subscribe(&repo, Uuid::new_v4(), None).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+// 테스트 6: 스케줄러가 마감한 비공개 안건 - 구독자가 마감 상태와 최종 집계를 받음
+#[tokio::test]
+async fn test_scheduled_close_publishes_final_tally() {
+    let (repo, users) = setup(1).await;
+    let closes_at = chrono::Utc::now() + chrono::Duration::milliseconds(200);
+    let payload = CreateAgendaRequest { closes_at: Some(closes_at), hide_results: true, ..request("Agenda", &[]) };
+    let agenda = create_agenda(&repo, payload, users[0]).await.unwrap();
+    vote(&repo, agenda.id, users[0], ballot(true)).await.unwrap();
+    let live = LiveTally::default();
+    let mut subscription = live.subscribe(&repo, agenda.id, None).await.unwrap();
+    assert_eq!(subscription.next().await.unwrap().agree_count, None);
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    scheduler::run_once(&repo, &live).await;
+    assert_eq!(live.publish(&repo).await, 1);
+
+    let update = subscription.next().await.unwrap();
+    assert_eq!(update.status, AgendaStatus::Closed);
+    assert_eq!(update.agree_count, Some(1));
+}
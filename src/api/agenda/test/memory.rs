@@ -0,0 +1,123 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use crate::api::agenda::dtos::{CreateAgendaRequest, VoteRequest};
+use crate::api::agenda::repository::{
+    memory::MemoryAgendaRepository, traits::AgendaRepository, AgendaStatus, Choice, DecisionRule, NewAgenda, TiePolicy,
+    VotingMethod,
+};
+use crate::api::agenda::service::{change_vote, create_agenda, reconcile_counters, retract_vote, vote};
+use crate::api::auth::repository::{memory::MemoryUserRepository, traits::UserRepository};
+use crate::api::error::AppError;
+use crate::api::memory::MemoryDb;
+use super::ballot;
+
+/// 메모리 DB + 가입한 사용자 `count`명
+pub async fn setup(count: usize) -> (MemoryAgendaRepository, Vec<Uuid>) {
+    let db = Arc::new(MemoryDb::default());
+    let users = MemoryUserRepository::new(&db);
+    let mut ids = Vec::new();
+    for i in 0..count {
+        ids.push(users.save(&format!("user{}", i), "hash").await.unwrap());
+    }
+    (MemoryAgendaRepository::new(&db), ids)
+}
+
+pub fn request(title: &str, options: &[&str]) -> CreateAgendaRequest {
+    CreateAgendaRequest {
+        title: title.to_string(),
+        options: options.iter().map(|o| o.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+// 테스트 1: 같은 사용자는 한 안건에 한 번만 투표 (uk_vote_user_agenda)
+#[tokio::test]
+async fn test_memory_one_vote_per_user() {
+    let (repo, users) = setup(1).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+
+    vote(&repo, agenda.id, users[0], ballot(true)).await.unwrap();
+    let result = vote(&repo, agenda.id, users[0], ballot(false)).await;
+
+    assert!(matches!(result, Err(AppError::AlreadyVoted(_))));
+    let stored = repo.find_by_id(agenda.id).await.unwrap().unwrap();
+    assert_eq!((stored.agree_count, stored.disagree_count), (1, 0));
+}
+
+// 테스트 2: 투표/변경/철회 후 카운터가 votes와 일치
+#[tokio::test]
+async fn test_memory_counters_stay_consistent() {
+    let (repo, users) = setup(3).await;
+    let agenda = create_agenda(&repo, request("Agenda", &["A", "B"]), users[0]).await.unwrap();
+    let (a, b) = (agenda.options[0].id, agenda.options[1].id);
+    let pick = |id| VoteRequest { option_id: Some(id), ..Default::default() };
+
+    vote(&repo, agenda.id, users[0], pick(a)).await.unwrap();
+    vote(&repo, agenda.id, users[1], pick(a)).await.unwrap();
+    vote(&repo, agenda.id, users[2], VoteRequest { abstain: true, ..Default::default() }).await.unwrap();
+    change_vote(&repo, agenda.id, users[1], pick(b)).await.unwrap();
+    retract_vote(&repo, agenda.id, users[0]).await.unwrap();
+
+    let options = repo.find_options(&[agenda.id]).await.unwrap();
+    assert_eq!(options.iter().map(|o| o.vote_count).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(repo.find_by_id(agenda.id).await.unwrap().unwrap().abstain_count, 1);
+    assert_eq!(repo.find_ballots(agenda.id).await.unwrap(), vec![vec![b]]);
+    assert!(reconcile_counters(&repo, false).await.unwrap().drifts.is_empty());
+}
+
+// 테스트 3: 다른 안건의 선택지로는 투표 불가 (fk_vote_option)
+#[tokio::test]
+async fn test_memory_rejects_foreign_option() {
+    let (repo, users) = setup(1).await;
+    let first = create_agenda(&repo, request("First", &["A", "B"]), users[0]).await.unwrap();
+    let second = create_agenda(&repo, request("Second", &["C", "D"]), users[0]).await.unwrap();
+
+    let result = repo.vote(first.id, users[0], Choice::Option(second.options[0].id)).await;
+
+    assert!(matches!(result.map_err(AppError::from), Err(AppError::Conflict(_))));
+    assert!(repo.find_ballots(first.id).await.unwrap().is_empty());
+}
+
+// 테스트 4: 없는 사용자는 안건을 만들거나 투표할 수 없음 (외래 키)
+#[tokio::test]
+async fn test_memory_requires_existing_user() {
+    let (repo, users) = setup(1).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+
+    let created = create_agenda(&repo, request("Ghost", &[]), Uuid::new_v4()).await;
+    let voted = repo.vote(agenda.id, Uuid::new_v4(), Choice::Agree).await;
+
+    assert!(matches!(created, Err(AppError::Conflict(_))));
+    assert!(matches!(voted.map_err(AppError::from), Err(AppError::Conflict(_))));
+}
+
+// 테스트 5: 예약 시각이 지난 안건은 스케줄러가 돌기 전에도 투표 불가 + 스케줄러가 상태 전이
+#[tokio::test]
+async fn test_memory_schedule_and_closed_agenda() {
+    let (repo, users) = setup(1).await;
+    let past = repo
+        .create(&NewAgenda {
+            title: "Past".to_string(),
+            created_by: users[0],
+            status: AgendaStatus::Draft,
+            opens_at: Some(Utc::now() - Duration::hours(2)),
+            closes_at: Some(Utc::now() - Duration::hours(1)),
+            options: vec![],
+            voting_method: VotingMethod::SingleChoice,
+            decision_rule: DecisionRule::SimpleMajority,
+            tie_policy: TiePolicy::Fail,
+            quorum: None,
+            hide_results: false,
+        })
+        .await
+        .unwrap();
+
+    let result = vote(&repo, past.id, users[0], ballot(true)).await;
+    assert!(matches!(result, Err(AppError::AgendaClosed(_))));
+
+    let changes = repo.apply_schedule().await.unwrap();
+    assert_eq!(changes.opened, vec![past.id]);
+    assert_eq!(changes.closed, vec![past.id]);
+    assert_eq!(repo.find_by_id(past.id).await.unwrap().unwrap().status, AgendaStatus::Closed);
+}
@@ -1,35 +1,291 @@
+use super::receipt::{commit, new_salt};
 use super::repository::traits::AgendaRepository;
-use super::repository::AgendaEntity;
+use super::dtos::VoteRequest;
+use super::repository::{
+    AgendaCursor, AgendaEntity, AgendaOptionEntity, AgendaStatus, AuditEntry, AuditTrail, Choice, NewAgenda,
+    ReceiptEntity, ReceiptRootEntity, RecordedVote, ScheduleChanges, CounterDrift, DecisionRule, TiePolicy, VotingMethod,
+};
 use async_trait::async_trait;
+use sqlx::error::{DatabaseError, ErrorKind};
 use uuid::Uuid;
 
 pub mod create;
+pub mod vote;
+pub mod change_vote;
+pub mod read;
+pub mod lifecycle;
+pub mod options;
+pub mod tally;
+pub mod voting_method;
+pub mod decision;
+pub mod result;
+pub mod reconcile;
+pub mod counter;
+pub mod memory;
+pub mod live;
+pub mod audit;
+pub mod receipt;
 
+#[derive(Default)]
 pub struct MockAgendaRepository {
-    pub should_fail: bool,
+    pub should_fail: bool,    // create 호출 시 에러 발생 여부
+    pub already_voted: bool,  // vote 호출 시 unique 제약 위반 여부
+    pub existing_vote: Option<Choice>, // 기존 투표 (change/retract 대상, None: 투표 내역 없음)
+    pub agendas: Vec<AgendaEntity>, // 조회(find/list) 및 투표 대상 안건들
+    pub options: Vec<AgendaOptionEntity>, // 다지선다 안건의 선택지들
+    pub ballots: Vec<Vec<Uuid>>, // 집계 대상 투표 용지들
+    pub drifts: Vec<CounterDrift>, // votes와 어긋난 캐싱 카운터들
+    pub audit: AuditTrail, // 감사 로그와 현재 투표들
 }
 
-impl Default for MockAgendaRepository {
-    fn default() -> Self {
-        Self { should_fail: false }
+impl MockAgendaRepository {
+    /// 안건 하나가 저장된 Mock
+    pub fn with_agenda(agenda: AgendaEntity) -> Self {
+        Self {
+            agendas: vec![agenda],
+            ..Default::default()
+        }
+    }
+}
+
+/// 투표 가능한(open) 테스트용 안건
+pub fn open_agenda() -> AgendaEntity {
+    AgendaEntity {
+        id: Uuid::new_v4(),
+        title: "Agenda".to_string(),
+        created_by: Uuid::new_v4(),
+        created_at: chrono::Utc::now(),
+        agree_count: 0,
+        disagree_count: 0,
+        abstain_count: 0,
+        status: AgendaStatus::Open,
+        opens_at: None,
+        closes_at: None,
+        voting_method: VotingMethod::SingleChoice,
+        decision_rule: DecisionRule::SimpleMajority,
+        tie_policy: TiePolicy::Fail,
+        quorum: None,
+        hide_results: false,
+    }
+}
+
+/// 안건에 딸린 테스트용 선택지 (position 순서대로)
+pub fn options_for(agenda_id: Uuid, labels: &[&str]) -> Vec<AgendaOptionEntity> {
+    labels
+        .iter()
+        .enumerate()
+        .map(|(position, label)| AgendaOptionEntity {
+            id: Uuid::new_v4(),
+            agenda_id,
+            position: position as i32,
+            label: label.to_string(),
+            vote_count: 0,
+        })
+        .collect()
+}
+
+/// 찬/반 투표 요청
+pub fn ballot(is_agree: bool) -> VoteRequest {
+    VoteRequest {
+        is_agree: Some(is_agree),
+        ..Default::default()
     }
 }
 
 #[async_trait]
 impl AgendaRepository for MockAgendaRepository {
-    async fn create(&self, title: &str, created_by: Uuid) -> Result<AgendaEntity, sqlx::Error> {
+    async fn create(&self, agenda: &NewAgenda) -> Result<AgendaEntity, sqlx::Error> {
         if self.should_fail {
             Err(sqlx::Error::RowNotFound)
         } else {
             Ok(AgendaEntity {
                 id: Uuid::new_v4(),
-                title: title.to_string(),
+                title: agenda.title.clone(),
                 // description removed
-                created_by,
+                created_by: agenda.created_by,
                 created_at: chrono::Utc::now(),
                 agree_count: 0,
                 disagree_count: 0,
+                abstain_count: 0,
+                status: agenda.status,
+                opens_at: agenda.opens_at,
+                closes_at: agenda.closes_at,
+                voting_method: agenda.voting_method,
+                decision_rule: agenda.decision_rule,
+                tie_policy: agenda.tie_policy,
+                quorum: agenda.quorum,
+                hide_results: agenda.hide_results,
             })
         }
     }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AgendaEntity>, sqlx::Error> {
+        Ok(self.agendas.iter().find(|a| a.id == id).cloned())
+    }
+
+    async fn list(&self, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+        Ok(page(self.agendas.iter(), cursor, limit))
+    }
+
+    async fn list_by_creator(&self, created_by: Uuid, cursor: Option<AgendaCursor>, limit: i64) -> Result<Vec<AgendaEntity>, sqlx::Error> {
+        Ok(page(self.agendas.iter().filter(|a| a.created_by == created_by), cursor, limit))
+    }
+
+    async fn find_options(&self, agenda_ids: &[Uuid]) -> Result<Vec<AgendaOptionEntity>, sqlx::Error> {
+        Ok(self
+            .options
+            .iter()
+            .filter(|o| agenda_ids.contains(&o.agenda_id))
+            .cloned()
+            .collect())
+    }
+
+    async fn find_ballots(&self, _agenda_id: Uuid) -> Result<Vec<Vec<Uuid>>, sqlx::Error> {
+        Ok(self.ballots.clone())
+    }
+
+    async fn vote(&self, agenda_id: Uuid, _user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error> {
+        if self.already_voted {
+            return Err(sqlx::Error::Database(Box::new(UniqueViolation)));
+        }
+
+        let mut agenda = self.find_by_id(agenda_id).await?.ok_or(sqlx::Error::RowNotFound)?;
+        adjust(&mut agenda, &choice, 1);
+        Ok(recorded(agenda, &choice))
+    }
+
+    async fn change_vote(&self, agenda_id: Uuid, _user_id: Uuid, choice: Choice) -> Result<RecordedVote, sqlx::Error> {
+        let previous = self.existing_vote.clone().ok_or(sqlx::Error::RowNotFound)?;
+        let mut agenda = self.find_by_id(agenda_id).await?.ok_or(sqlx::Error::RowNotFound)?;
+
+        // 기존 투표 1건이 choice 쪽으로 옮겨감
+        if previous != choice {
+            adjust(&mut agenda, &previous, -1);
+            adjust(&mut agenda, &choice, 1);
+        }
+        Ok(recorded(agenda, &choice))
+    }
+
+    async fn retract_vote(&self, agenda_id: Uuid, _user_id: Uuid) -> Result<AgendaEntity, sqlx::Error> {
+        let previous = self.existing_vote.clone().ok_or(sqlx::Error::RowNotFound)?;
+        let mut agenda = self.find_by_id(agenda_id).await?.ok_or(sqlx::Error::RowNotFound)?;
+
+        adjust(&mut agenda, &previous, -1);
+        Ok(agenda)
+    }
+
+    async fn update_status(&self, id: Uuid, from: AgendaStatus, to: AgendaStatus) -> Result<Option<AgendaEntity>, sqlx::Error> {
+        Ok(self
+            .agendas
+            .iter()
+            .find(|a| a.id == id && a.status == from)
+            .map(|a| AgendaEntity { status: to, ..a.clone() }))
+    }
+
+    async fn apply_schedule(&self) -> Result<ScheduleChanges, sqlx::Error> {
+        Ok(ScheduleChanges::default())
+    }
+
+    async fn find_counter_drifts(&self) -> Result<Vec<CounterDrift>, sqlx::Error> {
+        Ok(self.drifts.clone())
+    }
+
+    async fn repair_counters(&self, agenda_id: Uuid) -> Result<Vec<CounterDrift>, sqlx::Error> {
+        self.find_by_id(agenda_id).await?.ok_or(sqlx::Error::RowNotFound)?;
+
+        Ok(self.drifts.iter().filter(|d| d.agenda_id == agenda_id).cloned().collect())
+    }
+
+    async fn flush_counters(&self) -> Result<usize, sqlx::Error> {
+        Ok(0)
+    }
+
+    async fn find_audit_head(&self, _agenda_id: Uuid) -> Result<Option<AuditEntry>, sqlx::Error> {
+        Ok(self.audit.entries.last().cloned())
+    }
+
+    async fn find_audit_trail(&self, _agenda_id: Uuid) -> Result<AuditTrail, sqlx::Error> {
+        Ok(self.audit.clone())
+    }
+
+    async fn find_receipt(&self, _agenda_id: Uuid, _receipt: &str) -> Result<Option<ReceiptEntity>, sqlx::Error> {
+        Ok(None)
+    }
+
+    async fn find_receipt_root(&self, _agenda_id: Uuid) -> Result<Option<ReceiptRootEntity>, sqlx::Error> {
+        Ok(None)
+    }
+
+    async fn find_counted_receipts(&self, _agenda_id: Uuid) -> Result<Vec<String>, sqlx::Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// 투표 결과 + 새 영수증
+fn recorded(agenda: AgendaEntity, choice: &Choice) -> RecordedVote {
+    let receipt = commit(&new_salt(), agenda.id, Uuid::new_v4(), choice);
+    RecordedVote { agenda, receipt }
+}
+
+/// 찬/반/기권 카운터 조정 (선택지 득표는 Mock에서 추적하지 않음)
+fn adjust(agenda: &mut AgendaEntity, choice: &Choice, delta: i32) {
+    match choice {
+        Choice::Agree => agenda.agree_count += delta,
+        Choice::Disagree => agenda.disagree_count += delta,
+        Choice::Abstain => agenda.abstain_count += delta,
+        _ => {}
+    }
+}
+
+/// SQL의 Keyset 페이지네이션 흉내 (created_at DESC, id DESC 정렬 후 커서 이후만)
+fn page<'a>(
+    agendas: impl Iterator<Item = &'a AgendaEntity>,
+    cursor: Option<AgendaCursor>,
+    limit: i64,
+) -> Vec<AgendaEntity> {
+    let mut sorted: Vec<AgendaEntity> = agendas
+        .filter(|a| cursor.is_none_or(|c| (a.created_at, a.id) < (c.created_at, c.id)))
+        .cloned()
+        .collect();
+    sorted.sort_by_key(|a| std::cmp::Reverse((a.created_at, a.id)));
+    sorted.truncate(limit as usize);
+    sorted
+}
+
+/// PostgreSQL의 unique 제약 위반을 흉내내는 가짜 DB 에러
+#[derive(Debug)]
+pub struct UniqueViolation;
+
+impl std::fmt::Display for UniqueViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for UniqueViolation {}
+
+impl DatabaseError for UniqueViolation {
+    fn message(&self) -> &str {
+        "duplicate key value violates unique constraint \"uk_vote_user_agenda\""
+    }
+
+    fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn constraint(&self) -> Option<&str> {
+        Some("uk_vote_user_agenda")
+    }
+
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::UniqueViolation
+    }
 }
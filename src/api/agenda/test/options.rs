@@ -0,0 +1,152 @@
+use crate::api::agenda::dtos::{CreateAgendaRequest, VoteRequest};
+use crate::api::agenda::repository::Choice;
+use crate::api::agenda::service::{change_vote, create_agenda, get_agenda, vote};
+use crate::api::error::AppError;
+use super::{ballot, open_agenda, options_for, MockAgendaRepository};
+use uuid::Uuid;
+
+fn with_options(labels: &[&str]) -> CreateAgendaRequest {
+    CreateAgendaRequest {
+        title: "Lunch".to_string(),
+        options: labels.iter().map(|l| l.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+fn pick(option_id: Uuid) -> VoteRequest {
+    VoteRequest {
+        option_id: Some(option_id),
+        ..Default::default()
+    }
+}
+
+// 테스트 1: 선택지가 있는 안건 생성 성공
+#[tokio::test]
+async fn test_create_agenda_with_options() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let result = create_agenda(&mock_repo, with_options(&["Pizza", " Sushi "]), Uuid::new_v4()).await;
+
+    assert!(result.is_ok());
+}
+
+// 테스트 2: 선택지 1개 / 11개 → BadRequest
+#[tokio::test]
+async fn test_create_agenda_option_count_out_of_range() {
+    let mock_repo = MockAgendaRepository::default();
+    let too_many: Vec<String> = (0..11).map(|i| format!("Option {}", i)).collect();
+    let too_many: Vec<&str> = too_many.iter().map(String::as_str).collect();
+
+    let one = create_agenda(&mock_repo, with_options(&["Only"]), Uuid::new_v4()).await;
+    let eleven = create_agenda(&mock_repo, with_options(&too_many), Uuid::new_v4()).await;
+
+    assert!(matches!(one.unwrap_err(), AppError::BadRequest(..)));
+    assert!(matches!(eleven.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 3: 공백 제거 후 중복/빈 라벨 → BadRequest
+#[tokio::test]
+async fn test_create_agenda_invalid_labels() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let duplicate = create_agenda(&mock_repo, with_options(&["Pizza", "Pizza "]), Uuid::new_v4()).await;
+    let blank = create_agenda(&mock_repo, with_options(&["Pizza", "  "]), Uuid::new_v4()).await;
+
+    assert!(matches!(duplicate.unwrap_err(), AppError::BadRequest(..)));
+    assert!(matches!(blank.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 4: 안건 조회 시 선택지 포함, 찬/반 안건은 빈 배열
+#[tokio::test]
+async fn test_get_agenda_includes_options() {
+    let multi = open_agenda();
+    let binary = open_agenda();
+    let (multi_id, binary_id) = (multi.id, binary.id);
+    let mock_repo = MockAgendaRepository {
+        agendas: vec![multi, binary],
+        options: options_for(multi_id, &["Pizza", "Sushi", "Ramen"]),
+        ..Default::default()
+    };
+
+    let multi = get_agenda(&mock_repo, multi_id).await.unwrap();
+    let binary = get_agenda(&mock_repo, binary_id).await.unwrap();
+
+    let labels: Vec<&str> = multi.options.iter().map(|o| o.label.as_str()).collect();
+    assert_eq!(labels, ["Pizza", "Sushi", "Ramen"]);
+    assert!(binary.options.is_empty());
+}
+
+// 테스트 5: 선택지 투표 성공
+#[tokio::test]
+async fn test_vote_for_option() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let options = options_for(agenda_id, &["Pizza", "Sushi"]);
+    let sushi = options[1].id;
+    let mock_repo = MockAgendaRepository {
+        options,
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let response = vote(&mock_repo, agenda_id, Uuid::new_v4(), pick(sushi)).await.unwrap();
+
+    assert_eq!(response.option_id, Some(sushi));
+    assert_eq!(response.is_agree, None);
+    assert_eq!(response.options.len(), 2);
+}
+
+// 테스트 6: 다른 안건의 선택지 → BadRequest
+#[tokio::test]
+async fn test_vote_for_foreign_option() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        options: options_for(agenda_id, &["Pizza", "Sushi"]),
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let result = vote(&mock_repo, agenda_id, Uuid::new_v4(), pick(Uuid::new_v4())).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 7: 안건 종류와 맞지 않는 투표 형식 → BadRequest
+#[tokio::test]
+async fn test_vote_choice_kind_mismatch() {
+    let multi = open_agenda();
+    let binary = open_agenda();
+    let (multi_id, binary_id) = (multi.id, binary.id);
+    let options = options_for(multi_id, &["Pizza", "Sushi"]);
+    let pizza = options[0].id;
+    let mock_repo = MockAgendaRepository {
+        agendas: vec![multi, binary],
+        options,
+        ..Default::default()
+    };
+
+    let agree_on_multi = vote(&mock_repo, multi_id, Uuid::new_v4(), ballot(true)).await;
+    let option_on_binary = vote(&mock_repo, binary_id, Uuid::new_v4(), pick(pizza)).await;
+    let empty = vote(&mock_repo, binary_id, Uuid::new_v4(), VoteRequest::default()).await;
+
+    assert!(matches!(agree_on_multi.unwrap_err(), AppError::BadRequest(..)));
+    assert!(matches!(option_on_binary.unwrap_err(), AppError::BadRequest(..)));
+    assert!(matches!(empty.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 8: 선택지 간 투표 변경
+#[tokio::test]
+async fn test_change_vote_between_options() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let options = options_for(agenda_id, &["Pizza", "Sushi"]);
+    let (pizza, sushi) = (options[0].id, options[1].id);
+    let mock_repo = MockAgendaRepository {
+        options,
+        existing_vote: Some(Choice::Option(pizza)),
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let response = change_vote(&mock_repo, agenda_id, Uuid::new_v4(), pick(sushi)).await.unwrap();
+
+    assert_eq!(response.option_id, Some(sushi));
+}
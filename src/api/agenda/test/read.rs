@@ -0,0 +1,94 @@
+use crate::api::agenda::service::{get_agenda, list_agendas, list_my_agendas};
+use crate::api::agenda::repository::AgendaEntity;
+use crate::api::error::AppError;
+use super::{open_agenda, MockAgendaRepository};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+/// 1분 간격으로 생성된 안건 n개 (뒤로 갈수록 최신)
+fn agendas_by(created_by: Uuid, n: i64) -> Vec<AgendaEntity> {
+    let base = Utc::now();
+    (0..n)
+        .map(|i| AgendaEntity {
+            title: format!("Agenda {}", i),
+            created_by,
+            created_at: base + Duration::minutes(i),
+            ..open_agenda()
+        })
+        .collect()
+}
+
+// 테스트 1: 단건 조회 성공
+#[tokio::test]
+async fn test_get_agenda_success() {
+    let agendas = agendas_by(Uuid::new_v4(), 1);
+    let target = agendas[0].clone();
+    let mock_repo = MockAgendaRepository {
+        agendas,
+        ..Default::default()
+    };
+
+    let result = get_agenda(&mock_repo, target.id).await;
+
+    let response = result.unwrap();
+    assert_eq!(response.id, target.id);
+    assert_eq!(response.title, target.title);
+}
+
+// 테스트 2: 존재하지 않는 안건 → NotFound
+#[tokio::test]
+async fn test_get_agenda_not_found() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let result = get_agenda(&mock_repo, Uuid::new_v4()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+}
+
+// 테스트 3: 커서를 따라가며 전체 목록을 최신순으로 중복 없이 조회
+#[tokio::test]
+async fn test_list_agendas_pagination() {
+    let mock_repo = MockAgendaRepository {
+        agendas: agendas_by(Uuid::new_v4(), 3),
+        ..Default::default()
+    };
+
+    let first = list_agendas(&mock_repo, None, Some(2)).await.unwrap();
+    assert_eq!(first.items.len(), 2);
+    assert_eq!(first.items[0].title, "Agenda 2");
+    assert_eq!(first.items[1].title, "Agenda 1");
+    assert!(first.next_cursor.is_some());
+
+    let second = list_agendas(&mock_repo, first.next_cursor, Some(2)).await.unwrap();
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(second.items[0].title, "Agenda 0");
+    assert!(second.next_cursor.is_none());
+}
+
+// 테스트 4: 잘못된 커서 → BadRequest
+#[tokio::test]
+async fn test_list_agendas_invalid_cursor() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let result = list_agendas(&mock_repo, Some("not-a-cursor".to_string()), None).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 5: 내가 만든 안건만 조회
+#[tokio::test]
+async fn test_list_my_agendas() {
+    let me = Uuid::new_v4();
+    let mut agendas = agendas_by(me, 2);
+    agendas.extend(agendas_by(Uuid::new_v4(), 3));
+    let mock_repo = MockAgendaRepository {
+        agendas,
+        ..Default::default()
+    };
+
+    let result = list_my_agendas(&mock_repo, me, None, None).await.unwrap();
+
+    assert_eq!(result.items.len(), 2);
+    assert!(result.items.iter().all(|a| a.created_by == me.to_string()));
+    assert!(result.next_cursor.is_none());
+}
@@ -0,0 +1,124 @@
+use uuid::Uuid;
+use crate::api::agenda::receipt::{self, is_receipt, merkle_proof, merkle_root, verify_proof};
+use crate::api::agenda::repository::{traits::AgendaRepository, AgendaStatus, Choice, ReceiptStatus};
+use crate::api::agenda::service::{change_vote, create_agenda, get_receipt, get_receipt_root, retract_vote, vote};
+use crate::api::error::AppError;
+use super::ballot;
+use super::memory::{request, setup};
+
+fn receipts(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| receipt::commit(&[i as u8; 32], Uuid::nil(), Uuid::nil(), &Choice::Agree))
+        .collect()
+}
+
+// 테스트 1: 잎 수와 관계없이 모든 영수증의 포함 증명이 루트로 이어지고, 순서를 바꿔도 루트가 같음
+#[test]
+fn test_merkle_proofs_verify() {
+    for count in 1..=9 {
+        let leaves = receipts(count);
+        let root = merkle_root(&leaves);
+        let mut reversed = leaves.clone();
+        reversed.reverse();
+        assert_eq!(merkle_root(&reversed), root);
+
+        for leaf in &leaves {
+            let (_, proof) = merkle_proof(&leaves, leaf).unwrap();
+            assert!(verify_proof(leaf, &proof, &root), "{} leaves", count);
+        }
+    }
+}
+
+// 테스트 2: 트리에 없는 영수증은 증명이 없고, 증명을 고치거나 다른 영수증에 쓰면 실패
+#[test]
+fn test_merkle_rejects_non_members() {
+    let leaves = receipts(5);
+    let root = merkle_root(&leaves);
+    let outsider = receipts(6).pop().unwrap();
+
+    assert!(merkle_proof(&leaves, &outsider).is_none());
+
+    let (_, mut proof) = merkle_proof(&leaves, &leaves[0]).unwrap();
+    assert!(!verify_proof(&outsider, &proof, &root));
+    proof[0].hash = outsider;
+    assert!(!verify_proof(&leaves[0], &proof, &root));
+
+    // 빈 트리의 루트는 SHA-256("")
+    assert_eq!(merkle_root(&[]), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+}
+
+// 테스트 3: 같은 선택이라도 영수증은 투표마다 다름 (salt)
+#[tokio::test]
+async fn test_receipts_do_not_reveal_choice() {
+    let (repo, users) = setup(2).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+
+    let first = vote(&repo, agenda.id, users[0], ballot(true)).await.unwrap().receipt;
+    let second = vote(&repo, agenda.id, users[1], ballot(true)).await.unwrap().receipt;
+
+    assert!(is_receipt(&first) && is_receipt(&second));
+    assert_ne!(first, second);
+}
+
+// 테스트 4: 변경하면 이전 영수증은 superseded, 철회하면 retracted
+#[tokio::test]
+async fn test_receipt_status_follows_vote() {
+    let (repo, users) = setup(1).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+
+    let first = vote(&repo, agenda.id, users[0], ballot(true)).await.unwrap().receipt;
+    let same = change_vote(&repo, agenda.id, users[0], ballot(true)).await.unwrap().receipt;
+    assert_eq!(same, first);
+
+    let second = change_vote(&repo, agenda.id, users[0], ballot(false)).await.unwrap().receipt;
+    assert_ne!(second, first);
+    assert_eq!(get_receipt(&repo, agenda.id, &first).await.unwrap().status, ReceiptStatus::Superseded);
+
+    let counted = get_receipt(&repo, agenda.id, &second).await.unwrap();
+    assert_eq!(counted.status, ReceiptStatus::Counted);
+    assert!(counted.inclusion.is_none()); // 마감 전
+
+    retract_vote(&repo, agenda.id, users[0]).await.unwrap();
+    assert_eq!(get_receipt(&repo, agenda.id, &second).await.unwrap().status, ReceiptStatus::Retracted);
+}
+
+// 테스트 5: 마감하면 집계된 영수증의 루트가 공개되고 포함 증명이 그 루트로 이어짐
+#[tokio::test]
+async fn test_root_published_at_close() {
+    let (repo, users) = setup(3).await;
+    let agenda = create_agenda(&repo, request("Agenda", &[]), users[0]).await.unwrap();
+    let mut counted = Vec::new();
+    for user in &users {
+        counted.push(vote(&repo, agenda.id, *user, ballot(true)).await.unwrap().receipt);
+    }
+    let retracted = counted.pop().unwrap();
+    retract_vote(&repo, agenda.id, users[2]).await.unwrap();
+
+    assert!(matches!(get_receipt_root(&repo, agenda.id).await, Err(AppError::NotFound(_))));
+    repo.update_status(agenda.id, AgendaStatus::Open, AgendaStatus::Closed).await.unwrap();
+
+    let published = get_receipt_root(&repo, agenda.id).await.unwrap();
+    counted.sort();
+    assert_eq!(published.leaf_count, 2);
+    assert_eq!(published.receipts, counted);
+    assert_eq!(published.root, merkle_root(&counted));
+
+    for receipt in &counted {
+        let inclusion = get_receipt(&repo, agenda.id, receipt).await.unwrap().inclusion.unwrap();
+        assert_eq!(inclusion.root, published.root);
+        assert!(verify_proof(receipt, &inclusion.proof, &published.root));
+    }
+    assert!(get_receipt(&repo, agenda.id, &retracted).await.unwrap().inclusion.is_none());
+}
+
+// 테스트 6: 다른 안건의 영수증이나 없는 영수증은 404
+#[tokio::test]
+async fn test_receipt_of_other_agenda_not_found() {
+    let (repo, users) = setup(1).await;
+    let first = create_agenda(&repo, request("First", &[]), users[0]).await.unwrap();
+    let second = create_agenda(&repo, request("Second", &[]), users[0]).await.unwrap();
+    let receipt = vote(&repo, first.id, users[0], ballot(true)).await.unwrap().receipt;
+
+    assert!(matches!(get_receipt(&repo, second.id, &receipt).await, Err(AppError::NotFound(_))));
+    assert!(matches!(get_receipt(&repo, first.id, &receipts(1)[0]).await, Err(AppError::NotFound(_))));
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+use crate::api::agenda::reconciler;
+use crate::api::agenda::repository::{memory::MemoryAgendaRepository, traits::AgendaRepository, CounterDrift, CounterKind};
+use crate::api::agenda::service::{create_agenda, reconcile_counters};
+use crate::api::auth::repository::{memory::MemoryUserRepository, traits::UserRepository};
+use crate::api::memory::MemoryDb;
+use super::{memory::request, open_agenda, MockAgendaRepository};
+use uuid::Uuid;
+
+fn drift(agenda_id: Uuid, counter: CounterKind, cached: i32, actual: i64) -> CounterDrift {
+    CounterDrift {
+        agenda_id,
+        option_id: None,
+        counter,
+        cached,
+        actual,
+    }
+}
+
+// 테스트 1: 어긋난 카운터 없음 → 빈 보고서
+#[tokio::test]
+async fn test_reconcile_no_drift() {
+    let mock_repo = MockAgendaRepository::with_agenda(open_agenda());
+
+    let report = reconcile_counters(&mock_repo, true).await.unwrap();
+
+    assert!(report.drifts.is_empty());
+    assert_eq!(report.agendas_affected, 0);
+}
+
+// 테스트 2: 점검만 - 어긋난 카운터를 보고만 하고 복구하지 않음
+#[tokio::test]
+async fn test_reconcile_dry_run() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        drifts: vec![
+            drift(agenda_id, CounterKind::Agree, 5, 3),
+            drift(agenda_id, CounterKind::Abstain, 0, 1),
+        ],
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let report = reconcile_counters(&mock_repo, false).await.unwrap();
+
+    assert!(!report.repaired);
+    assert_eq!(report.drifts.len(), 2);
+    assert_eq!(report.agendas_affected, 1);
+    assert_eq!(report.drifts[0].cached, 5);
+    assert_eq!(report.drifts[0].actual, 3);
+}
+
+// 테스트 3: 복구 - 안건별로 복구한 내역 반환
+#[tokio::test]
+async fn test_reconcile_repair() {
+    let first = open_agenda();
+    let second = open_agenda();
+    let (first_id, second_id) = (first.id, second.id);
+    let mock_repo = MockAgendaRepository {
+        agendas: vec![first, second],
+        drifts: vec![
+            drift(first_id, CounterKind::Agree, 5, 3),
+            drift(second_id, CounterKind::Disagree, 1, 2),
+            drift(first_id, CounterKind::Disagree, 0, 1),
+        ],
+        ..Default::default()
+    };
+
+    let report = reconcile_counters(&mock_repo, true).await.unwrap();
+
+    assert!(report.repaired);
+    assert_eq!(report.drifts.len(), 3);
+    assert_eq!(report.agendas_affected, 2);
+}
+
+// 테스트 4: 복구 중 사라진 안건은 건너뜀
+#[tokio::test]
+async fn test_reconcile_skips_deleted_agenda() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        drifts: vec![
+            drift(agenda_id, CounterKind::Agree, 5, 3),
+            drift(Uuid::new_v4(), CounterKind::Agree, 1, 0),
+        ],
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let report = reconcile_counters(&mock_repo, true).await.unwrap();
+
+    assert_eq!(report.drifts.len(), 1);
+    assert_eq!(report.drifts[0].agenda_id, agenda_id);
+}
+
+// 테스트 5: 주기 점검은 어긋난 카운터를 보고만 하고 복구하지 않음
+#[tokio::test]
+async fn test_periodic_reconcile_does_not_repair() {
+    let db = Arc::new(MemoryDb::default());
+    let user_id = MemoryUserRepository::new(&db).save("user", "hash").await.unwrap();
+    let repo = MemoryAgendaRepository::new(&db);
+    let agenda = create_agenda(&repo, request("Agenda", &[]), user_id).await.unwrap();
+    db.lock().agendas.iter_mut().find(|a| a.id == agenda.id).unwrap().agree_count = 3;
+
+    reconciler::run_once(&repo).await;
+
+    assert_eq!(repo.find_by_id(agenda.id).await.unwrap().unwrap().agree_count, 3);
+    assert_eq!(reconcile_counters(&repo, false).await.unwrap().drifts.len(), 1);
+}
@@ -0,0 +1,125 @@
+use crate::api::agenda::decision::Outcome;
+use crate::api::agenda::dtos::CreateAgendaRequest;
+use crate::api::agenda::repository::{AgendaEntity, AgendaStatus, TiePolicy};
+use crate::api::agenda::service::{create_agenda, get_agenda, get_result, tally_agenda};
+use crate::api::error::AppError;
+use super::{open_agenda, options_for, MockAgendaRepository};
+use uuid::Uuid;
+
+// 테스트 1: 마감된 찬/반 안건 - 최종 결과
+#[tokio::test]
+async fn test_result_closed_motion() {
+    let agenda = AgendaEntity {
+        agree_count: 7,
+        disagree_count: 3,
+        status: AgendaStatus::Closed,
+        quorum: Some(5),
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let response = get_result(&mock_repo, agenda_id).await.unwrap();
+
+    assert!(response.is_final);
+    assert_eq!(response.outcome, Outcome::Passed);
+    assert_eq!(response.margin, 4);
+    assert_eq!(response.turnout, 10);
+    assert!(response.quorum_met);
+}
+
+// 테스트 2: 진행 중인 안건 - 잠정 결과
+#[tokio::test]
+async fn test_result_open_agenda_is_provisional() {
+    let agenda = AgendaEntity {
+        agree_count: 2,
+        disagree_count: 2,
+        tie_policy: TiePolicy::Tie,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let response = get_result(&mock_repo, agenda_id).await.unwrap();
+
+    assert!(!response.is_final);
+    assert_eq!(response.outcome, Outcome::Tied);
+}
+
+// 테스트 3: 마감 전 비공개 안건 - 결과/집계 403, 안건 조회 시 득표 숨김
+#[tokio::test]
+async fn test_hidden_results_before_close() {
+    let agenda = AgendaEntity {
+        agree_count: 3,
+        hide_results: true,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = get_result(&mock_repo, agenda_id).await;
+    let tally = tally_agenda(&mock_repo, agenda_id).await;
+    let agenda = get_agenda(&mock_repo, agenda_id).await.unwrap();
+
+    assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+    assert!(matches!(tally.unwrap_err(), AppError::Forbidden(_)));
+    assert_eq!(agenda.agree_count, None);
+    assert_eq!(agenda.disagree_count, None);
+}
+
+// 테스트 4: 비공개 안건도 마감 후에는 공개
+#[tokio::test]
+async fn test_hidden_results_after_close() {
+    let agenda = AgendaEntity {
+        agree_count: 3,
+        hide_results: true,
+        status: AgendaStatus::Closed,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let response = get_result(&mock_repo, agenda_id).await.unwrap();
+    let agenda = get_agenda(&mock_repo, agenda_id).await.unwrap();
+
+    assert_eq!(response.outcome, Outcome::Passed);
+    assert_eq!(agenda.agree_count, Some(3));
+}
+
+// 테스트 5: 선택지 안건 - 당선 선택지 반환
+#[tokio::test]
+async fn test_result_election_winner() {
+    let agenda = AgendaEntity {
+        status: AgendaStatus::Closed,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let options = options_for(agenda_id, &["Pizza", "Sushi"]);
+    let sushi = options[1].id;
+    let mock_repo = MockAgendaRepository {
+        ballots: vec![vec![sushi], vec![sushi], vec![options[0].id]],
+        options,
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let response = get_result(&mock_repo, agenda_id).await.unwrap();
+
+    assert_eq!(response.outcome, Outcome::Passed);
+    assert_eq!(response.winner, Some(sushi));
+    assert_eq!(response.turnout, 3);
+}
+
+// 테스트 6: 정족수가 1 미만 → BadRequest
+#[tokio::test]
+async fn test_create_agenda_invalid_quorum() {
+    let mock_repo = MockAgendaRepository::default();
+    let payload = CreateAgendaRequest {
+        title: "Quorum".to_string(),
+        quorum: Some(0),
+        ..Default::default()
+    };
+
+    let result = create_agenda(&mock_repo, payload, Uuid::new_v4()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
@@ -0,0 +1,215 @@
+use crate::api::agenda::repository::VotingMethod;
+use crate::api::agenda::tally::{tally, Score};
+use uuid::Uuid;
+
+/// 선택지 n개 (표시 순서)
+fn candidates(n: usize) -> Vec<Uuid> {
+    (0..n).map(|_| Uuid::new_v4()).collect()
+}
+
+/// 같은 용지 `count`장
+fn repeat(ballot: &[Uuid], count: usize) -> Vec<Vec<Uuid>> {
+    vec![ballot.to_vec(); count]
+}
+
+fn scores(round: &[Score]) -> Vec<u64> {
+    round.iter().map(|s| s.score).collect()
+}
+
+// 테스트 1: 단일 선택 - 첫 번째 선택지만 1표
+#[test]
+fn test_single_choice_counts_first_preference() {
+    let c = candidates(3);
+    let ballots = [repeat(&[c[0]], 2), repeat(&[c[1], c[0]], 1)].concat();
+
+    let result = tally(VotingMethod::SingleChoice, &c, &ballots);
+
+    assert_eq!(result.total_ballots, 3);
+    assert_eq!(result.rounds.len(), 1);
+    assert_eq!(scores(&result.rounds[0].scores), [2, 1, 0]);
+    assert_eq!(result.winners, [c[0]]);
+}
+
+// 테스트 2: 승인 투표 - 나열한 모든 선택지에 1표씩
+#[test]
+fn test_approval_counts_every_selection() {
+    let c = candidates(3);
+    let ballots = vec![vec![c[0], c[1]], vec![c[1], c[2]], vec![c[1]]];
+
+    let result = tally(VotingMethod::Approval, &c, &ballots);
+
+    assert_eq!(scores(&result.rounds[0].scores), [1, 3, 1]);
+    assert_eq!(result.winners, [c[1]]);
+}
+
+// 테스트 3: 보르다 - 선택지 n개일 때 k순위에 n-1-k점, 순위 밖은 0점
+#[test]
+fn test_borda_points() {
+    let c = candidates(4);
+    let ballots = vec![
+        vec![c[0], c[1], c[2], c[3]], // 3, 2, 1, 0
+        vec![c[1], c[2]],             // 0, 3, 2, 0
+        vec![c[3], c[1], c[0]],       // 1, 2, 0, 3
+    ];
+
+    let result = tally(VotingMethod::Borda, &c, &ballots);
+
+    assert_eq!(scores(&result.rounds[0].scores), [4, 7, 3, 3]);
+    assert_eq!(result.winners, [c[1]]);
+}
+
+// 테스트 4: 보르다 - 1순위 최다 득표자와 당선자가 다를 수 있음
+#[test]
+fn test_borda_consensus_beats_plurality() {
+    let c = candidates(3);
+    let ballots = [
+        repeat(&[c[0], c[2], c[1]], 3),
+        repeat(&[c[1], c[2], c[0]], 2),
+        repeat(&[c[2], c[1], c[0]], 1),
+    ]
+    .concat();
+
+    let plurality = tally(VotingMethod::SingleChoice, &c, &ballots);
+    let borda = tally(VotingMethod::Borda, &c, &ballots);
+
+    assert_eq!(plurality.winners, [c[0]]);
+    // A: 3*2 = 6, B: 2*2 + 1 = 5, C: 3 + 2 + 2 = 7
+    assert_eq!(scores(&borda.rounds[0].scores), [6, 5, 7]);
+    assert_eq!(borda.winners, [c[2]]);
+}
+
+// 테스트 5: 즉석 결선 - 1라운드 과반이면 바로 당선
+#[test]
+fn test_instant_runoff_first_round_majority() {
+    let c = candidates(3);
+    let ballots = [repeat(&[c[0]], 3), repeat(&[c[1]], 1), repeat(&[c[2]], 1)].concat();
+
+    let result = tally(VotingMethod::InstantRunoff, &c, &ballots);
+
+    assert_eq!(result.rounds.len(), 1);
+    assert!(result.rounds[0].eliminated.is_empty());
+    assert_eq!(result.winners, [c[0]]);
+}
+
+// 테스트 6: 즉석 결선 - 최하위 탈락 후 표 이양으로 역전
+#[test]
+fn test_instant_runoff_transfers_eliminated_votes() {
+    let c = candidates(3);
+    let ballots = [
+        repeat(&[c[0]], 4),
+        repeat(&[c[1], c[2]], 3),
+        repeat(&[c[2], c[1]], 2),
+    ]
+    .concat();
+
+    let result = tally(VotingMethod::InstantRunoff, &c, &ballots);
+
+    assert_eq!(result.rounds.len(), 2);
+    assert_eq!(scores(&result.rounds[0].scores), [4, 3, 2]);
+    assert_eq!(result.rounds[0].eliminated, [c[2]]);
+    // 2라운드에는 남은 선택지만 표시
+    let second = &result.rounds[1];
+    assert_eq!(second.scores.iter().map(|s| s.option_id).collect::<Vec<_>>(), [c[0], c[1]]);
+    assert_eq!(scores(&second.scores), [4, 5]);
+    assert_eq!(result.winners, [c[1]]);
+}
+
+// 테스트 7: 즉석 결선 - 순위를 모두 소진한 용지는 과반 기준에서 제외
+#[test]
+fn test_instant_runoff_exhausted_ballots() {
+    let c = candidates(3);
+    let ballots = [
+        repeat(&[c[0]], 3),
+        repeat(&[c[1]], 2),
+        repeat(&[c[2]], 2), // 탈락 후 더 이상 선호가 없음
+    ]
+    .concat();
+
+    let result = tally(VotingMethod::InstantRunoff, &c, &ballots);
+
+    // 1라운드: B, C 동률 최하위 → 함께 탈락
+    assert_eq!(result.rounds[0].eliminated, [c[1], c[2]]);
+    let last = result.rounds.last().unwrap();
+    assert_eq!(last.exhausted, 4);
+    assert_eq!(result.winners, [c[0]]);
+}
+
+// 테스트 8: 즉석 결선 - 남은 선택지가 모두 동률이면 공동 당선 (동률 처리는 호출 측 규칙에 맡김)
+#[test]
+fn test_instant_runoff_tie() {
+    let c = candidates(2);
+    let ballots = [repeat(&[c[0]], 2), repeat(&[c[1]], 2)].concat();
+
+    let result = tally(VotingMethod::InstantRunoff, &c, &ballots);
+
+    assert_eq!(result.rounds.len(), 1);
+    assert_eq!(result.winners, [c[0], c[1]]);
+}
+
+// 테스트 9: 즉석 결선 - 여러 라운드에 걸친 탈락
+#[test]
+fn test_instant_runoff_multiple_rounds() {
+    let c = candidates(4);
+    let ballots = [
+        repeat(&[c[0], c[3]], 5),
+        repeat(&[c[1], c[2]], 4),
+        repeat(&[c[2], c[1]], 3),
+        repeat(&[c[3], c[2]], 2),
+    ]
+    .concat();
+
+    let result = tally(VotingMethod::InstantRunoff, &c, &ballots);
+
+    // 1라운드: D 탈락 → C에 2표, 2라운드: A 5 B 4 C 5 → B 탈락 → C에 4표, 3라운드: A 5 C 9
+    assert_eq!(result.rounds.len(), 3);
+    assert_eq!(result.rounds[0].eliminated, [c[3]]);
+    assert_eq!(scores(&result.rounds[1].scores), [5, 4, 5]);
+    assert_eq!(result.rounds[1].eliminated, [c[1]]);
+    assert_eq!(scores(&result.rounds[2].scores), [5, 9]);
+    assert_eq!(result.winners, [c[2]]);
+}
+
+// 테스트 10: 표가 없으면 당선자 없음
+#[test]
+fn test_no_ballots_no_winner() {
+    let c = candidates(3);
+
+    for method in [
+        VotingMethod::SingleChoice,
+        VotingMethod::Approval,
+        VotingMethod::InstantRunoff,
+        VotingMethod::Borda,
+    ] {
+        let result = tally(method, &c, &[]);
+
+        assert_eq!(result.total_ballots, 0);
+        assert!(result.winners.is_empty(), "{:?}", method);
+    }
+}
+
+// 테스트 11: 안건에 없는 선택지와 한 용지 안의 중복은 무시
+#[test]
+fn test_unknown_and_duplicate_selections_ignored() {
+    let c = candidates(2);
+    let stranger = Uuid::new_v4();
+    let ballots = vec![vec![stranger, c[1], c[1], c[0]]];
+
+    let approval = tally(VotingMethod::Approval, &c, &ballots);
+    let borda = tally(VotingMethod::Borda, &c, &ballots);
+    let runoff = tally(VotingMethod::InstantRunoff, &c, &ballots);
+
+    assert_eq!(scores(&approval.rounds[0].scores), [1, 1]);
+    assert_eq!(scores(&borda.rounds[0].scores), [0, 1]);
+    assert_eq!(runoff.winners, [c[1]]);
+}
+
+// 테스트 12: 동률 최고 득표는 모두 당선자로 반환
+#[test]
+fn test_tied_winners() {
+    let c = candidates(3);
+    let ballots = vec![vec![c[0]], vec![c[2]]];
+
+    let result = tally(VotingMethod::SingleChoice, &c, &ballots);
+
+    assert_eq!(result.winners, [c[0], c[2]]);
+}
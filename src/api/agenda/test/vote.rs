@@ -0,0 +1,131 @@
+use crate::api::agenda::dtos::VoteRequest;
+use crate::api::agenda::repository::AgendaStatus;
+use crate::api::agenda::service::vote;
+use crate::api::error::AppError;
+use super::{ballot, open_agenda, MockAgendaRepository};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+
+// 테스트 1: 찬성 투표 성공
+#[tokio::test]
+async fn test_vote_agree_success() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(true)).await;
+
+    assert!(result.is_ok());
+    let response = result.unwrap();
+    assert_eq!(response.agenda_id, agenda_id);
+    assert_eq!(response.is_agree, Some(true));
+    assert_eq!(response.agree_count, Some(1));
+    assert_eq!(response.disagree_count, Some(0));
+}
+
+// 테스트 2: 반대 투표 성공
+#[tokio::test]
+async fn test_vote_disagree_success() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(false)).await;
+
+    let response = result.unwrap();
+    assert_eq!(response.is_agree, Some(false));
+    assert_eq!(response.agree_count, Some(0));
+    assert_eq!(response.disagree_count, Some(1));
+}
+
+// 테스트 3: 존재하지 않는 안건 → NotFound
+#[tokio::test]
+async fn test_vote_agenda_not_found() {
+    let mock_repo = MockAgendaRepository::default();
+
+    let result = vote(&mock_repo, Uuid::new_v4(), Uuid::new_v4(), ballot(true)).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+}
+
+// 테스트 4: 중복 투표 (uk_vote_user_agenda 위반) → AlreadyVoted
+#[tokio::test]
+async fn test_vote_duplicate() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository {
+        already_voted: true,
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let result = vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(true)).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::AlreadyVoted(_)));
+}
+
+// 테스트 5: 아직 시작 전인 안건 (draft) → AgendaClosed
+#[tokio::test]
+async fn test_vote_before_open() {
+    let agenda = crate::api::agenda::repository::AgendaEntity {
+        status: AgendaStatus::Draft,
+        opens_at: Some(Utc::now() + Duration::hours(1)),
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(true)).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::AgendaClosed(_)));
+}
+
+// 테스트 6: 마감 시각이 지난 안건 (스케줄러 반영 전이라도) → AgendaClosed
+#[tokio::test]
+async fn test_vote_after_close() {
+    let agenda = crate::api::agenda::repository::AgendaEntity {
+        closes_at: Some(Utc::now() - Duration::minutes(1)),
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = vote(&mock_repo, agenda_id, Uuid::new_v4(), ballot(true)).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::AgendaClosed(_)));
+}
+
+// 테스트 7: 기권 - 기권 카운터만 증가
+#[tokio::test]
+async fn test_vote_abstain() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+    let payload = VoteRequest {
+        abstain: true,
+        ..Default::default()
+    };
+
+    let response = vote(&mock_repo, agenda_id, Uuid::new_v4(), payload).await.unwrap();
+
+    assert!(response.abstain);
+    assert_eq!(response.is_agree, None);
+    assert_eq!(response.abstain_count, Some(1));
+    assert_eq!(response.agree_count, Some(0));
+    assert_eq!(response.disagree_count, Some(0));
+}
+
+// 테스트 8: 기권과 다른 선택을 함께 보냄 → BadRequest
+#[tokio::test]
+async fn test_vote_abstain_with_choice() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+    let payload = VoteRequest {
+        abstain: true,
+        ..ballot(true)
+    };
+
+    let result = vote(&mock_repo, agenda_id, Uuid::new_v4(), payload).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
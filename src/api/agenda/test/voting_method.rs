@@ -0,0 +1,116 @@
+use crate::api::agenda::dtos::{CreateAgendaRequest, VoteRequest};
+use crate::api::agenda::repository::{AgendaEntity, VotingMethod};
+use crate::api::agenda::service::{create_agenda, tally_agenda, vote};
+use crate::api::error::AppError;
+use super::{open_agenda, options_for, MockAgendaRepository};
+use uuid::Uuid;
+
+fn rank(option_ids: &[Uuid]) -> VoteRequest {
+    VoteRequest {
+        option_ids: Some(option_ids.to_vec()),
+        ..Default::default()
+    }
+}
+
+/// 주어진 투표 방식의 3지선다 안건이 저장된 Mock
+fn mock_with(method: VotingMethod) -> (MockAgendaRepository, Uuid, Vec<Uuid>) {
+    let agenda = AgendaEntity {
+        voting_method: method,
+        ..open_agenda()
+    };
+    let agenda_id = agenda.id;
+    let options = options_for(agenda_id, &["Alice", "Bob", "Carol"]);
+    let ids = options.iter().map(|o| o.id).collect();
+    let mock_repo = MockAgendaRepository {
+        options,
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+    (mock_repo, agenda_id, ids)
+}
+
+// 테스트 1: 선택지 없는 안건에 single_choice 외 방식 → BadRequest
+#[tokio::test]
+async fn test_create_binary_agenda_with_ranked_method() {
+    let mock_repo = MockAgendaRepository::default();
+    let payload = CreateAgendaRequest {
+        title: "Ranked".to_string(),
+        voting_method: VotingMethod::InstantRunoff,
+        ..Default::default()
+    };
+
+    let result = create_agenda(&mock_repo, payload, Uuid::new_v4()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 2: 순위 투표 성공 - 응답에 순위 그대로 반환
+#[tokio::test]
+async fn test_ranked_vote_success() {
+    let (mock_repo, agenda_id, c) = mock_with(VotingMethod::InstantRunoff);
+
+    let response = vote(&mock_repo, agenda_id, Uuid::new_v4(), rank(&[c[2], c[0]])).await.unwrap();
+
+    assert_eq!(response.option_ids, Some(vec![c[2], c[0]]));
+    assert_eq!(response.option_id, None);
+}
+
+// 테스트 3: 순위 투표에 중복/빈/모르는 선택지 → BadRequest
+#[tokio::test]
+async fn test_ranked_vote_invalid_ballot() {
+    let (mock_repo, agenda_id, c) = mock_with(VotingMethod::Borda);
+
+    let duplicate = vote(&mock_repo, agenda_id, Uuid::new_v4(), rank(&[c[0], c[0]])).await;
+    let empty = vote(&mock_repo, agenda_id, Uuid::new_v4(), rank(&[])).await;
+    let unknown = vote(&mock_repo, agenda_id, Uuid::new_v4(), rank(&[Uuid::new_v4()])).await;
+
+    assert!(matches!(duplicate.unwrap_err(), AppError::BadRequest(..)));
+    assert!(matches!(empty.unwrap_err(), AppError::BadRequest(..)));
+    assert!(matches!(unknown.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 4: 투표 방식과 맞지 않는 필드 → BadRequest
+#[tokio::test]
+async fn test_vote_field_must_match_method() {
+    let (approval_repo, approval_id, a) = mock_with(VotingMethod::Approval);
+    let (single_repo, single_id, s) = mock_with(VotingMethod::SingleChoice);
+
+    let single_on_approval = VoteRequest {
+        option_id: Some(a[0]),
+        ..Default::default()
+    };
+    let result = vote(&approval_repo, approval_id, Uuid::new_v4(), single_on_approval).await;
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+
+    let result = vote(&single_repo, single_id, Uuid::new_v4(), rank(&[s[0]])).await;
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 5: 집계 - 저장된 용지로 라운드별 결과와 라벨 반환
+#[tokio::test]
+async fn test_tally_agenda() {
+    let (mock_repo, agenda_id, c) = mock_with(VotingMethod::InstantRunoff);
+    let mock_repo = MockAgendaRepository {
+        ballots: vec![vec![c[0]], vec![c[0]], vec![c[1], c[0]], vec![c[2], c[1]], vec![c[1]]],
+        ..mock_repo
+    };
+
+    let response = tally_agenda(&mock_repo, agenda_id).await.unwrap();
+
+    assert_eq!(response.voting_method, VotingMethod::InstantRunoff);
+    assert_eq!(response.total_ballots, 5);
+    assert_eq!(response.rounds[0].scores[2].label, "Carol");
+    assert_eq!(response.rounds[0].eliminated, [c[2]]);
+    assert_eq!(response.winners, [c[1]]);
+}
+
+// 테스트 6: 찬/반 안건 집계 → BadRequest
+#[tokio::test]
+async fn test_tally_binary_agenda() {
+    let agenda = open_agenda();
+    let agenda_id = agenda.id;
+    let mock_repo = MockAgendaRepository::with_agenda(agenda);
+
+    let result = tally_agenda(&mock_repo, agenda_id).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
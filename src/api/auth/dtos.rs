@@ -1,16 +1,49 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use crate::api::validation::{is_username_char, Validate, Validator};
+use super::{password::{MAX_PASSWORD_LEN, MIN_PASSWORD_LEN}, role::Role};
+
+/// 사용자 이름 길이 (문자 수)
+const USERNAME_MIN_LEN: usize = 3;
+const USERNAME_MAX_LEN: usize = 32;
+
+/// 로그인 입력 최대 길이 - 가입 규칙이 생기기 전 계정도 로그인할 수 있도록 느슨하게
+const LOGIN_MAX_LEN: usize = 1024;
 
 // [Request] 회원가입 요청 데이터
 #[derive(Deserialize)]
 pub struct SignupRequest {
     pub username: String,
+    pub password: String,
+}
+
+// 사용자 이름: 앞뒤 공백 제거 + NFKC (전각/호환 문자로 같은 이름을 다시 만들지 못하도록)
+// 비밀번호: NFKC만 (공백도 비밀번호의 일부), 로그인도 같은 정규화를 거쳐 비교
+impl Validate for SignupRequest {
+    fn rules(&mut self, v: &mut Validator) {
+        v.text("username", &mut self.username)
+            .trim()
+            .nfkc()
+            .length(USERNAME_MIN_LEN, USERNAME_MAX_LEN)
+            .chars(is_username_char, "letters, digits, '_', '-' and '.'");
+        v.text("password", &mut self.password)
+            .nfkc()
+            .length(MIN_PASSWORD_LEN, MAX_PASSWORD_LEN);
+    }
 }
 
 // [Request] 로그인 요청 데이터
 #[derive(Deserialize)]
 pub struct LoginRequest {
     pub username: String,
+    pub password: String,
+}
+
+impl Validate for LoginRequest {
+    fn rules(&mut self, v: &mut Validator) {
+        v.text("username", &mut self.username).trim().nfkc().length(1, LOGIN_MAX_LEN);
+        v.text("password", &mut self.password).nfkc().length(1, LOGIN_MAX_LEN);
+    }
 }
 
 // [Response] 응답 데이터 (회원가입)
@@ -20,10 +53,25 @@ pub struct UserResponse {
     pub username: String,
 }
 
-// [Response] 응답 데이터 (로그인)
+// [Request] Access Token 재발급 요청 데이터
+#[derive(Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+// [Response] 응답 데이터 (로그인, 토큰 재발급)
 #[derive(Serialize, Debug)]
 pub struct LoginResponse {
-    pub token: String,
+    pub token: String,         // Access Token (JWT)
+    pub refresh_token: String, // 한 번만 사용 가능 (재발급 시 새 토큰으로 교체)
+    pub expires_in: usize,     // Access Token 유효 시간 (초)
     pub user_id: Uuid,
     pub username: String,
+    pub role: Role,
+}
+
+// [Request] 역할 변경 요청 데이터 (관리자 전용)
+#[derive(Deserialize)]
+pub struct UpdateRoleRequest {
+    pub role: Role,
 }
\ No newline at end of file
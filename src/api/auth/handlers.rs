@@ -1,35 +1,97 @@
-use axum::{extract::State, Json, http::StatusCode};
-use sqlx::PgPool;
-use super::{dtos::{SignupRequest, LoginRequest}, service, repository::PgUserRepository};
+use std::sync::Arc;
+use axum::{extract::{Path, State}, http::StatusCode};
+use uuid::Uuid;
+use super::{
+    dtos::{SignupRequest, LoginRequest, RefreshRequest, UpdateRoleRequest},
+    jwt::Claims,
+    role::{Admin, RequireRole},
+    service,
+};
+use crate::api::config::Config;
 use crate::api::error::AppError;
+use crate::api::extract::Json;
+use crate::api::state::{Keys, Users};
 
 /// 회원가입 핸들러
 ///
-/// 흐름: HTTP Request → Repository 생성 → Service 호출 → HTTP Response
+/// 흐름: HTTP Request → Service 호출 (State의 Repository 사용) → HTTP Response
+/// - 회원가입 기능이 꺼져 있으면(`features.signup = false`) 403 Forbidden
 pub async fn signup_handler(
-    State(pool): State<PgPool>,
+    State(repo): State<Users>,
+    State(config): State<Arc<Config>>,
     Json(payload): Json<SignupRequest>,
 ) -> Result<(StatusCode, Json<super::dtos::UserResponse>), AppError> {
-    // 1. Repository 생성 (실제 DB)
-    let repo = PgUserRepository::new(&pool);
+    if !config.features.signup {
+        return Err(AppError::Forbidden("Sign-up is disabled".to_string()));
+    }
 
-    // 2. Service 호출 (에러는 이미 AppError로 반환됨)
-    let user_res = service::sign_up(&repo, payload.username).await?;
+    // Service 호출 (에러는 이미 AppError로 반환됨)
+    let user_res = service::sign_up(repo.as_ref(), payload.username, payload.password).await?;
 
-    // 3. 성공 응답
+    // 성공 응답
     Ok((StatusCode::CREATED, Json(user_res)))
 }
 
 /// 로그인 핸들러
 ///
-/// 흐름: HTTP Request (username) → Service 호출 (find) → 성공 시 사용자 정보 반환
+/// 흐름: HTTP Request (username, password) → Service 호출 (find + 비밀번호 검증) → 성공 시 토큰 반환
 /// - 성공: 200 OK + UserResponse
 /// - 실패: 401 Unauthorized (Service에서 에러 발생)
 pub async fn login_handler(
-    State(pool): State<PgPool>,
+    State(repo): State<Users>,
+    State(keys): State<Keys>,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Json<super::dtos::LoginResponse>, AppError> { // Updated return type
-    let repo = PgUserRepository::new(&pool);
-    let user_res = service::login(&repo, payload.username).await?;
+    let user_res = service::login(repo.as_ref(), &keys, payload.username, payload.password).await?;
     Ok(Json(user_res))
 }
+
+/// Access Token 재발급 핸들러 (POST /auth/refresh)
+/// - 성공: 200 OK + 새 Access Token / Refresh Token (기존 Refresh Token은 더 이상 사용 불가)
+/// - 실패: 401 Unauthorized
+pub async fn refresh_handler(
+    State(repo): State<Users>,
+    State(keys): State<Keys>,
+    Json(payload): Json<RefreshRequest>,
+) -> Result<Json<super::dtos::LoginResponse>, AppError> {
+    let response = service::refresh(repo.as_ref(), &keys, payload.refresh_token).await?;
+    Ok(Json(response))
+}
+
+/// 로그아웃 핸들러 (POST /auth/logout)
+/// - 성공: 204 No Content (현재 세션의 Access Token / Refresh Token 모두 무효)
+pub async fn logout_handler(
+    State(repo): State<Users>,
+    claims: Claims,
+) -> Result<StatusCode, AppError> {
+    service::logout(repo.as_ref(), &claims).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 사용자 삭제 핸들러 (DELETE /auth/users/:id, 관리자 전용)
+/// - 성공: 204 No Content (투표/안건 기록은 남고 계정과 세션만 비활성화)
+pub async fn delete_user_handler(
+    State(repo): State<Users>,
+    admin: RequireRole<Admin>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    service::delete_user(repo.as_ref(), &admin, parse_user_id(&id)?).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 역할 변경 핸들러 (PUT /auth/users/:id/role, 관리자 전용)
+/// - 성공: 204 No Content (대상 사용자는 다시 로그인해야 새 역할 적용)
+pub async fn update_role_handler(
+    State(repo): State<Users>,
+    admin: RequireRole<Admin>,
+    Path(id): Path<String>,
+    Json(payload): Json<UpdateRoleRequest>,
+) -> Result<StatusCode, AppError> {
+    service::update_role(repo.as_ref(), &admin, parse_user_id(&id)?, payload.role).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 경로의 사용자 ID 파싱 (UUID 형식이 아니면 400)
+fn parse_user_id(id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(id).map_err(|_| AppError::BadRequest("Invalid user id".to_string(), vec![]))
+}
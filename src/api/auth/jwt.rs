@@ -1,82 +1,95 @@
-use jsonwebtoken::{encode, EncodingKey, Header};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use crate::api::error::AppError;
 use std::time::{SystemTime, UNIX_EPOCH};
+use super::{keys::KeyStore, role::Role};
 
-const DEFAULT_SECRET: &str = "secret";
+/// Access Token 유효 시간 (초) - 만료 후에는 Refresh Token으로 재발급
+pub const ACCESS_TOKEN_TTL_SECS: usize = 15 * 60;
 
 /// JWT Payload 구조체
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,    // User ID
     pub username: String,
+    #[serde(default)]
+    pub role: Role,     // 발급 시점의 역할 (역할 클레임이 없는 이전 토큰은 user)
+    pub sid: String,    // Session ID (로그아웃/재사용 감지로 폐기되면 토큰 거부)
     pub exp: usize,     // Expiration Time
     pub iat: usize,     // Issued At
 }
 
+impl Claims {
+    /// `sub`에 담긴 user_id를 Uuid로 파싱
+    pub fn user_id(&self) -> Result<Uuid, AppError> {
+        Uuid::parse_str(&self.sub)
+            .map_err(|_| AppError::Unauthorized("Invalid token user_id".to_string()))
+    }
+
+    /// `sid`에 담긴 session_id를 Uuid로 파싱
+    pub fn session_id(&self) -> Result<Uuid, AppError> {
+        Uuid::parse_str(&self.sid)
+            .map_err(|_| AppError::Unauthorized("Invalid token session".to_string()))
+    }
+}
+
 /// JWT 토큰 생성
 ///
 /// - `sub`: user_id
-/// - `exp`: 현재시간 + 24시간
-pub fn generate_token(user_id: Uuid, username: String) -> Result<String, AppError> {
+/// - `role`: 사용자 역할
+/// - `sid`: 로그인 세션 ID
+/// - `exp`: 현재시간 + 15분
+pub fn generate_token(
+    keys: &KeyStore,
+    user_id: Uuid,
+    username: String,
+    role: Role,
+    session_id: Uuid,
+) -> Result<String, AppError> {
     let expiration = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
-        .as_secs() as usize + 24 * 3600; // 24 hours
+        .as_secs() as usize + ACCESS_TOKEN_TTL_SECS;
 
     let claims = Claims {
         sub: user_id.to_string(),
         username,
+        role,
+        sid: session_id.to_string(),
         exp: expiration,
         iat: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as usize,
     };
 
-    // 환경 변수에서 SECRET 가져오기 (없으면 기본값 사용 - 개발용)
-    // 실제 운영 배포 시에는 반드시 JWT_SECRET을 설정해야 함!
-    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| DEFAULT_SECRET.to_string());
-
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(secret.as_bytes()),
-    )
-    .map_err(|_| AppError::InternalServerError("Token generation failed".to_string()))
+    // 활성 키로 서명 (헤더에 kid 포함)
+    keys.sign(&claims)
 }
 
-/// JWT 토큰 검증
-pub fn verify_token(token: &str) -> Result<Claims, AppError> {
-    let secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| DEFAULT_SECRET.to_string());
-    
-    let validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS256);
-    
-    let token_data = jsonwebtoken::decode::<Claims>(
-        token,
-        &jsonwebtoken::DecodingKey::from_secret(secret.as_bytes()),
-        &validation,
-    )
-    .map_err(|_| AppError::Unauthorized("Invalid token".to_string()))?;
-
-    Ok(token_data.claims)
+/// JWT 토큰 검증 (헤더의 kid로 키 선택)
+pub fn verify_token(keys: &KeyStore, token: &str) -> Result<Claims, AppError> {
+    keys.verify(token)
 }
 
 use axum::{
     async_trait,
-    extract::FromRequestParts,
+    extract::{FromRef, FromRequestParts},
     http::request::Parts,
 };
+use crate::api::state::{Keys, Users};
 
 /// Axum Extractor for Claims
 ///
 /// 핸들러에서 `claims: Claims` 형태로 사용하면 자동으로 헤더에서 토큰을 추출하고 검증합니다.
+/// State의 키로 서명/만료 검증 후 세션이 폐기되지 않았는지 사용자 Repository에서 확인합니다.
 #[async_trait]
 impl<S> FromRequestParts<S> for Claims
 where
+    Users: FromRef<S>,
+    Keys: FromRef<S>,
     S: Send + Sync,
 {
     type Rejection = AppError;
 
-    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
         // 1. Authorization 헤더 가져오기
         let auth_header = parts
             .headers
@@ -95,6 +108,10 @@ where
         let token = &auth_str[7..];
 
         // 3. 토큰 검증
-        verify_token(token)
+        let claims = verify_token(&Keys::from_ref(state), token)?;
+
+        // 4. 세션 유효성 확인
+        let users = Users::from_ref(state);
+        super::service::authorize(users.as_ref(), claims).await
     }
 }
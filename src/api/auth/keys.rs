@@ -0,0 +1,238 @@
+//! JWT 서명 키 관리
+//!
+//! 서버 시작 시 한 번 키를 읽어 `KeyStore`로 만들고, `AppState`에 넣어 토큰 발급/검증에 씁니다.
+//! - 서명은 활성 키 하나로, 검증은 토큰 헤더의 `kid`로 키를 찾아서 수행합니다.
+//!   새 키로 교체해도 이전 키를 검증용으로 남겨 두면 이미 발급된 토큰이 계속 통과합니다.
+//! - HS256(공유 비밀), RS256 / EdDSA(개인키 서명, 공개키 검증)를 지원합니다.
+//!   공개키 알고리즘을 쓰면 다른 서비스는 공개키만으로 토큰을 검증할 수 있습니다.
+//! - 운영 모드(`APP_ENV=production`)에서는 키가 없거나 비밀이 약하면 시작을 거부합니다.
+//!
+//! 설정 (`Config::jwt`, 환경 변수로도 지정 가능)
+//! - `JWT_KEYS`: `kid:ALG:서명키 파일[:검증키 파일]`을 `;`로 구분
+//!   - HS256: 비밀 파일 하나 (`k1:HS256:/run/secrets/jwt`)
+//!   - RS256 / EdDSA: 개인키 PEM + 공개키 PEM, 개인키 자리에 `-`를 쓰면 검증 전용 (`old:EdDSA:-:old.pub.pem`)
+//! - `JWT_ACTIVE_KID`: 서명에 쓸 키 (기본: `JWT_KEYS`의 첫 번째 키)
+//! - `JWT_SECRET`: `JWT_KEYS`가 없을 때 쓰는 단일 HS256 비밀 (kid `default`)
+
+use std::{collections::HashMap, fmt, fs, str::FromStr};
+use jsonwebtoken::{decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{de::DeserializeOwned, Serialize};
+use crate::api::{config::JwtConfig, error::AppError};
+
+/// 개발 모드에서 키 설정이 없을 때 쓰는 비밀 (운영 모드에서는 거부)
+const DEFAULT_SECRET: &str = "secret";
+
+/// 운영 모드의 HS256 비밀 최소 길이 (바이트)
+const MIN_SECRET_LEN: usize = 32;
+
+/// 키 설정 오류 (서버 시작 중단)
+#[derive(Debug)]
+pub struct KeyError(String);
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JWT key configuration: {}", self.0)
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+/// 키 하나의 설정 (파일에서 읽은 키 내용)
+pub struct KeySpec {
+    pub kid: String,
+    pub algorithm: Algorithm,
+    pub signing: Option<Vec<u8>>, // HS256 비밀 또는 개인키 PEM (None: 검증 전용)
+    pub verifying: Vec<u8>,       // HS256 비밀 또는 공개키 PEM
+}
+
+/// 키 저장소 설정
+pub struct KeyConfig {
+    pub production: bool,
+    pub keys: Vec<KeySpec>,
+    pub active_kid: Option<String>,
+}
+
+impl KeyConfig {
+    /// 애플리케이션 설정에서 만들기 (키 파일도 이때 읽음)
+    pub fn from_config(config: &JwtConfig, production: bool) -> Result<Self, KeyError> {
+        let keys = match (&config.keys, &config.secret) {
+            (Some(list), _) => list
+                .split(';')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(parse_entry)
+                .collect::<Result<Vec<_>, _>>()?,
+            (None, Some(secret)) => vec![KeySpec {
+                kid: "default".to_string(),
+                algorithm: Algorithm::HS256,
+                signing: Some(secret.clone().into_bytes()),
+                verifying: secret.clone().into_bytes(),
+            }],
+            (None, None) => vec![],
+        };
+
+        Ok(Self {
+            production,
+            keys,
+            active_kid: config.active_kid.clone(),
+        })
+    }
+}
+
+/// `kid:ALG:서명키 파일[:검증키 파일]`
+fn parse_entry(entry: &str) -> Result<KeySpec, KeyError> {
+    let parts: Vec<&str> = entry.split(':').collect();
+    let (kid, alg, signing_path, verifying_path) = match parts.as_slice() {
+        [kid, alg, path] => (*kid, *alg, *path, None),
+        [kid, alg, signing, verifying] => (*kid, *alg, *signing, Some(*verifying)),
+        _ => return Err(KeyError(format!("invalid JWT_KEYS entry `{}`", entry))),
+    };
+
+    let algorithm = Algorithm::from_str(alg)
+        .ok()
+        .filter(|a| matches!(a, Algorithm::HS256 | Algorithm::RS256 | Algorithm::EdDSA))
+        .ok_or_else(|| KeyError(format!("key `{}`: unsupported algorithm `{}`", kid, alg)))?;
+
+    let read = |path: &str| {
+        fs::read(path).map_err(|e| KeyError(format!("key `{}`: cannot read {}: {}", kid, path, e)))
+    };
+
+    let (signing, verifying) = if algorithm == Algorithm::HS256 {
+        let secret = String::from_utf8_lossy(&read(signing_path)?).trim().as_bytes().to_vec();
+        (Some(secret.clone()), secret)
+    } else {
+        let verifying_path = verifying_path
+            .ok_or_else(|| KeyError(format!("key `{}`: {} needs a public key file", kid, alg)))?;
+        let signing = (signing_path != "-").then(|| read(signing_path)).transpose()?;
+        (signing, read(verifying_path)?)
+    };
+
+    Ok(KeySpec {
+        kid: kid.to_string(),
+        algorithm,
+        signing,
+        verifying,
+    })
+}
+
+struct Key {
+    algorithm: Algorithm,
+    encoding: Option<EncodingKey>,
+    decoding: DecodingKey,
+}
+
+/// 서명/검증 키 모음
+pub struct KeyStore {
+    active: String,
+    keys: HashMap<String, Key>,
+}
+
+impl KeyStore {
+    /// 설정 검증 후 키 파싱
+    /// - 운영 모드: 키 필수, 기본 비밀 금지, HS256 비밀은 32바이트 이상
+    /// - 활성 키는 서명 키(개인키/비밀)가 있어야 함
+    pub fn load(config: KeyConfig) -> Result<Self, KeyError> {
+        if config.keys.is_empty() {
+            if config.production {
+                return Err(KeyError("no signing key configured (set JWT_KEYS or JWT_SECRET)".to_string()));
+            }
+            println!("⚠️ No JWT key configured, using the development secret");
+            return Ok(Self::development());
+        }
+
+        let active = config
+            .active_kid
+            .unwrap_or_else(|| config.keys[0].kid.clone());
+
+        let mut keys = HashMap::new();
+        for spec in config.keys {
+            let weak = spec.verifying == DEFAULT_SECRET.as_bytes() || spec.verifying.len() < MIN_SECRET_LEN;
+            if config.production && spec.algorithm == Algorithm::HS256 && weak {
+                return Err(KeyError(format!(
+                    "key `{}`: HS256 secret must be at least {} bytes in production",
+                    spec.kid, MIN_SECRET_LEN
+                )));
+            }
+
+            let key = parse_key(&spec)?;
+            if keys.insert(spec.kid.clone(), key).is_some() {
+                return Err(KeyError(format!("duplicate kid `{}`", spec.kid)));
+            }
+        }
+
+        match keys.get(&active) {
+            Some(key) if key.encoding.is_some() => Ok(Self { active, keys }),
+            Some(_) => Err(KeyError(format!("active key `{}` has no signing key", active))),
+            None => Err(KeyError(format!("active key `{}` is not configured", active))),
+        }
+    }
+
+    /// 개발용 기본 키 (HS256, kid `dev`)
+    pub fn development() -> Self {
+        let key = Key {
+            algorithm: Algorithm::HS256,
+            encoding: Some(EncodingKey::from_secret(DEFAULT_SECRET.as_bytes())),
+            decoding: DecodingKey::from_secret(DEFAULT_SECRET.as_bytes()),
+        };
+        Self {
+            active: "dev".to_string(),
+            keys: HashMap::from([("dev".to_string(), key)]),
+        }
+    }
+
+    /// 활성 키로 서명 (헤더에 `kid` 포함)
+    pub fn sign<T: Serialize>(&self, claims: &T) -> Result<String, AppError> {
+        let key = &self.keys[&self.active];
+        let encoding = key
+            .encoding
+            .as_ref()
+            .ok_or_else(|| AppError::InternalServerError("Token generation failed".to_string()))?;
+
+        let mut header = Header::new(key.algorithm);
+        header.kid = Some(self.active.clone());
+
+        encode(&header, claims, encoding)
+            .map_err(|_| AppError::InternalServerError("Token generation failed".to_string()))
+    }
+
+    /// 헤더의 `kid`로 키를 찾아 서명/만료 검증 (키의 알고리즘만 허용)
+    pub fn verify<T: DeserializeOwned>(&self, token: &str) -> Result<T, AppError> {
+        let invalid = || AppError::Unauthorized("Invalid token".to_string());
+
+        let header = decode_header(token).map_err(|_| invalid())?;
+        let key = header
+            .kid
+            .as_deref()
+            .and_then(|kid| self.keys.get(kid))
+            .ok_or_else(invalid)?;
+
+        decode::<T>(token, &key.decoding, &Validation::new(key.algorithm))
+            .map(|data| data.claims)
+            .map_err(|_| invalid())
+    }
+}
+
+fn parse_key(spec: &KeySpec) -> Result<Key, KeyError> {
+    let invalid = |e: jsonwebtoken::errors::Error| KeyError(format!("key `{}`: {}", spec.kid, e));
+
+    let (encoding, decoding) = match spec.algorithm {
+        Algorithm::HS256 => (
+            spec.signing.as_deref().map(EncodingKey::from_secret),
+            DecodingKey::from_secret(&spec.verifying),
+        ),
+        Algorithm::RS256 => (
+            spec.signing.as_deref().map(EncodingKey::from_rsa_pem).transpose().map_err(invalid)?,
+            DecodingKey::from_rsa_pem(&spec.verifying).map_err(invalid)?,
+        ),
+        _ => (
+            spec.signing.as_deref().map(EncodingKey::from_ed_pem).transpose().map_err(invalid)?,
+            DecodingKey::from_ed_pem(&spec.verifying).map_err(invalid)?,
+        ),
+    };
+
+    Ok(Key {
+        algorithm: spec.algorithm,
+        encoding,
+        decoding,
+    })
+}
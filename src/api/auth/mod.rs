@@ -1,5 +1,5 @@
-use axum::{routing::post, Router};
-use sqlx::PgPool;
+use axum::{routing::{delete, post, put}, Router};
+use crate::api::state::AppState;
 
 // Auth 모듈 구조
 // - dtos: Request/Response 데이터
@@ -10,16 +10,23 @@ use sqlx::PgPool;
 
 mod dtos;
 pub mod jwt; // Added
-mod repository;
+pub mod keys;
+pub mod role;
+pub mod repository;
+mod password;
+mod refresh_token;
 pub mod service;  // pub으로 변경 (테스트에서 접근 가능하도록)
 mod handlers;
 
 #[cfg(test)]
-mod test;
+pub mod test;
 
-pub fn router() -> Router<PgPool> {
+pub fn router() -> Router<AppState> {
     Router::new()
         .route("/signup", post(handlers::signup_handler))
         .route("/login", post(handlers::login_handler))
-        // .route("/login", ...)
+        .route("/refresh", post(handlers::refresh_handler))
+        .route("/logout", post(handlers::logout_handler))
+        .route("/users/:id", delete(handlers::delete_user_handler))
+        .route("/users/:id/role", put(handlers::update_role_handler))
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+//! 비밀번호 해싱 (Argon2id)
+//!
+//! - 해시는 솔트와 파라미터를 포함한 PHC 문자열로 저장하므로 파라미터를 바꿔도 기존 해시를 검증할 수 있습니다.
+//! - 해싱/검증은 의도적으로 느린 CPU 작업이라 `spawn_blocking`에서 실행합니다.
+//! - 검증은 상수 시간 비교이며, 사용자가 없을 때도 같은 비용의 검증을 수행해
+//!   응답 시간으로 username 존재 여부가 드러나지 않게 합니다.
+
+use std::sync::OnceLock;
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use crate::api::error::AppError;
+
+/// 비밀번호 최소 길이
+pub const MIN_PASSWORD_LEN: usize = 8;
+
+/// 비밀번호 최대 길이 (문자 수)
+pub const MAX_PASSWORD_LEN: usize = 128;
+
+/// 비밀번호 해싱 (Argon2id, 기본 파라미터 m=19MiB, t=2, p=1)
+pub async fn hash(password: String) -> Result<String, AppError> {
+    tokio::task::spawn_blocking(move || hash_blocking(&password))
+        .await
+        .map_err(|_| AppError::InternalServerError("Password hashing failed".to_string()))?
+}
+
+/// 비밀번호 검증 - 저장된 해시가 없으면(사용자 없음 등) 더미 해시로 검증한 뒤 `false`
+pub async fn verify(password: String, password_hash: Option<String>) -> bool {
+    tokio::task::spawn_blocking(move || match password_hash {
+        Some(stored) => verify_blocking(&password, &stored),
+        None => {
+            verify_blocking(&password, dummy_hash());
+            false
+        }
+    })
+    .await
+    .unwrap_or(false)
+}
+
+pub fn hash_blocking(password: &str) -> Result<String, AppError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|_| AppError::InternalServerError("Password hashing failed".to_string()))
+}
+
+fn verify_blocking(password: &str, stored: &str) -> bool {
+    PasswordHash::new(stored)
+        .map(|parsed| Argon2::default().verify_password(password.as_bytes(), &parsed).is_ok())
+        .unwrap_or(false)
+}
+
+/// 존재하지 않는 사용자의 로그인 시도에 쓰는 해시 (프로세스당 한 번 생성)
+fn dummy_hash() -> &'static str {
+    static DUMMY: OnceLock<String> = OnceLock::new();
+    DUMMY.get_or_init(|| hash_blocking("dummy-password").unwrap_or_default())
+}
@@ -0,0 +1,28 @@
+//! Refresh Token 생성/해싱
+//!
+//! Refresh Token은 256비트 난수라 추측이 불가능하므로 느린 해시(Argon2) 대신 SHA-256으로 충분합니다.
+//! DB에는 해시만 저장하므로 DB가 유출되어도 토큰을 재사용할 수 없습니다.
+
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+/// 새 Refresh Token (원문은 클라이언트에게만 전달)
+pub struct NewRefreshToken {
+    pub token: String,
+    pub hash: Vec<u8>,
+}
+
+pub fn generate() -> NewRefreshToken {
+    let mut bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    let token: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+
+    NewRefreshToken {
+        hash: hash(&token),
+        token,
+    }
+}
+
+pub fn hash(token: &str) -> Vec<u8> {
+    Sha256::digest(token.as_bytes()).to_vec()
+}
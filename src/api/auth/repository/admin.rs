@@ -0,0 +1,63 @@
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use crate::api::auth::role::Role;
+
+/// 사용자 삭제 (단일 트랜잭션)
+///
+/// 투표/안건은 집계에 남아야 하므로 행을 지우지 않고 계정만 비활성화합니다.
+/// - username을 `deleted-<id>`로 바꿔 같은 이름으로 다시 가입할 수 있게 함
+/// - 비밀번호를 지워 로그인 불가, 모든 세션 폐기
+pub async fn delete_user(pool: &PgPool, user_id: Uuid) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query!(
+        "UPDATE users
+         SET deleted_at = NOW(), username = 'deleted-' || id::text, password_hash = NULL
+         WHERE id = $1 AND deleted_at IS NULL",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    revoke_all_sessions(&mut tx, user_id).await?;
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+/// 역할 변경 + 세션 폐기 (이전 역할이 담긴 Access Token이 더 이상 통과하지 않도록)
+pub async fn update_role(pool: &PgPool, user_id: Uuid, role: Role) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query!(
+        "UPDATE users SET role = $2 WHERE id = $1 AND deleted_at IS NULL",
+        user_id,
+        role as Role
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    revoke_all_sessions(&mut tx, user_id).await?;
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+async fn revoke_all_sessions(conn: &mut PgConnection, user_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE sessions SET revoked_at = NOW() WHERE user_id = $1 AND revoked_at IS NULL",
+        user_id
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use sqlx::PgPool;
+use uuid::Uuid;
 use super::UserEntity;
+use crate::api::auth::role::Role;
 
 /// 사용자 조회 (로그인용)
 ///
@@ -9,9 +11,25 @@ use super::UserEntity;
 pub async fn find_by_username(pool: &PgPool, username: &str) -> Result<Option<UserEntity>, sqlx::Error> {
     sqlx::query_as!(
         UserEntity,
-        "SELECT id, username FROM users WHERE username = $1",
+        r#"SELECT id, username, password_hash, role as "role: Role", legacy_password
+           FROM users WHERE username = $1 AND deleted_at IS NULL"#,
         username
     )
     .fetch_optional(pool)
     .await
 }
+
+/// 정규화 도입 전 비밀번호 해시 교체
+///
+/// 입력 그대로 확인한 비밀번호를 정규화해 다시 해시한 값으로 바꾸고, 이후로는 정규화한 값으로만 확인합니다.
+pub async fn rehash_password(pool: &PgPool, user_id: Uuid, password_hash: &str) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE users SET password_hash = $2, legacy_password = FALSE WHERE id = $1",
+        user_id,
+        password_hash
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
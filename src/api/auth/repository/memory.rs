@@ -0,0 +1,197 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::error::ErrorKind;
+use uuid::Uuid;
+use super::{traits::UserRepository, RefreshOutcome, SessionEntity, UserEntity};
+use crate::api::auth::role::Role;
+use crate::api::memory::{violation, MemoryDb, RefreshTokenRow, SessionRow, Tables, UserRow};
+
+/// 메모리 Repository 구현체 (PostgreSQL 구현과 같은 제약/동작)
+pub struct MemoryUserRepository {
+    db: Arc<MemoryDb>,
+}
+
+impl MemoryUserRepository {
+    pub fn new(db: &Arc<MemoryDb>) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+#[async_trait]
+impl UserRepository for MemoryUserRepository {
+    async fn exists(&self, username: &str) -> Result<bool, sqlx::Error> {
+        Ok(self.db.lock().users.iter().any(|u| u.username == username))
+    }
+
+    async fn find_by_username(&self, username: &str) -> Result<Option<UserEntity>, sqlx::Error> {
+        Ok(self
+            .db
+            .lock()
+            .users
+            .iter()
+            .find(|u| u.username == username && u.deleted_at.is_none())
+            .map(|u| UserEntity {
+                id: u.id,
+                username: u.username.clone(),
+                password_hash: u.password_hash.clone(),
+                role: u.role,
+                legacy_password: false, // 메모리 저장소는 항상 정규화 이후에 가입한 계정
+            }))
+    }
+
+    async fn rehash_password(&self, user_id: Uuid, password_hash: &str) -> Result<(), sqlx::Error> {
+        if let Some(user) = self.db.lock().users.iter_mut().find(|u| u.id == user_id) {
+            user.password_hash = Some(password_hash.to_string());
+        }
+        Ok(())
+    }
+
+    /// username unique 제약 (`users_username_key`)
+    async fn save(&self, username: &str, password_hash: &str) -> Result<Uuid, sqlx::Error> {
+        let mut tables = self.db.lock();
+        if tables.users.iter().any(|u| u.username == username) {
+            return Err(violation(ErrorKind::UniqueViolation, "users_username_key"));
+        }
+
+        let id = Uuid::new_v4();
+        tables.users.push(UserRow {
+            id,
+            username: username.to_string(),
+            password_hash: Some(password_hash.to_string()),
+            role: Role::default(),
+            deleted_at: None,
+        });
+        Ok(id)
+    }
+
+    async fn create_session(&self, user_id: Uuid, token_hash: &[u8], expires_at: DateTime<Utc>) -> Result<Uuid, sqlx::Error> {
+        let mut tables = self.db.lock();
+        if !tables.user_exists(user_id) {
+            return Err(violation(ErrorKind::ForeignKeyViolation, "sessions_user_id_fkey"));
+        }
+        check_token_unique(&tables, token_hash)?;
+
+        let session_id = Uuid::new_v4();
+        tables.sessions.push(SessionRow {
+            id: session_id,
+            user_id,
+            revoked_at: None,
+        });
+        tables.refresh_tokens.push(RefreshTokenRow {
+            session_id,
+            token_hash: token_hash.to_vec(),
+            expires_at,
+            used_at: None,
+        });
+        Ok(session_id)
+    }
+
+    /// `session::rotate_refresh_token`과 같은 순서로 판정 (폐기 → 재사용 → 만료 → 교체)
+    async fn rotate_refresh_token(&self, token_hash: &[u8], new_token_hash: &[u8], new_expires_at: DateTime<Utc>) -> Result<RefreshOutcome, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let now = Utc::now();
+
+        let Some(token) = tables.refresh_tokens.iter().position(|t| t.token_hash == token_hash) else {
+            return Ok(RefreshOutcome::Invalid);
+        };
+        let session_id = tables.refresh_tokens[token].session_id;
+        let Some(session) = tables.sessions.iter().position(|s| s.id == session_id) else {
+            return Ok(RefreshOutcome::Invalid);
+        };
+
+        if tables.sessions[session].revoked_at.is_some() {
+            return Ok(RefreshOutcome::Invalid);
+        }
+
+        if tables.refresh_tokens[token].used_at.is_some() {
+            tables.sessions[session].revoked_at = Some(now);
+            return Ok(RefreshOutcome::Reused { session_id });
+        }
+
+        if tables.refresh_tokens[token].expires_at <= now {
+            return Ok(RefreshOutcome::Invalid);
+        }
+
+        check_token_unique(&tables, new_token_hash)?;
+        let user_id = tables.sessions[session].user_id;
+        let Some(user) = tables.users.iter().find(|u| u.id == user_id) else {
+            return Ok(RefreshOutcome::Invalid);
+        };
+        let rotated = SessionEntity {
+            session_id,
+            user_id,
+            username: user.username.clone(),
+            role: user.role,
+        };
+
+        tables.refresh_tokens[token].used_at = Some(now);
+        tables.refresh_tokens.push(RefreshTokenRow {
+            session_id,
+            token_hash: new_token_hash.to_vec(),
+            expires_at: new_expires_at,
+            used_at: None,
+        });
+        Ok(RefreshOutcome::Rotated(rotated))
+    }
+
+    async fn revoke_session(&self, session_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut tables = self.db.lock();
+        match tables.sessions.iter_mut().find(|s| s.id == session_id && s.revoked_at.is_none()) {
+            Some(session) => {
+                session.revoked_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn is_session_active(&self, session_id: Uuid) -> Result<bool, sqlx::Error> {
+        Ok(self
+            .db
+            .lock()
+            .sessions
+            .iter()
+            .any(|s| s.id == session_id && s.revoked_at.is_none()))
+    }
+
+    /// `admin::delete_user`와 같은 소프트 삭제 (username 해제, 비밀번호 삭제, 세션 폐기)
+    async fn delete_user(&self, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let Some(user) = tables.users.iter_mut().find(|u| u.id == user_id && u.deleted_at.is_none()) else {
+            return Ok(false);
+        };
+
+        user.deleted_at = Some(Utc::now());
+        user.username = format!("deleted-{}", user_id);
+        user.password_hash = None;
+        revoke_all_sessions(&mut tables, user_id);
+        Ok(true)
+    }
+
+    async fn update_role(&self, user_id: Uuid, role: Role) -> Result<bool, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let Some(user) = tables.users.iter_mut().find(|u| u.id == user_id && u.deleted_at.is_none()) else {
+            return Ok(false);
+        };
+
+        user.role = role;
+        revoke_all_sessions(&mut tables, user_id);
+        Ok(true)
+    }
+}
+
+/// refresh_tokens.token_hash unique 제약 (`uk_refresh_token_hash`)
+fn check_token_unique(tables: &Tables, token_hash: &[u8]) -> Result<(), sqlx::Error> {
+    if tables.refresh_tokens.iter().any(|t| t.token_hash == token_hash) {
+        return Err(violation(ErrorKind::UniqueViolation, "uk_refresh_token_hash"));
+    }
+    Ok(())
+}
+
+fn revoke_all_sessions(tables: &mut Tables, user_id: Uuid) {
+    let now = Utc::now();
+    for session in tables.sessions.iter_mut().filter(|s| s.user_id == user_id && s.revoked_at.is_none()) {
+        session.revoked_at = Some(now);
+    }
+}
@@ -1,41 +1,94 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 use self::traits::UserRepository;
+use super::role::Role;
 
 pub mod traits;
 pub mod signup;
 pub mod login; // New module
+pub mod session;
+pub mod admin;
+pub mod memory; // PostgreSQL 없이 실행할 때의 메모리 구현체
 
 #[derive(sqlx::FromRow, Debug, Clone)] // Added Debug, Clone
 pub struct UserEntity {
     pub id: Uuid,
     pub username: String,
+    pub password_hash: Option<String>, // Argon2id PHC 문자열 (None: 비밀번호 없는 기존 계정)
+    pub role: Role,
+    pub legacy_password: bool, // 입력 정규화 도입 전의 비밀번호 해시 (다음 로그인에 다시 해시)
+}
+
+/// Refresh Token으로 확인한 로그인 세션
+#[derive(Debug, Clone)]
+pub struct SessionEntity {
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    pub username: String,
+    pub role: Role,
+}
+
+/// Refresh Token 교체 결과
+#[derive(Debug, Clone)]
+pub enum RefreshOutcome {
+    Rotated(SessionEntity),        // 새 토큰 저장 완료
+    Reused { session_id: Uuid },   // 이미 사용된 토큰 재제출 → 세션 폐기됨
+    Invalid,                       // 없는 토큰, 만료, 폐기된 세션
 }
 
 /// PostgreSQL Repository 구현체
 /// Handler에서 실제 DB 작업에 사용
-pub struct PgUserRepository<'a> {
-    pool: &'a PgPool,
+pub struct PgUserRepository {
+    pool: PgPool,
 }
 
-impl<'a> PgUserRepository<'a> {
-    pub fn new(pool: &'a PgPool) -> Self {
-        Self { pool }
+impl PgUserRepository {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
     }
 }
 
 #[async_trait]
-impl<'a> UserRepository for PgUserRepository<'a> {
+impl UserRepository for PgUserRepository {
     async fn exists(&self, username: &str) -> Result<bool, sqlx::Error> {
-        signup::exist_by_username(self.pool, username).await
+        signup::exist_by_username(&self.pool, username).await
     }
 
     async fn find_by_username(&self, username: &str) -> Result<Option<UserEntity>, sqlx::Error> {
-        login::find_by_username(self.pool, username).await
+        login::find_by_username(&self.pool, username).await
+    }
+
+    async fn rehash_password(&self, user_id: Uuid, password_hash: &str) -> Result<(), sqlx::Error> {
+        login::rehash_password(&self.pool, user_id, password_hash).await
+    }
+
+    async fn save(&self, username: &str, password_hash: &str) -> Result<Uuid, sqlx::Error> {
+        signup::save_user(&self.pool, username, password_hash).await
+    }
+
+    async fn create_session(&self, user_id: Uuid, token_hash: &[u8], expires_at: DateTime<Utc>) -> Result<Uuid, sqlx::Error> {
+        session::create_session(&self.pool, user_id, token_hash, expires_at).await
+    }
+
+    async fn rotate_refresh_token(&self, token_hash: &[u8], new_token_hash: &[u8], new_expires_at: DateTime<Utc>) -> Result<RefreshOutcome, sqlx::Error> {
+        session::rotate_refresh_token(&self.pool, token_hash, new_token_hash, new_expires_at).await
+    }
+
+    async fn revoke_session(&self, session_id: Uuid) -> Result<bool, sqlx::Error> {
+        session::revoke_session(&self.pool, session_id).await
+    }
+
+    async fn is_session_active(&self, session_id: Uuid) -> Result<bool, sqlx::Error> {
+        session::is_session_active(&self.pool, session_id).await
+    }
+
+    async fn delete_user(&self, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        admin::delete_user(&self.pool, user_id).await
     }
 
-    async fn save(&self, username: &str) -> Result<Uuid, sqlx::Error> {
-        signup::save_user(self.pool, username).await
+    async fn update_role(&self, user_id: Uuid, role: Role) -> Result<bool, sqlx::Error> {
+        admin::update_role(&self.pool, user_id, role).await
     }
 }
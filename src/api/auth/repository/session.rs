@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::{RefreshOutcome, SessionEntity};
+use crate::api::auth::role::Role;
+
+/// 세션 생성 + 첫 Refresh Token 저장 후 세션 ID 반환
+pub async fn create_session(
+    pool: &PgPool,
+    user_id: Uuid,
+    token_hash: &[u8],
+    expires_at: DateTime<Utc>,
+) -> Result<Uuid, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let session_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO sessions (id, user_id, created_at) VALUES ($1, $2, NOW())",
+        session_id,
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO refresh_tokens (id, session_id, token_hash, created_at, expires_at) VALUES ($1, $2, $3, NOW(), $4)",
+        Uuid::new_v4(),
+        session_id,
+        token_hash,
+        expires_at
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(session_id)
+}
+
+/// Refresh Token 교체 (단일 트랜잭션)
+///
+/// 1. 토큰 + 세션 행 잠금 (같은 토큰으로 동시에 요청하면 하나만 교체되고 나머지는 재사용으로 처리)
+/// 2. 이미 사용된 토큰이면 세션 폐기 후 `Reused` (폐기도 커밋)
+/// 3. 없는 토큰, 폐기된 세션, 만료된 토큰이면 `Invalid`
+/// 4. 유효하면 기존 토큰 사용 처리 + 같은 세션에 새 토큰 저장 후 `Rotated`
+pub async fn rotate_refresh_token(
+    pool: &PgPool,
+    token_hash: &[u8],
+    new_token_hash: &[u8],
+    new_expires_at: DateTime<Utc>,
+) -> Result<RefreshOutcome, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let row = sqlx::query!(
+        r#"SELECT t.id, t.session_id, t.expires_at, t.used_at, s.revoked_at, s.user_id, u.username,
+                  u.role as "role: Role"
+           FROM refresh_tokens t
+           JOIN sessions s ON s.id = t.session_id
+           JOIN users u ON u.id = s.user_id
+           WHERE t.token_hash = $1
+           FOR UPDATE OF t, s"#,
+        token_hash
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(RefreshOutcome::Invalid);
+    };
+
+    if row.revoked_at.is_some() {
+        return Ok(RefreshOutcome::Invalid);
+    }
+
+    if row.used_at.is_some() {
+        sqlx::query!("UPDATE sessions SET revoked_at = NOW() WHERE id = $1", row.session_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        return Ok(RefreshOutcome::Reused { session_id: row.session_id });
+    }
+
+    if row.expires_at <= Utc::now() {
+        return Ok(RefreshOutcome::Invalid);
+    }
+
+    sqlx::query!("UPDATE refresh_tokens SET used_at = NOW() WHERE id = $1", row.id)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query!(
+        "INSERT INTO refresh_tokens (id, session_id, token_hash, created_at, expires_at) VALUES ($1, $2, $3, NOW(), $4)",
+        Uuid::new_v4(),
+        row.session_id,
+        new_token_hash,
+        new_expires_at
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(RefreshOutcome::Rotated(SessionEntity {
+        session_id: row.session_id,
+        user_id: row.user_id,
+        username: row.username,
+        role: row.role,
+    }))
+}
+
+/// 세션 폐기 - 이미 폐기된 세션이거나 없으면 `false`
+pub async fn revoke_session(pool: &PgPool, session_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "UPDATE sessions SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL",
+        session_id
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// 세션이 존재하고 폐기되지 않았는지
+pub async fn is_session_active(pool: &PgPool, session_id: Uuid) -> Result<bool, sqlx::Error> {
+    let active = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM sessions WHERE id = $1 AND revoked_at IS NULL) AS "active!""#,
+        session_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(active)
+}
@@ -13,14 +13,15 @@ pub async fn exist_by_username(pool: &PgPool, username: &str) -> Result<bool, sq
     Ok(result.count.unwrap_or(0) > 0)
 }
 
-/// 사용자 생성
-pub async fn save_user(pool: &PgPool, username: &str) -> Result<Uuid, sqlx::Error> {
+/// 사용자 생성 (`password_hash`: 이미 해싱된 비밀번호)
+pub async fn save_user(pool: &PgPool, username: &str, password_hash: &str) -> Result<Uuid, sqlx::Error> {
     let new_id = Uuid::new_v4();
 
     sqlx::query!(
-        "INSERT INTO users (id, username, created_at) VALUES ($1, $2, NOW())",
+        "INSERT INTO users (id, username, password_hash, created_at) VALUES ($1, $2, $3, NOW())",
         new_id,
-        username
+        username,
+        password_hash
     )
     .execute(pool)
     .await?;
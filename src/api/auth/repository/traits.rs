@@ -1,13 +1,15 @@
-/// Repository 추상화 Trait
-///
-/// Service 레이어가 구체적인 DB 구현에 의존하지 않도록 함
-/// - 실제: PgUserRepository (PostgreSQL)
-/// - 테스트: MockUserRepository (가짜 데이터)
+//! Repository 추상화 Trait
+//!
+//! Service 레이어가 구체적인 DB 구현에 의존하지 않도록 함
+//! - 실제: PgUserRepository (PostgreSQL)
+//! - 테스트: MockUserRepository (가짜 데이터)
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
-use super::UserEntity;
+use super::{RefreshOutcome, UserEntity};
+use crate::api::auth::role::Role;
 
 #[async_trait]
 pub trait UserRepository: Send + Sync {
@@ -17,6 +19,27 @@ pub trait UserRepository: Send + Sync {
     /// 사용자 조회 (로그인용)
     async fn find_by_username(&self, username: &str) -> Result<Option<UserEntity>, sqlx::Error>;
 
-    /// 사용자 저장 후 생성된 ID 반환
-    async fn save(&self, username: &str) -> Result<Uuid, sqlx::Error>;
+    /// 정규화 도입 전 비밀번호 해시를 새 해시로 교체 (`legacy_password` 해제)
+    async fn rehash_password(&self, user_id: Uuid, password_hash: &str) -> Result<(), sqlx::Error>;
+
+    /// 사용자 저장 후 생성된 ID 반환 (`password_hash`: Argon2id 해시)
+    async fn save(&self, username: &str, password_hash: &str) -> Result<Uuid, sqlx::Error>;
+
+    /// 로그인 세션 생성 + 첫 Refresh Token(해시) 저장 후 세션 ID 반환
+    async fn create_session(&self, user_id: Uuid, token_hash: &[u8], expires_at: DateTime<Utc>) -> Result<Uuid, sqlx::Error>;
+
+    /// Refresh Token 교체 (사용된 토큰이 다시 오면 세션 폐기)
+    async fn rotate_refresh_token(&self, token_hash: &[u8], new_token_hash: &[u8], new_expires_at: DateTime<Utc>) -> Result<RefreshOutcome, sqlx::Error>;
+
+    /// 세션 폐기 (로그아웃) - 이미 폐기되었거나 없으면 `false`
+    async fn revoke_session(&self, session_id: Uuid) -> Result<bool, sqlx::Error>;
+
+    /// 세션이 유효한지 (Access Token 검증 시 사용)
+    async fn is_session_active(&self, session_id: Uuid) -> Result<bool, sqlx::Error>;
+
+    /// 사용자 삭제 (계정 비활성화 + 세션 폐기) - 없거나 이미 삭제되었으면 `false`
+    async fn delete_user(&self, user_id: Uuid) -> Result<bool, sqlx::Error>;
+
+    /// 역할 변경 (세션 폐기, 다시 로그인하면 새 역할 적용) - 없거나 삭제된 사용자면 `false`
+    async fn update_role(&self, user_id: Uuid, role: Role) -> Result<bool, sqlx::Error>;
 }
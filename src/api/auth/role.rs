@@ -0,0 +1,106 @@
+//! 역할 기반 접근 제어
+//!
+//! 역할은 `users.role`에 저장되고 로그인 시 Access Token의 `role` 클레임에 담깁니다.
+//! - `user` < `moderator` < `admin` 순으로 상위 역할이 하위 역할의 권한을 포함합니다.
+//! - 핸들러는 `RequireRole<Admin>`처럼 필요한 역할을 인자로 선언합니다.
+//!   (토큰이 없거나 잘못되면 401, 역할이 부족하면 403)
+//! - 역할이 바뀌면 해당 사용자의 세션을 폐기하므로 다시 로그인해야 새 역할이 적용됩니다.
+
+use std::{marker::PhantomData, ops::Deref};
+use axum::{
+    async_trait,
+    extract::{FromRef, FromRequestParts},
+    http::request::Parts,
+};
+use serde::{Deserialize, Serialize};
+use crate::api::{error::AppError, state::{Keys, Users}};
+use super::jwt::Claims;
+
+/// 사용자 역할 (PostgreSQL `user_role` ENUM, 선언 순서 = 권한 순서)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "user_role", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    User,
+    Moderator,
+    Admin,
+}
+
+impl Role {
+    /// `required` 역할의 권한을 가지는지 (상위 역할은 하위 역할 포함)
+    pub fn includes(self, required: Role) -> bool {
+        self >= required
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::User => "user",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+/// 토큰의 역할이 `required` 이상인지 확인 (부족하면 403 Forbidden)
+pub fn require(claims: &Claims, required: Role) -> Result<(), AppError> {
+    if claims.role.includes(required) {
+        Ok(())
+    } else {
+        Err(AppError::Forbidden(format!("Requires {} role", required.as_str())))
+    }
+}
+
+/// `RequireRole`에 쓰는 역할 표시 타입
+pub trait RoleMarker: Send + Sync {
+    const ROLE: Role;
+}
+
+pub struct Moderator;
+pub struct Admin;
+
+impl RoleMarker for Moderator {
+    const ROLE: Role = Role::Moderator;
+}
+
+impl RoleMarker for Admin {
+    const ROLE: Role = Role::Admin;
+}
+
+/// 역할 검사 Extractor
+///
+/// 핸들러에서 `admin: RequireRole<Admin>` 형태로 사용하면 `Claims` 추출(토큰/세션 검증) 후
+/// 역할을 확인합니다. `Deref`로 `Claims`처럼 사용할 수 있습니다.
+pub struct RequireRole<R> {
+    pub claims: Claims,
+    _role: PhantomData<R>,
+}
+
+impl<R> Deref for RequireRole<R> {
+    type Target = Claims;
+
+    fn deref(&self) -> &Claims {
+        &self.claims
+    }
+}
+
+#[async_trait]
+impl<S, R> FromRequestParts<S> for RequireRole<R>
+where
+    Users: FromRef<S>,
+    Keys: FromRef<S>,
+    S: Send + Sync,
+    R: RoleMarker,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+        require(&claims, R::ROLE)?;
+
+        Ok(Self {
+            claims,
+            _role: PhantomData,
+        })
+    }
+}
@@ -1,29 +1,48 @@
-/// 회원가입 비즈니스 로직
-///
-/// Trait 기반 의존성 주입:
-/// - 실제: PgUserRepository (DB 사용)
-/// - 테스트: MockUserRepository (가짜 데이터)
+//! 회원가입 비즈니스 로직
+//!
+//! Trait 기반 의존성 주입:
+//! - 실제: PgUserRepository (DB 사용)
+//! - 테스트: MockUserRepository (가짜 데이터)
 
-use super::{dtos::{UserResponse, LoginResponse}, repository::traits::UserRepository};
+use chrono::{Duration, Utc};
+use uuid::Uuid;
+use super::{
+    dtos::{LoginRequest, LoginResponse, SignupRequest, UserResponse},
+    jwt::{self, Claims},
+    keys::KeyStore,
+    password, refresh_token,
+    repository::{traits::UserRepository, RefreshOutcome},
+    role::Role,
+};
 use crate::api::error::AppError;
+use crate::api::validation::Validate;
+
+/// 로그인 실패 메시지 - username이 없든 비밀번호가 틀리든 같은 응답
+const INVALID_CREDENTIALS: &str = "Invalid username or password";
 
-pub async fn sign_up<R: UserRepository>(
+/// Refresh Token 유효 기간 (일) - 사용할 때마다 새 토큰으로 교체되며 기간도 다시 시작
+const REFRESH_TOKEN_TTL_DAYS: i64 = 30;
+
+pub async fn sign_up<R: UserRepository + ?Sized>(
     repo: &R,
-    username: String
+    username: String,
+    password: String,
 ) -> Result<UserResponse, AppError> {
+    // 0. 입력 검증 + 정규화 (이름 앞뒤 공백 제거, 유니코드 정규화, 길이/허용 문자)
+    let SignupRequest { username, password } = SignupRequest { username, password }.validated()?;
+
     // 1. 중복 체크
     let exists = repo.exists(&username)
-        .await
-        .map_err(|_| AppError::InternalServerError("Database error occurred".to_string()))?;
+        .await?;
 
     if exists {
-        return Err(AppError::Conflict("Username already exists".to_string()));
+        return Err(AppError::UsernameTaken("Username already exists".to_string()));
     }
 
-    // 2. 저장
-    let user_id = repo.save(&username)
-        .await
-        .map_err(|_| AppError::InternalServerError("Database error occurred".to_string()))?;
+    // 2. 비밀번호 해싱 후 저장
+    let password_hash = password::hash(password).await?;
+    let user_id = repo.save(&username, &password_hash)
+        .await?;
 
     // 3. 응답 반환
     Ok(UserResponse {
@@ -35,32 +54,171 @@ pub async fn sign_up<R: UserRepository>(
 /// 로그인 비즈니스 로직
 ///
 /// 1. DB에서 사용자 조회
-/// 2. 사용자가 없으면 Unauthorized 에러 반환
-/// 3. 사용자가 있으면 성공 응답 반환 (추후 비밀번호 체크 추가 필요)
-use uuid::Uuid; // Added for token generation
-
-pub async fn login<R: UserRepository>(
+/// 2. 비밀번호 검증 (사용자가 없어도 같은 비용의 검증 수행, 항상 한 번만)
+///    - 입력 정규화 도입 전 비밀번호(`legacy_password`)는 입력 그대로 확인하고, 성공하면 정규화한 값으로 다시 해시
+/// 3. 사용자가 없거나, 비밀번호가 없는 계정이거나, 비밀번호가 틀리면 모두 같은 Unauthorized
+/// 4. 성공 시 세션 생성 + Access Token(JWT) / Refresh Token 발급
+pub async fn login<R: UserRepository + ?Sized>(
     repo: &R,
-    username: String
+    keys: &KeyStore,
+    username: String,
+    password: String,
 ) -> Result<LoginResponse, AppError> {
+    // 0. 가입 때와 같은 정규화 (정규화 도입 전 비밀번호 확인용으로 입력도 보관)
+    let input_password = password.clone();
+    let LoginRequest { username, password } = LoginRequest { username, password }.validated()?;
+
     // 1. 사용자 조회
     let user = repo.find_by_username(&username)
-        .await
-        .map_err(|_| AppError::InternalServerError("Database error occurred".to_string()))?;
+        .await?;
+
+    // 2. 비밀번호 검증
+    let legacy = user.as_ref().is_some_and(|u| u.legacy_password);
+    let password_hash = user.as_ref().and_then(|u| u.password_hash.clone());
+    let candidate = if legacy { input_password } else { password.clone() };
+    let verified = password::verify(candidate, password_hash).await;
 
-    // 2. 결과 처리
+    // 3. 결과 처리
     match user {
-        Some(u) => {
-            // JWT 토큰 발급
-            let token = super::jwt::generate_token(u.id, u.username.clone())?;
-            
-            Ok(LoginResponse { 
-                token, 
-                user_id: u.id, 
-                username: u.username 
-            })
+        Some(u) if verified => {
+            // 정규화 도입 전 비밀번호는 정규화한 값으로 다시 해시 (이후로는 정규화한 값으로만 확인)
+            if legacy {
+                let password_hash = password::hash(password).await?;
+                repo.rehash_password(u.id, &password_hash).await?;
+            }
+
+            // 세션 생성 + 토큰 발급
+            let refresh = refresh_token::generate();
+            let session_id = repo.create_session(u.id, &refresh.hash, refresh_expires_at())
+                .await?;
+
+            issue_tokens(keys, u.id, u.username, u.role, session_id, refresh.token)
         },
-        None => Err(AppError::Unauthorized("Invalid username".to_string())),
+        _ => Err(AppError::Unauthorized(INVALID_CREDENTIALS.to_string())),
+    }
+}
+
+/// Access Token 재발급 비즈니스 로직
+///
+/// 1. 제출된 Refresh Token을 새 토큰으로 교체 (rotation)
+/// 2. 이미 사용된 토큰이면 탈취로 보고 세션 폐기 (그 세션의 Access Token도 더 이상 통과하지 못함)
+/// 3. 실패 사유와 관계없이 같은 Unauthorized
+pub async fn refresh<R: UserRepository + ?Sized>(
+    repo: &R,
+    keys: &KeyStore,
+    token: String,
+) -> Result<LoginResponse, AppError> {
+    let next = refresh_token::generate();
+    let outcome = repo.rotate_refresh_token(&refresh_token::hash(&token), &next.hash, refresh_expires_at())
+        .await?;
+
+    match outcome {
+        RefreshOutcome::Rotated(session) => {
+            issue_tokens(keys, session.user_id, session.username, session.role, session.session_id, next.token)
+        }
+        RefreshOutcome::Reused { session_id } => {
+            eprintln!("⚠️ Refresh token reuse detected, session revoked: {}", session_id);
+            Err(AppError::Unauthorized("Invalid refresh token".to_string()))
+        }
+        RefreshOutcome::Invalid => Err(AppError::Unauthorized("Invalid refresh token".to_string())),
+    }
+}
+
+/// 로그아웃 비즈니스 로직 - Access Token의 세션 폐기 (Refresh Token도 함께 무효)
+pub async fn logout<R: UserRepository + ?Sized>(
+    repo: &R,
+    claims: &Claims,
+) -> Result<(), AppError> {
+    repo.revoke_session(claims.session_id()?)
+        .await?;
+
+    Ok(())
+}
+
+/// 서명이 검증된 Access Token의 세션이 아직 유효한지 확인 (Claims Extractor에서 사용)
+pub async fn authorize<R: UserRepository + ?Sized>(
+    repo: &R,
+    claims: Claims,
+) -> Result<Claims, AppError> {
+    let active = repo.is_session_active(claims.session_id()?)
+        .await?;
+
+    if !active {
+        return Err(AppError::Unauthorized("Session has been revoked".to_string()));
+    }
+
+    Ok(claims)
+}
+
+/// 사용자 삭제 비즈니스 로직 (관리자 전용 - 역할은 Extractor에서 확인)
+///
+/// - 자기 자신 삭제 → 400 BadRequest (마지막 관리자가 스스로 사라지는 것 방지)
+/// - 없거나 이미 삭제된 사용자 → 404 NotFound
+pub async fn delete_user<R: UserRepository + ?Sized>(
+    repo: &R,
+    admin: &Claims,
+    user_id: Uuid,
+) -> Result<(), AppError> {
+    if admin.user_id()? == user_id {
+        return Err(AppError::BadRequest("Cannot delete your own account".to_string(), vec![]));
+    }
+
+    let deleted = repo.delete_user(user_id)
+        .await?;
+
+    if !deleted {
+        return Err(AppError::NotFound("User not found".to_string()));
+    }
+
+    Ok(())
+}
+
+/// 역할 변경 비즈니스 로직 (관리자 전용 - 역할은 Extractor에서 확인)
+///
+/// - 자기 자신의 역할 변경 → 400 BadRequest
+/// - 없거나 삭제된 사용자 → 404 NotFound
+/// - 대상 사용자의 세션은 폐기되어 다시 로그인해야 새 역할이 적용됨
+pub async fn update_role<R: UserRepository + ?Sized>(
+    repo: &R,
+    admin: &Claims,
+    user_id: Uuid,
+    role: Role,
+) -> Result<(), AppError> {
+    if admin.user_id()? == user_id {
+        return Err(AppError::BadRequest("Cannot change your own role".to_string(), vec![]));
+    }
+
+    let updated = repo.update_role(user_id, role)
+        .await?;
+
+    if !updated {
+        return Err(AppError::NotFound("User not found".to_string()));
     }
+
+    Ok(())
+}
+
+fn refresh_expires_at() -> chrono::DateTime<Utc> {
+    Utc::now() + Duration::days(REFRESH_TOKEN_TTL_DAYS)
+}
+
+fn issue_tokens(
+    keys: &KeyStore,
+    user_id: Uuid,
+    username: String,
+    role: Role,
+    session_id: Uuid,
+    refresh_token: String,
+) -> Result<LoginResponse, AppError> {
+    let token = jwt::generate_token(keys, user_id, username.clone(), role, session_id)?;
+
+    Ok(LoginResponse {
+        token,
+        refresh_token,
+        expires_in: jwt::ACCESS_TOKEN_TTL_SECS,
+        user_id,
+        username,
+        role,
+    })
 }
 
@@ -1,18 +1,16 @@
+use crate::api::auth::keys::KeyStore;
+use crate::api::auth::password;
 use crate::api::auth::service::login;
 use crate::api::auth::repository::UserEntity;
 use crate::api::error::AppError;
-use super::MockUserRepository;
-use uuid::Uuid;
+use super::{user_with_password, MockUserRepository};
 
 // 테스트 1: 로그인 성공
-// 올바른 username이 주어졌을 때, 해당 사용자 정보를 반환해야 함
+// 올바른 username/password가 주어졌을 때, 해당 사용자 정보를 반환해야 함
 #[tokio::test]
 async fn test_login_success() {
     // Mock 데이터 준비: DB에 "john" 유저가 있다고 가정
-    let user = UserEntity {
-        id: Uuid::new_v4(),
-        username: "john".to_string(),
-    };
+    let user = user_with_password("john", "correct horse");
 
     let mock_repo = MockUserRepository {
         find_result: Some(user.clone()), // 조회 시 유저 반환
@@ -20,7 +18,7 @@ async fn test_login_success() {
     };
 
     // Service 호출
-    let result = login(&mock_repo, "john".to_string()).await;
+    let result = login(&mock_repo, &KeyStore::development(), "john".to_string(), "correct horse".to_string()).await;
     
     // 검증
     assert!(result.is_ok());
@@ -39,9 +37,108 @@ async fn test_login_not_found() {
         ..Default::default()
     };
 
-    let result = login(&mock_repo, "unknown".to_string()).await;
+    let result = login(&mock_repo, &KeyStore::development(), "unknown".to_string(), "correct horse".to_string()).await;
     
     // 검증: Unauthorized 에러 발생하는지 확인
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
 }
+
+// 테스트 3: 로그인 실패 (비밀번호 불일치)
+#[tokio::test]
+async fn test_login_wrong_password() {
+    let mock_repo = MockUserRepository {
+        find_result: Some(user_with_password("john", "correct horse")),
+        ..Default::default()
+    };
+
+    let result = login(&mock_repo, &KeyStore::development(), "john".to_string(), "battery staple".to_string()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+}
+
+// 테스트 4: 비밀번호 불일치와 사용자 없음은 같은 응답 (username 존재 여부 노출 방지)
+#[tokio::test]
+async fn test_login_failures_are_indistinguishable() {
+    let wrong_password = MockUserRepository {
+        find_result: Some(user_with_password("john", "correct horse")),
+        ..Default::default()
+    };
+    let unknown_user = MockUserRepository::default();
+
+    let wrong = login(&wrong_password, &KeyStore::development(), "john".to_string(), "battery staple".to_string()).await;
+    let unknown = login(&unknown_user, &KeyStore::development(), "jane".to_string(), "battery staple".to_string()).await;
+
+    match (wrong.unwrap_err(), unknown.unwrap_err()) {
+        (AppError::Unauthorized(a), AppError::Unauthorized(b)) => assert_eq!(a, b),
+        other => panic!("expected Unauthorized, got {:?}", other),
+    }
+}
+
+// 테스트 5: 비밀번호가 없는 기존 계정은 로그인 불가
+#[tokio::test]
+async fn test_login_without_password_hash() {
+    let mock_repo = MockUserRepository {
+        find_result: Some(UserEntity {
+            password_hash: None,
+            ..user_with_password("legacy", "whatever1")
+        }),
+        ..Default::default()
+    };
+
+    let result = login(&mock_repo, &KeyStore::development(), "legacy".to_string(), "whatever1".to_string()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+}
+
+// 테스트 6: 정규화 도입 전 비밀번호는 입력 그대로 한 번 확인한 뒤 정규화한 값으로 다시 해시
+#[tokio::test]
+async fn test_login_rehashes_legacy_password() {
+    let user = UserEntity {
+        legacy_password: true,
+        ..user_with_password("john", "ｃｏｒｒｅｃｔ ｈｏｒｓｅ")
+    };
+    let mock_repo = MockUserRepository {
+        find_result: Some(user.clone()),
+        ..Default::default()
+    };
+
+    let result = login(&mock_repo, &KeyStore::development(), "john".to_string(), "ｃｏｒｒｅｃｔ ｈｏｒｓｅ".to_string()).await;
+
+    assert!(result.is_ok());
+    let rehashed = mock_repo.rehashed.lock().unwrap().clone();
+    assert_eq!(rehashed.len(), 1);
+    assert_eq!(rehashed[0].0, user.id);
+    assert!(password::verify("correct horse".to_string(), Some(rehashed[0].1.clone())).await);
+}
+
+// 테스트 7: 정규화 도입 전 비밀번호라도 정규화한 값으로는 확인하지 않음 (검증은 한 번만)
+#[tokio::test]
+async fn test_login_legacy_password_checked_once() {
+    let mock_repo = MockUserRepository {
+        find_result: Some(UserEntity {
+            legacy_password: true,
+            ..user_with_password("john", "correct horse")
+        }),
+        ..Default::default()
+    };
+
+    let result = login(&mock_repo, &KeyStore::development(), "john".to_string(), "ｃｏｒｒｅｃｔ ｈｏｒｓｅ".to_string()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+    assert!(mock_repo.rehashed.lock().unwrap().is_empty());
+}
+
+// 테스트 8: 정규화 이후 계정은 다시 해시하지 않음
+#[tokio::test]
+async fn test_login_does_not_rehash_normalized_password() {
+    let mock_repo = MockUserRepository {
+        find_result: Some(user_with_password("john", "correct horse")),
+        ..Default::default()
+    };
+
+    let result = login(&mock_repo, &KeyStore::development(), "john".to_string(), "ｃｏｒｒｅｃｔ ｈｏｒｓｅ".to_string()).await;
+
+    assert!(result.is_ok());
+    assert!(mock_repo.rehashed.lock().unwrap().is_empty());
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use chrono::{Duration, Utc};
+use crate::api::auth::repository::{memory::MemoryUserRepository, traits::UserRepository, RefreshOutcome};
+use crate::api::auth::role::Role;
+use crate::api::auth::keys::KeyStore;
+use crate::api::auth::service::{login, sign_up};
+use crate::api::error::AppError;
+use crate::api::memory::MemoryDb;
+
+fn repo() -> MemoryUserRepository {
+    MemoryUserRepository::new(&Arc::new(MemoryDb::default()))
+}
+
+// 테스트 1: username unique (users_username_key → USERNAME_TAKEN)
+#[tokio::test]
+async fn test_memory_unique_username() {
+    let repo = repo();
+    repo.save("alice", "hash").await.unwrap();
+
+    let result = repo.save("alice", "hash").await;
+
+    assert!(matches!(result.map_err(AppError::from), Err(AppError::UsernameTaken(_))));
+}
+
+// 테스트 2: 가입 → 로그인 → 세션 확인
+#[tokio::test]
+async fn test_memory_signup_and_login() {
+    let repo = repo();
+    sign_up(&repo, "alice".to_string(), "password123".to_string()).await.unwrap();
+
+    let response = login(&repo, &KeyStore::development(), "alice".to_string(), "password123".to_string()).await.unwrap();
+
+    assert_eq!(response.username, "alice");
+    assert_eq!(response.role, Role::User);
+    assert!(matches!(
+        sign_up(&repo, "alice".to_string(), "password123".to_string()).await,
+        Err(AppError::UsernameTaken(_))
+    ));
+}
+
+// 테스트 3: Refresh Token 재사용 시 세션 폐기
+#[tokio::test]
+async fn test_memory_refresh_token_reuse_revokes_session() {
+    let repo = repo();
+    let user_id = repo.save("alice", "hash").await.unwrap();
+    let expires_at = Utc::now() + Duration::days(1);
+    let session_id = repo.create_session(user_id, b"first", expires_at).await.unwrap();
+
+    let rotated = repo.rotate_refresh_token(b"first", b"second", expires_at).await.unwrap();
+    assert!(matches!(rotated, RefreshOutcome::Rotated(ref s) if s.session_id == session_id));
+
+    let reused = repo.rotate_refresh_token(b"first", b"third", expires_at).await.unwrap();
+    assert!(matches!(reused, RefreshOutcome::Reused { .. }));
+    assert!(!repo.is_session_active(session_id).await.unwrap());
+    assert!(matches!(
+        repo.rotate_refresh_token(b"second", b"fourth", expires_at).await.unwrap(),
+        RefreshOutcome::Invalid
+    ));
+}
+
+// 테스트 4: 삭제하면 username이 풀리고 세션 폐기
+#[tokio::test]
+async fn test_memory_delete_user() {
+    let repo = repo();
+    let user_id = repo.save("alice", "hash").await.unwrap();
+    let session_id = repo.create_session(user_id, b"token", Utc::now() + Duration::days(1)).await.unwrap();
+
+    assert!(repo.delete_user(user_id).await.unwrap());
+
+    assert!(!repo.delete_user(user_id).await.unwrap());
+    assert!(repo.find_by_username("alice").await.unwrap().is_none());
+    assert!(!repo.is_session_active(session_id).await.unwrap());
+    assert!(repo.save("alice", "hash").await.is_ok());
+}
+
+// 테스트 5: 전각/공백이 섞인 입력도 가입 때와 같은 값으로 정규화해 로그인
+#[tokio::test]
+async fn test_memory_login_normalizes_input() {
+    let repo = repo();
+    sign_up(&repo, "alice".to_string(), "password123".to_string()).await.unwrap();
+
+    let alice = login(&repo, &KeyStore::development(), " ａｌｉｃｅ ".to_string(), "ｐａｓｓｗｏｒｄ１２３".to_string()).await.unwrap();
+
+    assert_eq!(alice.username, "alice");
+}
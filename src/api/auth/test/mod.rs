@@ -1,29 +1,50 @@
+use crate::api::auth::password;
 use crate::api::auth::repository::traits::UserRepository;
-use crate::api::auth::repository::UserEntity;
+use crate::api::auth::repository::{RefreshOutcome, UserEntity};
+use crate::api::auth::role::Role;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Mutex;
 use uuid::Uuid;
 
 #[cfg(test)]
 pub mod signup;
 #[cfg(test)]
 pub mod login;
+#[cfg(test)]
+pub mod session;
+#[cfg(test)]
+pub mod keys;
+#[cfg(test)]
+pub mod role;
+#[cfg(test)]
+pub mod memory;
+
+/// 주어진 비밀번호로 가입한 사용자 (DB에 저장된 것과 같은 Argon2id 해시)
+pub fn user_with_password(username: &str, plain: &str) -> UserEntity {
+    UserEntity {
+        id: Uuid::new_v4(),
+        username: username.to_string(),
+        password_hash: Some(password::hash_blocking(plain).unwrap()),
+        role: Role::User,
+        legacy_password: false,
+    }
+}
 
 /// Mock Repository 정의
 /// 실제 DB 연결 없이 서비스 로직을 테스트하기 위해 사용됨
+#[derive(Default)]
 pub struct MockUserRepository {
     pub should_exist: bool,          // exists 호출 시 반환값 (true: 이미 존재함)
     pub save_should_fail: bool,      // save 호출 시 에러 발생 여부
     pub find_result: Option<UserEntity>, // find_by_username 호출 시 반환값 (Some: 사용자 있음)
-}
-
-impl Default for MockUserRepository {
-    fn default() -> Self {
-        Self {
-            should_exist: false,
-            save_should_fail: false,
-            find_result: None,
-        }
-    }
+    pub refresh_outcome: Option<RefreshOutcome>, // rotate_refresh_token 결과 (None: Invalid)
+    pub session_revoked: bool,       // is_session_active 호출 시 폐기된 세션으로 응답
+    pub revoked: Mutex<Vec<Uuid>>,   // revoke_session으로 폐기된 세션들
+    pub user_missing: bool,          // delete_user / update_role 대상이 없음
+    pub deleted: Mutex<Vec<Uuid>>,   // delete_user로 삭제된 사용자들
+    pub role_updates: Mutex<Vec<(Uuid, Role)>>, // update_role 호출 내역
+    pub rehashed: Mutex<Vec<(Uuid, String)>>, // rehash_password 호출 내역 (사용자 ID, 새 해시)
 }
 
 #[async_trait]
@@ -36,11 +57,49 @@ impl UserRepository for MockUserRepository {
         Ok(self.find_result.clone())
     }
 
-    async fn save(&self, _username: &str) -> Result<Uuid, sqlx::Error> {
+    async fn rehash_password(&self, user_id: Uuid, password_hash: &str) -> Result<(), sqlx::Error> {
+        self.rehashed.lock().unwrap().push((user_id, password_hash.to_string()));
+        Ok(())
+    }
+
+    async fn save(&self, _username: &str, _password_hash: &str) -> Result<Uuid, sqlx::Error> {
         if self.save_should_fail {
-            Err(sqlx::Error::RowNotFound)
+            Err(sqlx::Error::PoolTimedOut)
         } else {
             Ok(Uuid::new_v4())
         }
     }
+
+    async fn create_session(&self, _user_id: Uuid, _token_hash: &[u8], _expires_at: DateTime<Utc>) -> Result<Uuid, sqlx::Error> {
+        Ok(Uuid::new_v4())
+    }
+
+    async fn rotate_refresh_token(&self, _token_hash: &[u8], _new_token_hash: &[u8], _new_expires_at: DateTime<Utc>) -> Result<RefreshOutcome, sqlx::Error> {
+        Ok(self.refresh_outcome.clone().unwrap_or(RefreshOutcome::Invalid))
+    }
+
+    async fn revoke_session(&self, session_id: Uuid) -> Result<bool, sqlx::Error> {
+        self.revoked.lock().unwrap().push(session_id);
+        Ok(true)
+    }
+
+    async fn is_session_active(&self, _session_id: Uuid) -> Result<bool, sqlx::Error> {
+        Ok(!self.session_revoked)
+    }
+
+    async fn delete_user(&self, user_id: Uuid) -> Result<bool, sqlx::Error> {
+        if self.user_missing {
+            return Ok(false);
+        }
+        self.deleted.lock().unwrap().push(user_id);
+        Ok(true)
+    }
+
+    async fn update_role(&self, user_id: Uuid, role: Role) -> Result<bool, sqlx::Error> {
+        if self.user_missing {
+            return Ok(false);
+        }
+        self.role_updates.lock().unwrap().push((user_id, role));
+        Ok(true)
+    }
 }
@@ -0,0 +1,97 @@
+use crate::api::auth::jwt::{generate_token, verify_token, Claims};
+use crate::api::auth::keys::KeyStore;
+use crate::api::auth::role::{require, Role};
+use crate::api::auth::service::{delete_user, login, update_role};
+use crate::api::error::AppError;
+use super::{user_with_password, MockUserRepository};
+use uuid::Uuid;
+
+fn claims(role: Role) -> Claims {
+    let token = generate_token(&KeyStore::development(), Uuid::new_v4(), "someone".to_string(), role, Uuid::new_v4()).unwrap();
+    verify_token(&KeyStore::development(), &token).unwrap()
+}
+
+// 테스트 1: 상위 역할은 하위 역할의 권한을 포함
+#[test]
+fn test_role_hierarchy() {
+    assert!(Role::Admin.includes(Role::Moderator));
+    assert!(Role::Moderator.includes(Role::User));
+    assert!(!Role::Moderator.includes(Role::Admin));
+    assert!(!Role::User.includes(Role::Moderator));
+}
+
+// 테스트 2: 일반 사용자 / 운영자의 관리자 전용 기능 접근 → Forbidden
+#[test]
+fn test_require_admin_denied() {
+    for role in [Role::User, Role::Moderator] {
+        let result = require(&claims(role), Role::Admin);
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+    }
+    assert!(require(&claims(Role::Admin), Role::Admin).is_ok());
+}
+
+// 테스트 3: 일반 사용자의 운영자 기능 접근 → Forbidden, 관리자는 허용
+#[test]
+fn test_require_moderator() {
+    assert!(matches!(require(&claims(Role::User), Role::Moderator).unwrap_err(), AppError::Forbidden(_)));
+    assert!(require(&claims(Role::Moderator), Role::Moderator).is_ok());
+    assert!(require(&claims(Role::Admin), Role::Moderator).is_ok());
+}
+
+// 테스트 4: 로그인 시 저장된 역할이 토큰에 담김
+#[tokio::test]
+async fn test_login_carries_role() {
+    let mock_repo = MockUserRepository {
+        find_result: Some(crate::api::auth::repository::UserEntity {
+            role: Role::Moderator,
+            ..user_with_password("mod", "password123")
+        }),
+        ..Default::default()
+    };
+
+    let response = login(&mock_repo, &KeyStore::development(), "mod".to_string(), "password123".to_string()).await.unwrap();
+
+    assert_eq!(response.role, Role::Moderator);
+    assert_eq!(verify_token(&KeyStore::development(), &response.token).unwrap().role, Role::Moderator);
+}
+
+// 테스트 5: 역할 클레임이 없는 이전 토큰은 일반 사용자로 취급
+#[test]
+fn test_claims_without_role_default_to_user() {
+    let json = r#"{"sub":"00000000-0000-0000-0000-000000000000","username":"old","sid":"x","exp":0,"iat":0}"#;
+    let claims: Claims = serde_json::from_str(json).unwrap();
+
+    assert_eq!(claims.role, Role::User);
+}
+
+// 테스트 6: 사용자 삭제 성공 / 없는 사용자 → NotFound / 자기 자신 → BadRequest
+#[tokio::test]
+async fn test_delete_user() {
+    let admin = claims(Role::Admin);
+    let target = Uuid::new_v4();
+
+    let mock_repo = MockUserRepository::default();
+    delete_user(&mock_repo, &admin, target).await.unwrap();
+    assert_eq!(*mock_repo.deleted.lock().unwrap(), vec![target]);
+
+    let missing = MockUserRepository { user_missing: true, ..Default::default() };
+    let result = delete_user(&missing, &admin, target).await;
+    assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+
+    let result = delete_user(&mock_repo, &admin, admin.user_id().unwrap()).await;
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 7: 역할 변경 - 자기 자신의 역할은 변경 불가
+#[tokio::test]
+async fn test_update_role() {
+    let admin = claims(Role::Admin);
+    let target = Uuid::new_v4();
+    let mock_repo = MockUserRepository::default();
+
+    update_role(&mock_repo, &admin, target, Role::Moderator).await.unwrap();
+    let result = update_role(&mock_repo, &admin, admin.user_id().unwrap(), Role::User).await;
+
+    assert_eq!(*mock_repo.role_updates.lock().unwrap(), vec![(target, Role::Moderator)]);
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
@@ -0,0 +1,105 @@
+use crate::api::auth::jwt::verify_token;
+use crate::api::auth::keys::KeyStore;
+use crate::api::auth::repository::{RefreshOutcome, SessionEntity};
+use crate::api::auth::role::Role;
+use crate::api::auth::service::{authorize, login, logout, refresh};
+use crate::api::error::AppError;
+use super::{user_with_password, MockUserRepository};
+use uuid::Uuid;
+
+fn session() -> SessionEntity {
+    SessionEntity {
+        session_id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        username: "john".to_string(),
+        role: Role::User,
+    }
+}
+
+// 테스트 1: 로그인 시 세션 ID가 담긴 Access Token + Refresh Token 발급
+#[tokio::test]
+async fn test_login_issues_refresh_token() {
+    let mock_repo = MockUserRepository {
+        find_result: Some(user_with_password("john", "correct horse")),
+        ..Default::default()
+    };
+
+    let response = login(&mock_repo, &KeyStore::development(), "john".to_string(), "correct horse".to_string()).await.unwrap();
+    let claims = verify_token(&KeyStore::development(), &response.token).unwrap();
+
+    assert!(!response.refresh_token.is_empty());
+    assert!(claims.session_id().is_ok());
+}
+
+// 테스트 2: Refresh Token 교체 성공 - 같은 세션으로 새 토큰 발급
+#[tokio::test]
+async fn test_refresh_rotates_token() {
+    let session = session();
+    let mock_repo = MockUserRepository {
+        refresh_outcome: Some(RefreshOutcome::Rotated(session.clone())),
+        ..Default::default()
+    };
+
+    let response = refresh(&mock_repo, &KeyStore::development(), "old-token".to_string()).await.unwrap();
+    let claims = verify_token(&KeyStore::development(), &response.token).unwrap();
+
+    assert_eq!(response.user_id, session.user_id);
+    assert_ne!(response.refresh_token, "old-token");
+    assert_eq!(claims.session_id().unwrap(), session.session_id);
+}
+
+// 테스트 3: 이미 사용된 Refresh Token 재사용 → Unauthorized (세션은 저장소에서 폐기)
+#[tokio::test]
+async fn test_refresh_reuse_detected() {
+    let mock_repo = MockUserRepository {
+        refresh_outcome: Some(RefreshOutcome::Reused { session_id: Uuid::new_v4() }),
+        ..Default::default()
+    };
+
+    let result = refresh(&mock_repo, &KeyStore::development(), "used-token".to_string()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+}
+
+// 테스트 4: 없는/만료된 Refresh Token → Unauthorized
+#[tokio::test]
+async fn test_refresh_invalid_token() {
+    let mock_repo = MockUserRepository::default();
+
+    let result = refresh(&mock_repo, &KeyStore::development(), "unknown".to_string()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::Unauthorized(_)));
+}
+
+// 테스트 5: 로그아웃 - Access Token의 세션 폐기
+#[tokio::test]
+async fn test_logout_revokes_session() {
+    let mock_repo = MockUserRepository {
+        find_result: Some(user_with_password("john", "correct horse")),
+        ..Default::default()
+    };
+    let response = login(&mock_repo, &KeyStore::development(), "john".to_string(), "correct horse".to_string()).await.unwrap();
+    let claims = verify_token(&KeyStore::development(), &response.token).unwrap();
+
+    logout(&mock_repo, &claims).await.unwrap();
+
+    assert_eq!(*mock_repo.revoked.lock().unwrap(), [claims.session_id().unwrap()]);
+}
+
+// 테스트 6: 폐기된 세션의 Access Token은 서명이 유효해도 거부
+#[tokio::test]
+async fn test_authorize_rejects_revoked_session() {
+    let active = MockUserRepository {
+        find_result: Some(user_with_password("john", "correct horse")),
+        ..Default::default()
+    };
+    let response = login(&active, &KeyStore::development(), "john".to_string(), "correct horse".to_string()).await.unwrap();
+    let claims = verify_token(&KeyStore::development(), &response.token).unwrap();
+    let revoked = MockUserRepository {
+        session_revoked: true,
+        ..Default::default()
+    };
+
+    assert!(authorize(&active, claims.clone()).await.is_ok());
+    assert!(matches!(authorize(&revoked, claims).await.unwrap_err(), AppError::Unauthorized(_)));
+}
@@ -11,7 +11,7 @@ async fn test_signup_success() {
         ..Default::default()
     };
 
-    let result = sign_up(&mock_repo, "john".to_string()).await;
+    let result = sign_up(&mock_repo, "john".to_string(), "correct horse".to_string()).await;
 
     assert!(result.is_ok());
     assert_eq!(result.unwrap().username, "john");
@@ -26,10 +26,10 @@ async fn test_signup_duplicate_user() {
         ..Default::default()
     };
 
-    let result = sign_up(&mock_repo, "john".to_string()).await;
+    let result = sign_up(&mock_repo, "john".to_string(), "correct horse".to_string()).await;
 
     assert!(result.is_err());
-    assert!(matches!(result.unwrap_err(), AppError::Conflict(_)));
+    assert!(matches!(result.unwrap_err(), AppError::UsernameTaken(_)));
 }
 
 // 테스트 3: DB 저장 실패
@@ -40,19 +40,63 @@ async fn test_signup_database_error() {
         ..Default::default()
     };
 
-    let result = sign_up(&mock_repo, "john".to_string()).await;
+    let result = sign_up(&mock_repo, "john".to_string(), "correct horse".to_string()).await;
 
     assert!(result.is_err());
     assert!(matches!(result.unwrap_err(), AppError::InternalServerError(_)));
 }
 
-// 테스트 4: 빈 username (엣지 케이스)
+// 테스트 4: 빈 username (공백만 있는 경우 포함) → BadRequest + username 필드 상세
 #[tokio::test]
 async fn test_signup_empty_username() {
     let mock_repo = MockUserRepository::default();
 
-    let result = sign_up(&mock_repo, "".to_string()).await;
+    for username in ["", "   "] {
+        let result = sign_up(&mock_repo, username.to_string(), "correct horse".to_string()).await;
 
-    // TODO: validation 추가 시 실패하도록 수정
-    assert!(result.is_ok());
+        match result.unwrap_err() {
+            AppError::BadRequest(_, errors) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field, "username");
+                assert_eq!(errors[0].code, "too_short");
+            }
+            other => panic!("expected BadRequest, got {:?}", other),
+        }
+    }
+}
+
+// 테스트 5: 너무 짧은 비밀번호 → BadRequest
+#[tokio::test]
+async fn test_signup_short_password() {
+    let mock_repo = MockUserRepository::default();
+
+    let result = sign_up(&mock_repo, "john".to_string(), "short".to_string()).await;
+
+    assert!(matches!(result.unwrap_err(), AppError::BadRequest(..)));
+}
+
+// 테스트 6: username 앞뒤 공백 제거 + NFKC 정규화 (전각 문자 → 기본 문자)
+#[tokio::test]
+async fn test_signup_normalizes_username() {
+    let mock_repo = MockUserRepository::default();
+
+    let result = sign_up(&mock_repo, "  Ｊｏｈｎ  ".to_string(), "correct horse".to_string()).await;
+
+    assert_eq!(result.unwrap().username, "John");
+}
+
+// 테스트 7: 허용되지 않는 문자 + 짧은 비밀번호 → 실패한 필드 모두 보고
+#[tokio::test]
+async fn test_signup_reports_every_invalid_field() {
+    let mock_repo = MockUserRepository::default();
+
+    let result = sign_up(&mock_repo, "john doe!".to_string(), "short".to_string()).await;
+
+    match result.unwrap_err() {
+        AppError::BadRequest(_, errors) => {
+            let fields: Vec<(&str, &str)> = errors.iter().map(|e| (e.field.as_str(), e.code)).collect();
+            assert_eq!(fields, [("username", "invalid_characters"), ("password", "too_short")]);
+        }
+        other => panic!("expected BadRequest, got {:?}", other),
+    }
 }
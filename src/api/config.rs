@@ -0,0 +1,517 @@
+//! 애플리케이션 설정
+//!
+//! 서버 시작 시 한 번 읽어 `Config` 하나로 만들고, 이후에는 이 값만 사용합니다.
+//! 적용 순서: 기본값 → TOML 설정 파일(선택) → 환경 변수
+//! - 설정 파일: `CONFIG_FILE` 경로 (없으면 현재 디렉터리의 `config.toml`이 있을 때만 읽음)
+//! - 알 수 없는 키, 잘못된 값, 범위를 벗어난 값은 시작 시 `ConfigError`로 거부합니다.
+//!
+//! 환경 변수 (설정 파일 키)
+//! - `APP_ENV` (`environment`): `development` / `production`
+//! - `BIND_ADDR` (`server.bind_addr`)
+//! - `DATABASE_BACKEND` (`database.backend`): `postgres` / `memory`
+//! - `DATABASE_URL`, `DB_MAX_CONNECTIONS`, `DB_MIN_CONNECTIONS`,
+//!   `DB_ACQUIRE_TIMEOUT_SECS`, `DB_IDLE_TIMEOUT_SECS` (`database.*`)
+//! - `JWT_KEYS`, `JWT_ACTIVE_KID`, `JWT_SECRET` (`jwt.*`, 형식은 `auth::keys` 참고)
+//! - `CORS_ALLOWED_ORIGINS` (`cors.allowed_origins`, `,`로 구분)
+//! - `COUNTER_FLUSH_INTERVAL_MS`, `COUNTER_FLUSH_BATCH_SIZE` (`counters.*`)
+//! - `LIVE_UPDATE_INTERVAL_MS` (`live.update_interval_ms`)
+//! - `WEBHOOK_MAX_ATTEMPTS`, `WEBHOOK_INITIAL_BACKOFF_SECS`, `WEBHOOK_MAX_BACKOFF_SECS`,
+//!   `WEBHOOK_TIMEOUT_SECS`, `WEBHOOK_POLL_INTERVAL_MS`, `WEBHOOK_ALLOW_PRIVATE_TARGETS` (`webhooks.*`)
+//! - `OUTBOX_SINKS` (`outbox.sinks`, `,`로 구분: `log` / `webhook` / `channel`),
+//!   `OUTBOX_POLL_INTERVAL_MS`, `OUTBOX_BATCH_SIZE`, `OUTBOX_RETRY_DELAY_MS`, `OUTBOX_RETENTION_HOURS` (`outbox.*`)
+//! - `FEATURE_SIGNUP`, `FEATURE_SCHEDULER`, `FEATURE_RECONCILER`, `FEATURE_WEBHOOKS` (`features.*`)
+
+use std::{env, fmt, fs, net::SocketAddr, path::Path, str::FromStr, time::Duration};
+use axum::http::HeaderValue;
+use serde::Deserialize;
+
+/// `CONFIG_FILE`이 없을 때 찾는 설정 파일
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// 웹훅 요청 1회 제한 시간 상한 (5분) - 임대 만료 시각 계산(`timeout * 2`)이 넘치지 않게 막음
+const MAX_WEBHOOK_TIMEOUT_SECS: u64 = 5 * 60;
+
+/// 발행한 이벤트 보관 기간 상한 (1년) - 삭제 기준 시각 계산(`retention_hours * 60 * 60`)이 넘치지 않게 막음
+const MAX_OUTBOX_RETENTION_HOURS: u64 = 365 * 24;
+
+/// 설정 오류 (서버 시작 중단)
+#[derive(Debug)]
+pub struct ConfigError(String);
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "configuration: {}", self.0)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// 실행 환경 (운영 모드에서는 JWT 키 / CORS 검사가 엄격해짐)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+    #[default]
+    Development,
+    Production,
+}
+
+impl FromStr for Environment {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "development" | "dev" => Ok(Environment::Development),
+            "production" | "prod" => Ok(Environment::Production),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub environment: Environment,
+    pub server: ServerConfig,
+    pub database: DatabaseConfig,
+    pub jwt: JwtConfig,
+    pub cors: CorsConfig,
+    pub counters: CounterConfig,
+    pub live: LiveConfig,
+    pub webhooks: WebhookConfig,
+    pub outbox: OutboxConfig,
+    pub features: Features,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub bind_addr: SocketAddr,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: SocketAddr::from(([0, 0, 0, 0], 3000)),
+        }
+    }
+}
+
+/// 저장소 종류
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backend {
+    #[default]
+    Postgres,
+    Memory, // PostgreSQL 없이 실행 (재시작하면 데이터 사라짐)
+}
+
+impl FromStr for Backend {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "postgres" | "postgresql" => Ok(Backend::Postgres),
+            "memory" => Ok(Backend::Memory),
+            _ => Err(()),
+        }
+    }
+}
+
+/// 저장소 / 연결 풀 설정 (`memory`면 연결 풀 설정은 쓰지 않음)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub backend: Backend,
+    pub url: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout_secs: u64, // 풀에서 연결을 기다리는 최대 시간
+    pub idle_timeout_secs: u64,    // 쉬는 연결을 닫기까지의 시간 (0: 닫지 않음)
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            backend: Backend::Postgres,
+            url: String::new(),
+            max_connections: 5,
+            min_connections: 0,
+            acquire_timeout_secs: 5,
+            idle_timeout_secs: 600,
+        }
+    }
+}
+
+impl DatabaseConfig {
+    pub fn acquire_timeout(&self) -> Duration {
+        Duration::from_secs(self.acquire_timeout_secs)
+    }
+
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        (self.idle_timeout_secs > 0).then(|| Duration::from_secs(self.idle_timeout_secs))
+    }
+}
+
+/// JWT 서명 키 설정 (키 파일은 `auth::keys`에서 읽음)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JwtConfig {
+    pub keys: Option<String>, // `kid:ALG:서명키 파일[:검증키 파일]`을 `;`로 구분
+    pub active_kid: Option<String>,
+    pub secret: Option<String>, // `keys`가 없을 때 쓰는 단일 HS256 비밀
+}
+
+/// 허용할 Origin 목록 (비어 있으면 CORS 헤더를 보내지 않음, `*`는 모든 Origin)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsConfig {
+    pub allowed_origins: Vec<String>,
+}
+
+/// 카운터 쓰기 지연 설정
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CounterConfig {
+    pub flush_interval_ms: u64,  // 반영 주기
+    pub flush_batch_size: usize, // 주기 전이라도 반영을 시작하는 투표 수
+}
+
+impl Default for CounterConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval_ms: 200,
+            flush_batch_size: 1000,
+        }
+    }
+}
+
+impl CounterConfig {
+    pub fn flush_interval(&self) -> Duration {
+        Duration::from_millis(self.flush_interval_ms)
+    }
+}
+
+/// 실시간 집계 전송 (SSE / WebSocket) 설정
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LiveConfig {
+    pub update_interval_ms: u64, // 안건 하나에 집계를 보내는 최소 간격 (사이의 투표는 하나로 합침)
+}
+
+impl Default for LiveConfig {
+    fn default() -> Self {
+        Self { update_interval_ms: 500 }
+    }
+}
+
+impl LiveConfig {
+    pub fn update_interval(&self) -> Duration {
+        Duration::from_millis(self.update_interval_ms)
+    }
+}
+
+/// 웹훅 전송 설정
+/// - 실패하면 `initial_backoff_secs`부터 두 배씩(최대 `max_backoff_secs`) 기다렸다가 재시도
+/// - `max_attempts`번 실패하면 dead로 옮기고 더 시도하지 않음
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebhookConfig {
+    pub max_attempts: u32,
+    pub initial_backoff_secs: u64,
+    pub max_backoff_secs: u64,
+    pub timeout_secs: u64,     // 요청 1회 제한 시간
+    pub poll_interval_ms: u64, // 대기열 확인 주기
+    pub allow_private_targets: bool, // 루프백/사설망/링크 로컬 주소로도 전송 (개발/테스트용)
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 8,
+            initial_backoff_secs: 10,
+            max_backoff_secs: 60 * 60,
+            timeout_secs: 10,
+            poll_interval_ms: 1000,
+            allow_private_targets: false,
+        }
+    }
+}
+
+impl WebhookConfig {
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+}
+
+/// outbox 이벤트를 발행할 곳
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SinkKind {
+    Log,     // 서버 로그
+    Webhook, // 웹훅 전송 대기열 (안건 이벤트만)
+    Channel, // 프로세스 안의 구독자
+}
+
+impl FromStr for SinkKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "log" => Ok(SinkKind::Log),
+            "webhook" => Ok(SinkKind::Webhook),
+            "channel" => Ok(SinkKind::Channel),
+            _ => Err(()),
+        }
+    }
+}
+
+/// outbox relay 설정
+/// - 싱크 하나라도 실패하면 그 안건의 이벤트는 `retry_delay_ms` 뒤에 다시 발행
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutboxConfig {
+    pub sinks: Vec<SinkKind>,
+    pub poll_interval_ms: u64,
+    pub batch_size: i64,       // 한 번에 임대하는 이벤트 수
+    pub retry_delay_ms: u64,
+    pub retention_hours: u64,  // 발행한 이벤트 보관 기간
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            sinks: vec![SinkKind::Webhook],
+            poll_interval_ms: 200,
+            batch_size: 100,
+            retry_delay_ms: 5000,
+            retention_hours: 24,
+        }
+    }
+}
+
+impl OutboxConfig {
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub fn retry_delay(&self) -> Duration {
+        Duration::from_millis(self.retry_delay_ms)
+    }
+
+    pub fn retention(&self) -> Duration {
+        Duration::from_secs(self.retention_hours * 60 * 60)
+    }
+}
+
+/// 기능 토글
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Features {
+    pub signup: bool,     // 회원가입 허용 (끄면 403)
+    pub scheduler: bool,  // 예약 시작/마감 스케줄러
+    pub reconciler: bool, // 캐싱 카운터 주기 점검
+    pub webhooks: bool,   // 웹훅 전송 작업 (끄면 이벤트는 대기열에 쌓이기만 함)
+}
+
+impl Default for Features {
+    fn default() -> Self {
+        Self {
+            signup: true,
+            scheduler: true,
+            reconciler: true,
+            webhooks: true,
+        }
+    }
+}
+
+impl Config {
+    /// 설정 파일과 프로세스 환경 변수에서 읽기
+    pub fn load() -> Result<Self, ConfigError> {
+        let file = match env::var("CONFIG_FILE") {
+            Ok(path) => Some(read_file(&path)?),
+            Err(_) if Path::new(DEFAULT_CONFIG_FILE).exists() => Some(read_file(DEFAULT_CONFIG_FILE)?),
+            Err(_) => None,
+        };
+
+        Self::from_sources(file.as_deref(), |key| env::var(key).ok())
+    }
+
+    /// 설정 파일 내용과 환경 변수 조회 함수로 만들기 (테스트에서는 고정 값 사용)
+    pub fn from_sources(file: Option<&str>, env: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let mut config: Config = match file {
+            Some(text) => toml::from_str(text).map_err(|e| ConfigError(format!("config file: {}", e)))?,
+            None => Config::default(),
+        };
+
+        config.apply_env(&env)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn apply_env(&mut self, env: &impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        override_parsed(env, "APP_ENV", &mut self.environment)?;
+        override_parsed(env, "BIND_ADDR", &mut self.server.bind_addr)?;
+
+        override_parsed(env, "DATABASE_BACKEND", &mut self.database.backend)?;
+        override_parsed(env, "DATABASE_URL", &mut self.database.url)?;
+        override_parsed(env, "DB_MAX_CONNECTIONS", &mut self.database.max_connections)?;
+        override_parsed(env, "DB_MIN_CONNECTIONS", &mut self.database.min_connections)?;
+        override_parsed(env, "DB_ACQUIRE_TIMEOUT_SECS", &mut self.database.acquire_timeout_secs)?;
+        override_parsed(env, "DB_IDLE_TIMEOUT_SECS", &mut self.database.idle_timeout_secs)?;
+
+        if let Some(keys) = env("JWT_KEYS") {
+            self.jwt.keys = Some(keys);
+        }
+        if let Some(kid) = env("JWT_ACTIVE_KID") {
+            self.jwt.active_kid = Some(kid);
+        }
+        if let Some(secret) = env("JWT_SECRET") {
+            self.jwt.secret = Some(secret);
+        }
+
+        if let Some(origins) = env("CORS_ALLOWED_ORIGINS") {
+            self.cors.allowed_origins = origins
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(String::from)
+                .collect();
+        }
+
+        override_parsed(env, "COUNTER_FLUSH_INTERVAL_MS", &mut self.counters.flush_interval_ms)?;
+        override_parsed(env, "COUNTER_FLUSH_BATCH_SIZE", &mut self.counters.flush_batch_size)?;
+        override_parsed(env, "LIVE_UPDATE_INTERVAL_MS", &mut self.live.update_interval_ms)?;
+        override_parsed(env, "WEBHOOK_MAX_ATTEMPTS", &mut self.webhooks.max_attempts)?;
+        override_parsed(env, "WEBHOOK_INITIAL_BACKOFF_SECS", &mut self.webhooks.initial_backoff_secs)?;
+        override_parsed(env, "WEBHOOK_MAX_BACKOFF_SECS", &mut self.webhooks.max_backoff_secs)?;
+        override_parsed(env, "WEBHOOK_TIMEOUT_SECS", &mut self.webhooks.timeout_secs)?;
+        override_parsed(env, "WEBHOOK_POLL_INTERVAL_MS", &mut self.webhooks.poll_interval_ms)?;
+        override_parsed(env, "WEBHOOK_ALLOW_PRIVATE_TARGETS", &mut self.webhooks.allow_private_targets)?;
+
+        if let Some(sinks) = env("OUTBOX_SINKS") {
+            self.outbox.sinks = sinks
+                .split(',')
+                .map(str::trim)
+                .filter(|sink| !sink.is_empty())
+                .map(|sink| sink.parse().map_err(|_| ConfigError(format!("OUTBOX_SINKS: invalid sink `{}`", sink))))
+                .collect::<Result<_, _>>()?;
+        }
+        override_parsed(env, "OUTBOX_POLL_INTERVAL_MS", &mut self.outbox.poll_interval_ms)?;
+        override_parsed(env, "OUTBOX_BATCH_SIZE", &mut self.outbox.batch_size)?;
+        override_parsed(env, "OUTBOX_RETRY_DELAY_MS", &mut self.outbox.retry_delay_ms)?;
+        override_parsed(env, "OUTBOX_RETENTION_HOURS", &mut self.outbox.retention_hours)?;
+
+        override_parsed(env, "FEATURE_SIGNUP", &mut self.features.signup)?;
+        override_parsed(env, "FEATURE_SCHEDULER", &mut self.features.scheduler)?;
+        override_parsed(env, "FEATURE_RECONCILER", &mut self.features.reconciler)?;
+        override_parsed(env, "FEATURE_WEBHOOKS", &mut self.features.webhooks)?;
+        Ok(())
+    }
+
+    /// 값 범위 검사 (처음 발견한 문제 하나를 보고)
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |message: &str| Err(ConfigError(message.to_string()));
+        let db = &self.database;
+
+        if db.backend == Backend::Postgres && db.url.trim().is_empty() {
+            return invalid("database.url is required (set DATABASE_URL, or DATABASE_BACKEND=memory)");
+        }
+        if db.max_connections == 0 {
+            return invalid("database.max_connections must be at least 1");
+        }
+        if db.min_connections > db.max_connections {
+            return invalid("database.min_connections must not exceed database.max_connections");
+        }
+        if db.acquire_timeout_secs == 0 {
+            return invalid("database.acquire_timeout_secs must be at least 1");
+        }
+
+        if self.counters.flush_interval_ms == 0 {
+            return invalid("counters.flush_interval_ms must be at least 1");
+        }
+        if self.counters.flush_batch_size == 0 {
+            return invalid("counters.flush_batch_size must be at least 1");
+        }
+        if self.live.update_interval_ms == 0 {
+            return invalid("live.update_interval_ms must be at least 1");
+        }
+
+        let webhooks = &self.webhooks;
+        if webhooks.max_attempts == 0 {
+            return invalid("webhooks.max_attempts must be at least 1");
+        }
+        if webhooks.initial_backoff_secs == 0 {
+            return invalid("webhooks.initial_backoff_secs must be at least 1");
+        }
+        if webhooks.max_backoff_secs < webhooks.initial_backoff_secs {
+            return invalid("webhooks.max_backoff_secs must not be less than webhooks.initial_backoff_secs");
+        }
+        if webhooks.timeout_secs == 0 || webhooks.timeout_secs > MAX_WEBHOOK_TIMEOUT_SECS {
+            return invalid(&format!("webhooks.timeout_secs must be between 1 and {}", MAX_WEBHOOK_TIMEOUT_SECS));
+        }
+        if webhooks.poll_interval_ms == 0 {
+            return invalid("webhooks.poll_interval_ms must be at least 1");
+        }
+
+        let outbox = &self.outbox;
+        if outbox.poll_interval_ms == 0 {
+            return invalid("outbox.poll_interval_ms must be at least 1");
+        }
+        if outbox.batch_size < 1 {
+            return invalid("outbox.batch_size must be at least 1");
+        }
+        if outbox.retry_delay_ms == 0 {
+            return invalid("outbox.retry_delay_ms must be at least 1");
+        }
+        if outbox.retention_hours > MAX_OUTBOX_RETENTION_HOURS {
+            return invalid(&format!("outbox.retention_hours must not exceed {}", MAX_OUTBOX_RETENTION_HOURS));
+        }
+
+        for origin in &self.cors.allowed_origins {
+            if origin == "*" {
+                if self.environment == Environment::Production {
+                    return invalid("cors.allowed_origins must not contain `*` in production");
+                }
+                continue;
+            }
+            let valid = (origin.starts_with("http://") || origin.starts_with("https://"))
+                && !origin.ends_with('/')
+                && HeaderValue::from_str(origin).is_ok();
+            if !valid {
+                return Err(ConfigError(format!(
+                    "cors.allowed_origins: invalid origin `{}` (expected scheme://host[:port])",
+                    origin
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn is_production(&self) -> bool {
+        self.environment == Environment::Production
+    }
+}
+
+fn read_file(path: &str) -> Result<String, ConfigError> {
+    fs::read_to_string(path).map_err(|e| ConfigError(format!("cannot read config file {}: {}", path, e)))
+}
+
+/// 환경 변수가 있으면 파싱해서 덮어쓰기 (파싱 실패는 에러 - 잘못된 값을 조용히 무시하지 않음)
+fn override_parsed<T: FromStr>(
+    env: &impl Fn(&str) -> Option<String>,
+    key: &str,
+    target: &mut T,
+) -> Result<(), ConfigError> {
+    if let Some(raw) = env(key) {
+        *target = raw
+            .trim()
+            .parse()
+            .map_err(|_| ConfigError(format!("{}: invalid value `{}`", key, raw)))?;
+    }
+    Ok(())
+}
@@ -1,10 +1,80 @@
-use axum::{response::IntoResponse, http::StatusCode, Json};
+//! 공통 에러 타입과 응답 형식
+//!
+//! 에러 응답은 RFC 7807 problem+json 형식입니다.
+//! ```json
+//! { "type": "/problems/already-voted", "title": "Conflict", "status": 409,
+//!   "detail": "Already voted on this agenda", "code": "ALREADY_VOTED" }
+//! ```
+//! - `code`: 클라이언트가 메시지 대신 비교할 수 있는 안정적인 에러 코드
+//! - `errors`: 입력 검증 실패 시 필드별 상세 (없으면 생략)
+
+use axum::{
+    http::{header, HeaderValue, StatusCode},
+    response::IntoResponse,
+    Json,
+};
 use serde::Serialize;
+use sqlx::error::ErrorKind;
+use super::validation::FieldError;
 
-// 공통 에러 응답 DTO
+/// problem+json 응답 본문
 #[derive(Serialize)]
-pub struct ErrorResponse {
-    pub message: String,
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String, // 에러 종류 URI (코드에서 생성)
+    pub title: &'static str,  // HTTP 상태 설명
+    pub status: u16,
+    pub detail: String,       // 사람이 읽는 설명 (바뀔 수 있음)
+    pub code: ErrorCode,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
+/// 안정적인 에러 코드 (응답에는 `USERNAME_TAKEN` 형식)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    BadRequest,
+    ValidationFailed,
+    Unauthorized,
+    Forbidden,
+    AgendaClosed,
+    NotFound,
+    Conflict,
+    UsernameTaken,
+    AlreadyVoted,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    InternalError,
+}
+
+impl ErrorCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::BadRequest => "BAD_REQUEST",
+            ErrorCode::ValidationFailed => "VALIDATION_FAILED",
+            ErrorCode::Unauthorized => "UNAUTHORIZED",
+            ErrorCode::Forbidden => "FORBIDDEN",
+            ErrorCode::AgendaClosed => "AGENDA_CLOSED",
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::Conflict => "CONFLICT",
+            ErrorCode::UsernameTaken => "USERNAME_TAKEN",
+            ErrorCode::AlreadyVoted => "ALREADY_VOTED",
+            ErrorCode::PayloadTooLarge => "PAYLOAD_TOO_LARGE",
+            ErrorCode::UnsupportedMediaType => "UNSUPPORTED_MEDIA_TYPE",
+            ErrorCode::InternalError => "INTERNAL_ERROR",
+        }
+    }
+
+    /// problem+json의 `type` (예: `ALREADY_VOTED` → `/problems/already-voted`)
+    fn problem_type(self) -> String {
+        format!("/problems/{}", self.as_str().to_lowercase().replace('_', "-"))
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
 // 커스텀 에러 타입
@@ -12,24 +82,118 @@ pub struct ErrorResponse {
 pub enum AppError {
     NotFound(String),
     Conflict(String),
-    BadRequest(String),
+    BadRequest(String, Vec<FieldError>), // 필드별 상세가 없으면 빈 목록
     Unauthorized(String),
+    Forbidden(String),
+    AgendaClosed(String), // 투표 기간이 아닌 안건에 투표 (draft/closed/cancelled, opens_at~closes_at 밖)
+    UsernameTaken(String), // 이미 사용 중인 username으로 가입
+    AlreadyVoted(String),  // 이미 투표한 안건에 다시 투표 (변경은 PUT)
+    PayloadTooLarge(String),      // 요청 본문이 크기 제한을 넘음
+    UnsupportedMediaType(String), // JSON 본문에 `Content-Type: application/json`이 없음
     InternalServerError(String),
 }
 
+impl AppError {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            AppError::NotFound(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) | AppError::UsernameTaken(_) | AppError::AlreadyVoted(_) => StatusCode::CONFLICT,
+            AppError::BadRequest(..) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) | AppError::AgendaClosed(_) => StatusCode::FORBIDDEN,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            AppError::InternalServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            AppError::NotFound(_) => ErrorCode::NotFound,
+            AppError::Conflict(_) => ErrorCode::Conflict,
+            AppError::BadRequest(_, errors) if !errors.is_empty() => ErrorCode::ValidationFailed,
+            AppError::BadRequest(..) => ErrorCode::BadRequest,
+            AppError::Unauthorized(_) => ErrorCode::Unauthorized,
+            AppError::Forbidden(_) => ErrorCode::Forbidden,
+            AppError::AgendaClosed(_) => ErrorCode::AgendaClosed,
+            AppError::UsernameTaken(_) => ErrorCode::UsernameTaken,
+            AppError::AlreadyVoted(_) => ErrorCode::AlreadyVoted,
+            AppError::PayloadTooLarge(_) => ErrorCode::PayloadTooLarge,
+            AppError::UnsupportedMediaType(_) => ErrorCode::UnsupportedMediaType,
+            AppError::InternalServerError(_) => ErrorCode::InternalError,
+        }
+    }
+}
+
 // IntoResponse 구현 - 자동으로 HTTP 응답으로 변환
 impl IntoResponse for AppError {
     fn into_response(self) -> axum::response::Response {
-        let (status, message) = match self {
-            AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            AppError::Conflict(msg) => (StatusCode::CONFLICT, msg),
-            AppError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
-            AppError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
-            AppError::InternalServerError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+        let (status, code) = (self.status(), self.code());
+        let (detail, errors) = match self {
+            AppError::BadRequest(msg, errors) => (msg, errors),
+            AppError::NotFound(msg)
+            | AppError::Conflict(msg)
+            | AppError::Unauthorized(msg)
+            | AppError::Forbidden(msg)
+            | AppError::AgendaClosed(msg)
+            | AppError::UsernameTaken(msg)
+            | AppError::AlreadyVoted(msg)
+            | AppError::PayloadTooLarge(msg)
+            | AppError::UnsupportedMediaType(msg)
+            | AppError::InternalServerError(msg) => (msg, vec![]),
         };
 
-        let body = ErrorResponse { message };
+        let body = ProblemDetails {
+            problem_type: code.problem_type(),
+            title: status.canonical_reason().unwrap_or("Error"),
+            status: status.as_u16(),
+            detail,
+            code,
+            errors,
+        };
+
+        let mut response = (status, Json(body)).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        response
+    }
+}
+
+/// DB 에러 분류
+///
+/// - 행 없음 → 404 NotFound
+/// - unique 위반 → 409 (알려진 제약은 전용 코드: username → USERNAME_TAKEN, 중복 투표 → ALREADY_VOTED)
+/// - 외래 키 위반 → 409 Conflict (참조 대상이 없음)
+/// - check 위반 → 400 BadRequest
+/// - 그 외 → 500 (원인은 로그에만 남기고 응답에는 노출하지 않음)
+impl From<sqlx::Error> for AppError {
+    fn from(e: sqlx::Error) -> Self {
+        if let sqlx::Error::RowNotFound = e {
+            return AppError::NotFound("Resource not found".to_string());
+        }
+
+        if let Some(db) = e.as_database_error() {
+            match db.kind() {
+                ErrorKind::UniqueViolation => {
+                    return match db.constraint() {
+                        Some("users_username_key") => AppError::UsernameTaken("Username already exists".to_string()),
+                        Some("uk_vote_user_agenda") => AppError::AlreadyVoted("Already voted on this agenda".to_string()),
+                        _ => AppError::Conflict("Resource already exists".to_string()),
+                    };
+                }
+                ErrorKind::ForeignKeyViolation => {
+                    return AppError::Conflict("Referenced resource does not exist".to_string());
+                }
+                ErrorKind::CheckViolation => {
+                    return AppError::BadRequest("Request violates a data constraint".to_string(), vec![]);
+                }
+                _ => {}
+            }
+        }
 
-        (status, Json(body)).into_response()
+        eprintln!("❌ Database error: {}", e);
+        AppError::InternalServerError("Database error occurred".to_string())
     }
 }
@@ -0,0 +1,71 @@
+//! 거부 응답이 problem+json인 Extractor
+//!
+//! `axum::Json` / `axum::extract::Query`는 본문/쿼리 파싱에 실패하면 일반 텍스트로 응답합니다.
+//! 같은 이름의 래퍼로 바꿔 쓰면 파싱 실패도 problem+json으로 응답합니다.
+//! - `Content-Type: application/json`이 없으면 415 `UNSUPPORTED_MEDIA_TYPE`
+//! - 본문이 크기 제한을 넘으면 413 `PAYLOAD_TOO_LARGE`
+//! - 그 밖의 파싱 실패는 400 `BAD_REQUEST`
+
+use axum::{
+    async_trait,
+    extract::{rejection::{JsonRejection, QueryRejection}, FromRequest, FromRequestParts, Request},
+    http::{request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+};
+use super::error::AppError;
+
+/// JSON 요청 본문 / 응답 (`axum::Json`과 같음)
+pub struct Json<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for Json<T>
+where
+    axum::Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        axum::Json::<T>::from_request(req, state)
+            .await
+            .map(|axum::Json(value)| Json(value))
+            .map_err(|rejection| rejected(rejection.status(), rejection.body_text()))
+    }
+}
+
+impl<T> IntoResponse for Json<T>
+where
+    axum::Json<T>: IntoResponse,
+{
+    fn into_response(self) -> Response {
+        axum::Json(self.0).into_response()
+    }
+}
+
+/// 쿼리 문자열 (`axum::extract::Query`와 같음)
+pub struct Query<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for Query<T>
+where
+    axum::extract::Query<T>: FromRequestParts<S, Rejection = QueryRejection>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        axum::extract::Query::<T>::from_request_parts(parts, state)
+            .await
+            .map(|axum::extract::Query(value)| Query(value))
+            .map_err(|rejection| rejected(rejection.status(), rejection.body_text()))
+    }
+}
+
+/// 거부 상태 코드에 맞는 에러 (415/413은 그대로, 나머지는 400)
+fn rejected(status: StatusCode, detail: String) -> AppError {
+    match status {
+        StatusCode::UNSUPPORTED_MEDIA_TYPE => AppError::UnsupportedMediaType(detail),
+        StatusCode::PAYLOAD_TOO_LARGE => AppError::PayloadTooLarge(detail),
+        _ => AppError::BadRequest(detail, vec![]),
+    }
+}
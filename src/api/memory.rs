@@ -0,0 +1,249 @@
+//! 메모리 저장소 (PostgreSQL 없이 실행 - 데모, 로컬 개발, 빠른 통합 테스트)
+//!
+//! 사용자/세션/안건/선택지/투표/감사 로그/영수증/웹훅/outbox를 테이블처럼 한 잠금 아래 보관합니다.
+//! - Repository 메서드 하나가 잠금을 한 번 잡고 검사 → 변경을 끝내므로 트랜잭션처럼 동작합니다.
+//! - 스키마의 제약(unique, 외래 키, check)은 같은 제약 이름의 DB 에러로 흉내내므로
+//!   `AppError` 변환 결과가 PostgreSQL과 같습니다. (예: 중복 투표 → `ALREADY_VOTED`)
+//! - 투표 카운터는 쓰기 지연 없이 투표와 함께 바로 갱신합니다.
+//! - 서버가 종료되면 데이터는 사라집니다.
+
+use std::sync::{Mutex, MutexGuard};
+use chrono::{DateTime, Utc};
+use sqlx::error::{DatabaseError, ErrorKind};
+use uuid::Uuid;
+use super::agenda::{audit, receipt};
+use super::agenda::repository::{
+    AgendaEntity, AgendaOptionEntity, AuditAction, AuditEntry, Choice, ReceiptEntity, ReceiptRootEntity, ReceiptStatus,
+};
+use super::auth::role::Role;
+use super::outbox::{event::DomainEvent, repository::OutboxRecord};
+use super::webhook::repository::{AttemptResult, DeliveryStatus, SubscriptionEntity};
+
+/// 메모리 DB (Repository들이 `Arc`로 공유)
+#[derive(Default)]
+pub struct MemoryDb {
+    tables: Mutex<Tables>,
+}
+
+impl MemoryDb {
+    /// 테이블 잠금 (await 없이 검사 → 변경 후 바로 해제)
+    pub fn lock(&self) -> MutexGuard<'_, Tables> {
+        self.tables.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[derive(Default)]
+pub struct Tables {
+    pub users: Vec<UserRow>,
+    pub sessions: Vec<SessionRow>,
+    pub refresh_tokens: Vec<RefreshTokenRow>,
+    pub agendas: Vec<AgendaEntity>, // 카운터 컬럼 포함
+    pub options: Vec<AgendaOptionEntity>,
+    pub votes: Vec<VoteRow>,
+    pub vote_audit: Vec<AuditEntry>, // 안건별 seq 순으로 추가만 함
+    pub receipts: Vec<ReceiptRow>,
+    pub receipt_roots: Vec<ReceiptRootEntity>,
+    pub webhook_subscriptions: Vec<SubscriptionEntity>,
+    pub webhook_deliveries: Vec<DeliveryRow>,
+    pub webhook_attempts: Vec<AttemptRow>,
+    pub outbox: Vec<OutboxRow>,
+    pub outbox_seq: i64, // outbox.id (BIGSERIAL) 마지막 값
+}
+
+pub struct UserRow {
+    pub id: Uuid,
+    pub username: String,
+    pub password_hash: Option<String>,
+    pub role: Role,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+pub struct SessionRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+pub struct RefreshTokenRow {
+    pub session_id: Uuid,
+    pub token_hash: Vec<u8>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+/// votes + vote_selections (선택 목록은 `Choice`에 포함)
+pub struct VoteRow {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub agenda_id: Uuid,
+    pub choice: Choice,
+}
+
+/// vote_receipts (salt는 다시 읽을 일이 없으므로 보관하지 않음)
+pub struct ReceiptRow {
+    pub entity: ReceiptEntity,
+    pub vote_id: Uuid,
+}
+
+pub struct DeliveryRow {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+pub struct AttemptRow {
+    pub delivery_id: Uuid,
+    pub attempted_at: DateTime<Utc>,
+    pub result: AttemptResult,
+}
+
+pub struct OutboxRow {
+    pub record: OutboxRecord,
+    pub locked_until: Option<DateTime<Utc>>,
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl Tables {
+    pub fn user_exists(&self, user_id: Uuid) -> bool {
+        self.users.iter().any(|u| u.id == user_id)
+    }
+
+    /// 이벤트 기록 (`outbox::repository::append`와 같이 변경과 같은 잠금 안에서 호출)
+    pub fn append_events(&mut self, events: impl IntoIterator<Item = DomainEvent>) {
+        let now = Utc::now();
+        for event in events {
+            self.outbox_seq += 1;
+            self.outbox.push(OutboxRow {
+                record: OutboxRecord {
+                    id: self.outbox_seq,
+                    agenda_id: event.agenda_id(),
+                    event_type: event.event_type().to_string(),
+                    payload: event.payload(),
+                    created_at: now,
+                },
+                locked_until: None,
+                published_at: None,
+            });
+        }
+    }
+
+    /// 감사 로그 한 줄 추가 (`agenda::repository::audit::append`와 같이 투표 변경과 같은 잠금 안에서 호출)
+    pub fn append_audit(&mut self, agenda_id: Uuid, action: AuditAction, vote_id: Uuid, user_id: Uuid, choice: Option<&Choice>) {
+        let previous = self.vote_audit.iter().rev().find(|e| e.agenda_id == agenda_id);
+        let entry = audit::link(previous, agenda_id, action, vote_id, user_id, choice);
+        self.vote_audit.push(entry);
+    }
+
+    /// 새 영수증 발급 (`agenda::repository::receipt::issue`와 같음)
+    pub fn issue_receipt(&mut self, agenda_id: Uuid, vote_id: Uuid, choice: &Choice) -> String {
+        let receipt = receipt::commit(&receipt::new_salt(), agenda_id, vote_id, choice);
+        self.receipts.push(ReceiptRow {
+            entity: ReceiptEntity {
+                receipt: receipt.clone(),
+                agenda_id,
+                status: ReceiptStatus::Counted,
+                created_at: Utc::now(),
+            },
+            vote_id,
+        });
+        receipt
+    }
+
+    /// 집계 중인 영수증을 `status`로 변경
+    pub fn revoke_receipt(&mut self, vote_id: Uuid, status: ReceiptStatus) {
+        for row in self.receipts.iter_mut().filter(|r| r.vote_id == vote_id) {
+            if row.entity.status == ReceiptStatus::Counted {
+                row.entity.status = status;
+            }
+        }
+    }
+
+    /// 투표의 집계 중인 영수증
+    pub fn current_receipt(&self, vote_id: Uuid) -> Option<String> {
+        self.receipts
+            .iter()
+            .find(|r| r.vote_id == vote_id && r.entity.status == ReceiptStatus::Counted)
+            .map(|r| r.entity.receipt.clone())
+    }
+
+    /// 집계 중인 영수증 전체
+    pub fn counted_receipts(&self, agenda_id: Uuid) -> Vec<String> {
+        self.receipts
+            .iter()
+            .filter(|r| r.entity.agenda_id == agenda_id && r.entity.status == ReceiptStatus::Counted)
+            .map(|r| r.entity.receipt.clone())
+            .collect()
+    }
+
+    /// 마감한 안건의 영수증 Merkle 루트 기록 (이미 있으면 그대로 둠)
+    pub fn publish_receipt_root(&mut self, agenda_id: Uuid) {
+        if self.receipt_roots.iter().any(|r| r.agenda_id == agenda_id) {
+            return;
+        }
+        let receipts = self.counted_receipts(agenda_id);
+        self.receipt_roots.push(ReceiptRootEntity {
+            agenda_id,
+            root: receipt::merkle_root(&receipts),
+            leaf_count: receipts.len() as i32,
+            published_at: Utc::now(),
+        });
+    }
+}
+
+/// 제약 위반 DB 에러 생성 (`constraint`: 스키마의 제약 이름)
+pub fn violation(kind: ErrorKind, constraint: &'static str) -> sqlx::Error {
+    sqlx::Error::Database(Box::new(ConstraintViolation { kind, constraint }))
+}
+
+/// PostgreSQL 제약 위반과 같은 정보를 주는 DB 에러
+#[derive(Debug)]
+pub struct ConstraintViolation {
+    kind: ErrorKind,
+    constraint: &'static str,
+}
+
+impl std::fmt::Display for ConstraintViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "violates constraint \"{}\"", self.constraint)
+    }
+}
+
+impl std::error::Error for ConstraintViolation {}
+
+impl DatabaseError for ConstraintViolation {
+    fn message(&self) -> &str {
+        "constraint violation"
+    }
+
+    fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn constraint(&self) -> Option<&str> {
+        Some(self.constraint)
+    }
+
+    fn kind(&self) -> ErrorKind {
+        // ErrorKind는 Clone이 아니므로 다시 만듦
+        match self.kind {
+            ErrorKind::UniqueViolation => ErrorKind::UniqueViolation,
+            ErrorKind::ForeignKeyViolation => ErrorKind::ForeignKeyViolation,
+            ErrorKind::NotNullViolation => ErrorKind::NotNullViolation,
+            ErrorKind::CheckViolation => ErrorKind::CheckViolation,
+            _ => ErrorKind::Other,
+        }
+    }
+}
@@ -1,21 +1,116 @@
-use axum::{Router, routing::get};
-use sqlx::PgPool;
+use std::{sync::Arc, time::Duration};
+use axum::{Router, http::{header, HeaderValue, Method}, routing::get};
+use tower_http::cors::{AllowOrigin, CorsLayer};
 
 // 하위 모듈(파일)들을 등록합니다.
 // 이렇게 선언해야 auth.rs, agenda.rs를 인식합니다.
 pub mod error;  // 공통 에러 모듈 (다른 모듈에서 사용 가능하도록 pub)
+pub mod config; // 애플리케이션 설정 (기본값 → 설정 파일 → 환경 변수)
+pub mod validation; // 요청 DTO 입력 검증
+pub mod extract;    // 거부 응답이 problem+json인 Json / Query
+pub mod state;      // 라우터 공유 상태 (Repository, 설정, 공유 서비스)
+pub mod memory;     // 메모리 저장소 (PostgreSQL 없이 실행)
 mod auth;
 mod agenda;
+mod webhook;
+mod outbox;
+
+#[cfg(test)]
+mod test;
 
 // Express의 app.use('/path', router)와 같은 역할을 하는 함수를 만듭니다.
 // 외부(main.rs)에서 이 함수를 호출해서 완성된 Router를 받아갑니다.
-pub fn app(pool: PgPool) -> Router {
-    Router::new()
-        .route("/health", get(health_check)) // 공통 헬스 체크
-        .nest("/auth", auth::router())       // 1. Auth 라우터 연결
-        .nest("/agendas", agenda::router())  // 2. Agenda 라우터 연결
-        .fallback(handler_404)               // 3. 404 에러 핸들러
-        .with_state(pool)                    // 4. DB Pool 공유 (최상단에서 한 번만 주입)
+pub fn app(state: AppState) -> Router {
+    let cors = cors_layer(&state.config.cors);
+    let router = Router::new()
+        .route("/health", get(health_check))  // 공통 헬스 체크
+        .nest("/auth", auth::router())        // 1. Auth 라우터 연결
+        .nest("/agendas", agenda::router())   // 2. Agenda 라우터 연결
+        .nest("/webhooks", webhook::router()) // 3. Webhook 라우터 연결
+        .fallback(handler_404)                // 4. 404 에러 핸들러
+        .with_state(state);                   // 5. 공유 상태 주입 (최상단에서 한 번만)
+
+    // 6. 허용된 Origin이 있을 때만 CORS 헤더 추가
+    match cors {
+        Some(cors) => router.layer(cors),
+        None => router,
+    }
+}
+
+pub use config::Config;
+pub use state::AppState;
+
+/// CORS 설정 (`*`면 모든 Origin, 목록이 비어 있으면 None)
+fn cors_layer(cors: &config::CorsConfig) -> Option<CorsLayer> {
+    if cors.allowed_origins.is_empty() {
+        return None;
+    }
+
+    let origins = if cors.allowed_origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        // 형식은 Config::validate에서 검사함
+        AllowOrigin::list(cors.allowed_origins.iter().filter_map(|origin| HeaderValue::from_str(origin).ok()))
+    };
+
+    Some(
+        CorsLayer::new()
+            .allow_origin(origins)
+            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+            .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE]),
+    )
+}
+
+/// JWT 서명 키 로드 (서버 시작 시 한 번, 운영 모드에서 키가 없으면 에러)
+pub fn load_signing_keys(config: &Config) -> Result<auth::keys::KeyStore, auth::keys::KeyError> {
+    let keys = auth::keys::KeyConfig::from_config(&config.jwt, config.is_production())?;
+    auth::keys::KeyStore::load(keys)
+}
+
+/// 종료 시 처리 - 버퍼에 남은 카운터 증감을 DB에 반영
+pub async fn shutdown(state: &AppState) {
+    agenda::flusher::flush_remaining(state.agendas.as_ref()).await;
+}
+
+/// 안건 스케줄러 실행 주기
+const SCHEDULER_PERIOD: Duration = Duration::from_secs(1);
+
+/// 캐싱 카운터 점검 주기
+const RECONCILE_PERIOD: Duration = Duration::from_secs(10 * 60);
+
+/// 백그라운드 작업 시작 (안건 예약 상태 전이, 카운터 반영/점검, 실시간 집계 전송, 이벤트 발행, 웹훅 전송)
+/// - 스케줄러 / 점검 / 웹훅 전송은 기능 토글로 끌 수 있음 (카운터 반영, 이벤트 발행은 항상 실행)
+pub fn spawn_background_tasks(state: &AppState) {
+    let features = &state.config.features;
+    if features.scheduler {
+        agenda::scheduler::spawn(state.agendas.clone(), state.live.clone(), SCHEDULER_PERIOD);
+    }
+    if features.reconciler {
+        agenda::reconciler::spawn(state.agendas.clone(), RECONCILE_PERIOD);
+    }
+    agenda::flusher::spawn(state.agendas.clone(), state.counters.clone(), state.config.counters.flush_interval());
+    agenda::live::spawn(state.agendas.clone(), state.live.clone(), state.config.live.update_interval());
+    outbox::relay::spawn(state.outbox.clone(), outbox_sinks(state), state.config.outbox.clone());
+    if features.webhooks {
+        webhook::dispatcher::spawn(state.webhooks.clone(), state.config.webhooks.clone());
+    }
+}
+
+/// `outbox.sinks` 설정 순서대로 싱크 구성
+fn outbox_sinks(state: &AppState) -> Vec<Arc<dyn outbox::sink::Sink>> {
+    state
+        .config
+        .outbox
+        .sinks
+        .iter()
+        .map(|kind| -> Arc<dyn outbox::sink::Sink> {
+            match kind {
+                config::SinkKind::Log => Arc::new(outbox::sink::LogSink),
+                config::SinkKind::Webhook => Arc::new(webhook::sink::WebhookSink::new(state.webhooks.clone(), state.agendas.clone())),
+                config::SinkKind::Channel => state.events.clone(),
+            }
+        })
+        .collect()
 }
 
 async fn health_check() -> &'static str {
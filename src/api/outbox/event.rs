@@ -0,0 +1,138 @@
+//! 도메인 이벤트 (outbox에 기록되는 내용)
+//!
+//! - `agenda.*`: 안건 생성 / 상태 변경 (데이터: 변경 후의 안건, 득표 제외)
+//! - `vote.*`: 투표 / 변경 / 철회 (데이터: 투표자와 선택)
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+use crate::api::agenda::repository::{AgendaEntity, AgendaStatus, Choice, DecisionRule, TiePolicy, VotingMethod};
+
+pub const AGENDA_CREATED: &str = "agenda.created";
+pub const AGENDA_OPENED: &str = "agenda.opened";
+pub const AGENDA_CLOSED: &str = "agenda.closed";
+pub const AGENDA_CANCELLED: &str = "agenda.cancelled";
+pub const VOTE_CAST: &str = "vote.cast";
+pub const VOTE_CHANGED: &str = "vote.changed";
+pub const VOTE_RETRACTED: &str = "vote.retracted";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DomainEvent {
+    AgendaCreated(AgendaSnapshot),
+    AgendaOpened(AgendaSnapshot),
+    AgendaClosed(AgendaSnapshot),
+    AgendaCancelled(AgendaSnapshot),
+    VoteCast(VoteEvent),
+    VoteChanged(VoteEvent),
+    VoteRetracted(VoteEvent),
+}
+
+/// 이벤트 시점의 안건 (득표는 카운터 반영 시점에 따라 달라지므로 제외 - 결과는 `/result`로 조회)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgendaSnapshot {
+    pub id: Uuid,
+    pub title: String,
+    pub created_by: Uuid,
+    pub status: AgendaStatus,
+    pub opens_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub voting_method: VotingMethod,
+    pub decision_rule: DecisionRule,
+    pub tie_policy: TiePolicy,
+    pub quorum: Option<i32>,
+    pub hide_results: bool,
+}
+
+/// 투표 이벤트 (`previous`: 변경/철회 전 선택, `choice`: 투표/변경 후 선택)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VoteEvent {
+    pub agenda_id: Uuid,
+    pub user_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<Choice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub choice: Option<Choice>,
+}
+
+impl From<&AgendaEntity> for AgendaSnapshot {
+    fn from(agenda: &AgendaEntity) -> Self {
+        Self {
+            id: agenda.id,
+            title: agenda.title.clone(),
+            created_by: agenda.created_by,
+            status: agenda.status,
+            opens_at: agenda.opens_at,
+            closes_at: agenda.closes_at,
+            voting_method: agenda.voting_method,
+            decision_rule: agenda.decision_rule,
+            tie_policy: agenda.tie_policy,
+            quorum: agenda.quorum,
+            hide_results: agenda.hide_results,
+        }
+    }
+}
+
+impl DomainEvent {
+    /// 상태 전이 후의 안건 → 이벤트 (draft로 돌아가는 전이는 없음)
+    pub fn status_changed(agenda: &AgendaEntity) -> Option<Self> {
+        let snapshot = AgendaSnapshot::from(agenda);
+        match agenda.status {
+            AgendaStatus::Open => Some(DomainEvent::AgendaOpened(snapshot)),
+            AgendaStatus::Closed => Some(DomainEvent::AgendaClosed(snapshot)),
+            AgendaStatus::Cancelled => Some(DomainEvent::AgendaCancelled(snapshot)),
+            AgendaStatus::Draft => None,
+        }
+    }
+
+    pub fn vote_cast(agenda_id: Uuid, user_id: Uuid, choice: &Choice) -> Self {
+        DomainEvent::VoteCast(VoteEvent { agenda_id, user_id, previous: None, choice: Some(choice.clone()) })
+    }
+
+    pub fn vote_changed(agenda_id: Uuid, user_id: Uuid, previous: &Choice, choice: &Choice) -> Self {
+        DomainEvent::VoteChanged(VoteEvent {
+            agenda_id,
+            user_id,
+            previous: Some(previous.clone()),
+            choice: Some(choice.clone()),
+        })
+    }
+
+    pub fn vote_retracted(agenda_id: Uuid, user_id: Uuid, previous: &Choice) -> Self {
+        DomainEvent::VoteRetracted(VoteEvent { agenda_id, user_id, previous: Some(previous.clone()), choice: None })
+    }
+
+    /// 순서 보장 단위
+    pub fn agenda_id(&self) -> Uuid {
+        match self {
+            DomainEvent::AgendaCreated(agenda)
+            | DomainEvent::AgendaOpened(agenda)
+            | DomainEvent::AgendaClosed(agenda)
+            | DomainEvent::AgendaCancelled(agenda) => agenda.id,
+            DomainEvent::VoteCast(vote) | DomainEvent::VoteChanged(vote) | DomainEvent::VoteRetracted(vote) => vote.agenda_id,
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            DomainEvent::AgendaCreated(_) => AGENDA_CREATED,
+            DomainEvent::AgendaOpened(_) => AGENDA_OPENED,
+            DomainEvent::AgendaClosed(_) => AGENDA_CLOSED,
+            DomainEvent::AgendaCancelled(_) => AGENDA_CANCELLED,
+            DomainEvent::VoteCast(_) => VOTE_CAST,
+            DomainEvent::VoteChanged(_) => VOTE_CHANGED,
+            DomainEvent::VoteRetracted(_) => VOTE_RETRACTED,
+        }
+    }
+
+    /// outbox.payload (JSONB)
+    pub fn payload(&self) -> serde_json::Value {
+        let value = match self {
+            DomainEvent::AgendaCreated(agenda)
+            | DomainEvent::AgendaOpened(agenda)
+            | DomainEvent::AgendaClosed(agenda)
+            | DomainEvent::AgendaCancelled(agenda) => serde_json::to_value(agenda),
+            DomainEvent::VoteCast(vote) | DomainEvent::VoteChanged(vote) | DomainEvent::VoteRetracted(vote) => serde_json::to_value(vote),
+        };
+        value.expect("domain events serialize to JSON")
+    }
+}
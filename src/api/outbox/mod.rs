@@ -0,0 +1,13 @@
+// Outbox 모듈 구조 (트랜잭션 아웃박스)
+// - event: 도메인 이벤트 (안건 생성/상태 변경, 투표/변경/철회)
+// - repository: outbox 테이블 (기록은 안건 Repository의 트랜잭션 안에서, 임대/발행 표시는 relay가)
+// - sink: 발행 대상 (log / channel, 웹훅은 `webhook::sink`)
+// - relay: outbox → 싱크 발행 작업
+
+pub mod event;
+pub mod repository;
+pub mod sink;
+pub mod relay;
+
+#[cfg(test)]
+pub mod test;
@@ -0,0 +1,94 @@
+//! outbox relay 작업
+//!
+//! `poll_interval` 마다 발행되지 않은 이벤트를 id 순서대로 임대해 모든 싱크에 넘깁니다.
+//! - 모든 싱크가 성공한 이벤트만 발행 완료로 표시 → 최소 한 번(at-least-once) 발행
+//! - 한 이벤트가 실패하면 같은 안건의 뒤 이벤트도 함께 `retry_delay` 뒤로 미룸 → 안건별 순서 유지
+//! - 서버가 발행 도중 죽으면 임대가 끝난 뒤 다른 relay가 다시 발행
+//! - 발행 후 `retention_hours`가 지난 이벤트는 삭제
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use uuid::Uuid;
+use crate::api::config::OutboxConfig;
+use crate::api::state::Outbox;
+use super::repository::{traits::OutboxRepository, OutboxRecord};
+use super::sink::{Sink, SinkError};
+
+/// 임대 시간 (이 시간 안에 발행 결과를 기록하지 못하면 다시 발행)
+const LEASE: Duration = Duration::from_secs(60);
+
+/// 백그라운드 relay 시작
+pub fn spawn(repo: Outbox, sinks: Vec<Arc<dyn Sink>>, config: OutboxConfig) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(config.poll_interval());
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            // 한 묶음을 꽉 채웠으면 남은 이벤트가 더 있을 수 있으므로 바로 다음 묶음 처리
+            while run_once(repo.as_ref(), &sinks, &config).await == config.batch_size as usize {}
+            purge(repo.as_ref(), &config).await;
+        }
+    })
+}
+
+/// relay 1회 실행 - 가져온 이벤트 수 반환 (DB 에러는 다음 주기에 다시 시도하므로 로그만 남김)
+pub async fn run_once<R: OutboxRepository + ?Sized>(repo: &R, sinks: &[Arc<dyn Sink>], config: &OutboxConfig) -> usize {
+    let records = match repo.claim(config.batch_size, Utc::now() + LEASE).await {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("❌ Outbox read failed: {}", e);
+            return 0;
+        }
+    };
+
+    let mut published = Vec::new();
+    let mut deferred = Vec::new();
+    let mut blocked: HashSet<Uuid> = HashSet::new(); // 이번 묶음에서 실패한 안건
+
+    for record in &records {
+        if !blocked.contains(&record.agenda_id) {
+            match publish(sinks, record).await {
+                Ok(()) => {
+                    published.push(record.id);
+                    continue;
+                }
+                Err((sink, e)) => {
+                    eprintln!("❌ Outbox event #{} ({}) failed on {} sink: {}", record.id, record.event_type, sink, e);
+                    blocked.insert(record.agenda_id);
+                }
+            }
+        }
+        deferred.push(record.id);
+    }
+
+    if !published.is_empty() {
+        if let Err(e) = repo.mark_published(&published).await {
+            eprintln!("❌ Outbox publish mark failed: {}", e);
+        }
+    }
+    if !deferred.is_empty() {
+        if let Err(e) = repo.release(&deferred, Utc::now() + config.retry_delay()).await {
+            eprintln!("❌ Outbox release failed: {}", e);
+        }
+    }
+    records.len()
+}
+
+/// 싱크에 순서대로 발행 (처음 실패한 싱크에서 멈춤)
+async fn publish(sinks: &[Arc<dyn Sink>], record: &OutboxRecord) -> Result<(), (&'static str, SinkError)> {
+    for sink in sinks {
+        sink.publish(record).await.map_err(|e| (sink.name(), e))?;
+    }
+    Ok(())
+}
+
+/// 보관 기간이 지난 발행 완료 이벤트 삭제
+async fn purge<R: OutboxRepository + ?Sized>(repo: &R, config: &OutboxConfig) {
+    if let Err(e) = repo.purge_published(Utc::now() - config.retention()).await {
+        eprintln!("❌ Outbox purge failed: {}", e);
+    }
+}
@@ -0,0 +1,33 @@
+use sqlx::{Postgres, Transaction};
+use crate::api::outbox::event::DomainEvent;
+
+/// 이벤트 기록 DB 로직 (호출자의 트랜잭션 안에서 실행)
+///
+/// 변경과 같은 트랜잭션에서 기록하므로 변경이 롤백되면 이벤트도 남지 않고,
+/// 커밋된 변경은 반드시 이벤트를 남깁니다. id는 배열 순서대로 매겨집니다.
+///
+/// id는 커밋이 아니라 INSERT 시점에 매겨지므로, 같은 안건을 동시에 바꾸는 트랜잭션은
+/// 호출 전에 안건 행 배타 잠금이나 `vote::append_event`처럼 안건별 잠금으로 직렬화해야 커밋 순서와 id 순서가 같습니다.
+pub async fn append(tx: &mut Transaction<'_, Postgres>, events: &[DomainEvent]) -> Result<(), sqlx::Error> {
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    let agenda_ids: Vec<_> = events.iter().map(DomainEvent::agenda_id).collect();
+    let event_types: Vec<_> = events.iter().map(|event| event.event_type().to_string()).collect();
+    let payloads: Vec<_> = events.iter().map(DomainEvent::payload).collect();
+
+    sqlx::query!(
+        "INSERT INTO outbox (agenda_id, event_type, payload)
+         SELECT agenda_id, event_type, payload
+         FROM UNNEST($1::uuid[], $2::text[], $3::jsonb[]) WITH ORDINALITY AS t(agenda_id, event_type, payload, ord)
+         ORDER BY ord",
+        &agenda_ids,
+        &event_types,
+        &payloads
+    )
+    .execute(&mut **tx)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::{traits::OutboxRepository, OutboxRecord};
+use crate::api::memory::MemoryDb;
+
+/// 메모리 Repository 구현체 (PostgreSQL 구현과 같은 임대 / 순서 규칙)
+pub struct MemoryOutboxRepository {
+    db: Arc<MemoryDb>,
+}
+
+impl MemoryOutboxRepository {
+    pub fn new(db: &Arc<MemoryDb>) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for MemoryOutboxRepository {
+    /// 임대 중인 이벤트가 있는 안건은 통째로 제외 (`relay::claim`과 같은 규칙)
+    async fn claim(&self, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<OutboxRecord>, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let now = Utc::now();
+
+        let leased: Vec<Uuid> = tables
+            .outbox
+            .iter()
+            .filter(|row| row.published_at.is_none() && row.locked_until.is_some_and(|t| t > now))
+            .map(|row| row.record.agenda_id)
+            .collect();
+
+        // outbox는 id 순서대로 쌓임
+        Ok(tables
+            .outbox
+            .iter_mut()
+            .filter(|row| row.published_at.is_none() && !leased.contains(&row.record.agenda_id))
+            .take(limit.max(0) as usize)
+            .map(|row| {
+                row.locked_until = Some(lease_until);
+                row.record.clone()
+            })
+            .collect())
+    }
+
+    async fn mark_published(&self, ids: &[i64]) -> Result<(), sqlx::Error> {
+        let mut tables = self.db.lock();
+        let now = Utc::now();
+        for row in tables.outbox.iter_mut().filter(|row| ids.contains(&row.record.id)) {
+            row.published_at = Some(now);
+            row.locked_until = None;
+        }
+        Ok(())
+    }
+
+    async fn release(&self, ids: &[i64], retry_at: DateTime<Utc>) -> Result<(), sqlx::Error> {
+        let mut tables = self.db.lock();
+        for row in tables.outbox.iter_mut().filter(|row| ids.contains(&row.record.id)) {
+            row.locked_until = Some(retry_at);
+        }
+        Ok(())
+    }
+
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let count = tables.outbox.len();
+        tables.outbox.retain(|row| row.published_at.is_none_or(|t| t >= before));
+        Ok((count - tables.outbox.len()) as u64)
+    }
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use self::traits::OutboxRepository;
+
+pub mod traits;
+pub mod append;
+pub mod relay;
+pub mod memory; // PostgreSQL 없이 실행할 때의 메모리 구현체
+
+/// outbox에 기록된 이벤트 1개
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxRecord {
+    pub id: i64, // 기록 순서 (수신자의 중복 제거 키)
+    pub agenda_id: uuid::Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// PostgreSQL Repository 구현체
+/// - 기록(`append`)은 각 안건 Repository가 자신의 트랜잭션 안에서 직접 합니다.
+pub struct PgOutboxRepository {
+    pool: PgPool,
+}
+
+impl PgOutboxRepository {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for PgOutboxRepository {
+    async fn claim(&self, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<OutboxRecord>, sqlx::Error> {
+        relay::claim(&self.pool, limit, lease_until).await
+    }
+
+    async fn mark_published(&self, ids: &[i64]) -> Result<(), sqlx::Error> {
+        relay::mark_published(&self.pool, ids).await
+    }
+
+    async fn release(&self, ids: &[i64], retry_at: DateTime<Utc>) -> Result<(), sqlx::Error> {
+        relay::release(&self.pool, ids, retry_at).await
+    }
+
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        relay::purge_published(&self.pool, before).await
+    }
+}
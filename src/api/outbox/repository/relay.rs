@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use super::OutboxRecord;
+
+/// 임대 직렬화용 advisory lock 키 ("outbox" ASCII)
+const CLAIM_LOCK_KEY: i64 = 0x6f7574626f78;
+
+/// 발행할 이벤트 임대 (단일 트랜잭션)
+///
+/// 1. advisory lock으로 여러 서버의 임대를 한 번에 하나씩 실행
+/// 2. 발행되지 않은 이벤트를 id 순서대로 가져오되, 임대 중인 이벤트가 있는 안건은 통째로 제외
+///
+/// 1이 없으면 두 relay가 같은 안건의 앞/뒤 이벤트를 나눠 가져가 순서가 뒤바뀔 수 있습니다.
+pub async fn claim(pool: &PgPool, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<OutboxRecord>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!("SELECT pg_advisory_xact_lock($1)", CLAIM_LOCK_KEY)
+        .execute(&mut *tx)
+        .await?;
+
+    let mut records = sqlx::query_as!(
+        OutboxRecord,
+        "WITH next AS (
+             SELECT o.id FROM outbox o
+             WHERE o.published_at IS NULL
+               AND NOT EXISTS (
+                   SELECT 1 FROM outbox l
+                   WHERE l.agenda_id = o.agenda_id AND l.published_at IS NULL AND l.locked_until > NOW()
+               )
+             ORDER BY o.id
+             LIMIT $1
+         )
+         UPDATE outbox SET locked_until = $2
+         FROM next
+         WHERE outbox.id = next.id
+         RETURNING outbox.id, agenda_id, event_type, payload, created_at",
+        limit,
+        lease_until
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    // RETURNING은 순서를 보장하지 않음
+    records.sort_by_key(|record| record.id);
+    Ok(records)
+}
+
+pub async fn mark_published(pool: &PgPool, ids: &[i64]) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE outbox SET published_at = NOW(), locked_until = NULL WHERE id = ANY($1)",
+        ids
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn release(pool: &PgPool, ids: &[i64], retry_at: DateTime<Utc>) -> Result<(), sqlx::Error> {
+    sqlx::query!("UPDATE outbox SET locked_until = $2 WHERE id = ANY($1)", ids, retry_at)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+pub async fn purge_published(pool: &PgPool, before: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM outbox WHERE published_at < $1", before)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
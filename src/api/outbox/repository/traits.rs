@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use super::OutboxRecord;
+
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// 발행할 이벤트를 id 순서대로 최대 `limit`개 가져와 `lease_until`까지 임대
+    /// - 이미 임대 중인(또는 재시도를 기다리는) 이벤트가 있는 안건은 건너뜀 (안건별 순서 보장)
+    async fn claim(&self, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<OutboxRecord>, sqlx::Error>;
+
+    /// 발행 완료 표시
+    async fn mark_published(&self, ids: &[i64]) -> Result<(), sqlx::Error>;
+
+    /// 발행하지 못한 이벤트를 `retry_at`까지 임대한 채로 돌려놓음 (그때까지 같은 안건은 발행하지 않음)
+    async fn release(&self, ids: &[i64], retry_at: DateTime<Utc>) -> Result<(), sqlx::Error>;
+
+    /// `before` 이전에 발행된 이벤트 삭제 후 삭제한 수 반환
+    async fn purge_published(&self, before: DateTime<Utc>) -> Result<u64, sqlx::Error>;
+}
@@ -0,0 +1,72 @@
+//! 발행 대상 (싱크)
+//!
+//! relay가 outbox 이벤트를 하나씩 순서대로 넘깁니다. 에러를 반환하면 그 이벤트와
+//! 같은 안건의 뒤 이벤트는 재시도 간격 뒤에 다시 발행합니다 (다른 싱크에는 다시 갈 수 있음).
+//! - `LogSink`: 서버 로그
+//! - `ChannelSink`: 프로세스 안의 구독자 (`tokio::sync::broadcast`)
+//! - `webhook::sink::WebhookSink`: 웹훅 전송 대기열
+
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+use super::repository::OutboxRecord;
+
+pub type SinkError = Box<dyn std::error::Error + Send + Sync>;
+
+/// 채널에 쌓아 두는 최대 이벤트 수 (느린 구독자는 그보다 오래된 이벤트를 놓침)
+const CHANNEL_CAPACITY: usize = 1024;
+
+#[async_trait]
+pub trait Sink: Send + Sync {
+    /// 로그용 이름
+    fn name(&self) -> &'static str;
+
+    /// 이벤트 1개 발행 - 같은 이벤트가 두 번 올 수 있으므로 `record.id`로 중복을 거를 수 있어야 함
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), SinkError>;
+}
+
+/// 서버 로그로 발행
+pub struct LogSink;
+
+#[async_trait]
+impl Sink for LogSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), SinkError> {
+        println!("📣 Event #{} {} (agenda {})", record.id, record.event_type, record.agenda_id);
+        Ok(())
+    }
+}
+
+/// 프로세스 안의 구독자에게 발행 (구독자가 없으면 버림)
+pub struct ChannelSink {
+    sender: broadcast::Sender<OutboxRecord>,
+}
+
+impl Default for ChannelSink {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(CHANNEL_CAPACITY).0,
+        }
+    }
+}
+
+impl ChannelSink {
+    pub fn subscribe(&self) -> broadcast::Receiver<OutboxRecord> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl Sink for ChannelSink {
+    fn name(&self) -> &'static str {
+        "channel"
+    }
+
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), SinkError> {
+        // 에러는 구독자가 없다는 뜻뿐이므로 무시
+        let _ = self.sender.send(record.clone());
+        Ok(())
+    }
+}
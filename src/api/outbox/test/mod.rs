@@ -0,0 +1,101 @@
+use std::sync::{Arc, Mutex};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::api::agenda::repository::{
+    memory::MemoryAgendaRepository, traits::AgendaRepository, AgendaStatus, DecisionRule, NewAgenda, TiePolicy, VotingMethod,
+};
+use crate::api::auth::repository::{memory::MemoryUserRepository, traits::UserRepository};
+use crate::api::memory::MemoryDb;
+use super::repository::{memory::MemoryOutboxRepository, traits::OutboxRepository, OutboxRecord};
+use super::sink::{Sink, SinkError};
+
+pub mod relay;
+pub mod postgres; // DATABASE_URL이 있을 때만 실행
+
+/// 아무 이벤트도 없는 outbox (Mock 안건 Repository는 이벤트를 기록하지 않음)
+pub struct MockOutboxRepository;
+
+#[async_trait]
+impl OutboxRepository for MockOutboxRepository {
+    async fn claim(&self, _limit: i64, _lease_until: DateTime<Utc>) -> Result<Vec<OutboxRecord>, sqlx::Error> {
+        Ok(vec![])
+    }
+
+    async fn mark_published(&self, _ids: &[i64]) -> Result<(), sqlx::Error> {
+        Ok(())
+    }
+
+    async fn release(&self, _ids: &[i64], _retry_at: DateTime<Utc>) -> Result<(), sqlx::Error> {
+        Ok(())
+    }
+
+    async fn purge_published(&self, _before: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+        Ok(0)
+    }
+}
+
+/// 받은 이벤트를 기록하는 싱크 (`failing` 안건의 이벤트는 실패)
+#[derive(Default)]
+pub struct RecordingSink {
+    pub published: Mutex<Vec<OutboxRecord>>,
+    pub failing: Mutex<Option<Uuid>>,
+}
+
+impl RecordingSink {
+    /// 받은 이벤트의 (안건, 종류)
+    pub fn events(&self) -> Vec<(Uuid, String)> {
+        self.published
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|record| (record.agenda_id, record.event_type.clone()))
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Sink for RecordingSink {
+    fn name(&self) -> &'static str {
+        "recording"
+    }
+
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), SinkError> {
+        if *self.failing.lock().unwrap() == Some(record.agenda_id) {
+            return Err("sink unavailable".into());
+        }
+        self.published.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+}
+
+/// 메모리 DB를 공유하는 안건 / outbox Repository + 가입한 사용자 `count`명
+pub async fn setup(count: usize) -> (MemoryAgendaRepository, MemoryOutboxRepository, Vec<Uuid>) {
+    let db = Arc::new(MemoryDb::default());
+    let users = MemoryUserRepository::new(&db);
+    let mut ids = Vec::new();
+    for i in 0..count {
+        ids.push(users.save(&format!("user{}", i), "hash").await.unwrap());
+    }
+    (MemoryAgendaRepository::new(&db), MemoryOutboxRepository::new(&db), ids)
+}
+
+/// 투표 가능한 찬/반 안건 생성
+pub async fn open_agenda<R: AgendaRepository + ?Sized>(repo: &R, created_by: Uuid) -> Uuid {
+    repo.create(&NewAgenda {
+        title: "Agenda".to_string(),
+        created_by,
+        status: AgendaStatus::Open,
+        opens_at: None,
+        closes_at: None,
+        options: vec![],
+        voting_method: VotingMethod::SingleChoice,
+        decision_rule: DecisionRule::SimpleMajority,
+        tie_policy: TiePolicy::Fail,
+        quorum: None,
+        hide_results: false,
+    })
+    .await
+    .unwrap()
+    .id
+}
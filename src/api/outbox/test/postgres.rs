@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use std::time::Duration;
+use sqlx::{postgres::PgPoolOptions, PgPool};
+use uuid::Uuid;
+use crate::api::agenda::repository::{counter::CounterBuffer, vote, Choice, PgAgendaRepository};
+use crate::api::auth::repository::{traits::UserRepository, PgUserRepository};
+use crate::api::config::OutboxConfig;
+use crate::api::outbox::event::DomainEvent;
+use crate::api::outbox::relay::run_once;
+use crate::api::outbox::repository::PgOutboxRepository;
+use crate::api::outbox::sink::Sink;
+use super::{open_agenda, RecordingSink};
+
+/// `DATABASE_URL`의 PostgreSQL (`cargo test -- --ignored`로 실행)
+async fn connect() -> PgPool {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL is set");
+    PgPoolOptions::new().max_connections(4).connect(&url).await.expect("DATABASE_URL is reachable")
+}
+
+/// 남은 이벤트를 모두 발행한 뒤 `agenda_id` 안건의 (종류, 사용자 ID) 목록
+async fn drain(outbox: &PgOutboxRepository, sink: &Arc<RecordingSink>, agenda_id: Uuid) -> Vec<(String, Option<String>)> {
+    let sinks: Vec<Arc<dyn Sink>> = vec![sink.clone()];
+    while run_once(outbox, &sinks, &OutboxConfig::default()).await > 0 {}
+
+    let published = sink.published.lock().unwrap();
+    let records: Vec<_> = published.iter().filter(|record| record.agenda_id == agenda_id).collect();
+    assert!(records.windows(2).all(|pair| pair[0].id < pair[1].id));
+    records
+        .into_iter()
+        .map(|record| (record.event_type.clone(), record.payload["user_id"].as_str().map(str::to_string)))
+        .collect()
+}
+
+// 테스트 1: 같은 안건의 두 투표 트랜잭션이 엇갈려도 outbox id 순서 = 커밋 순서로 발행
+// - 먼저 이벤트 id를 받은 트랜잭션이 커밋하기 전까지 다음 투표는 기록 잠금에서 기다려야 함
+#[tokio::test]
+#[ignore = "requires DATABASE_URL"]
+async fn test_interleaved_votes_publish_in_commit_order() {
+    let pool = connect().await;
+    let counters = Arc::new(CounterBuffer::new(100));
+    let agendas = PgAgendaRepository::new(&pool, &counters);
+    let users = PgUserRepository::new(&pool);
+    let outbox = PgOutboxRepository::new(&pool);
+    let sink = Arc::new(RecordingSink::default());
+
+    let first = users.save(&format!("outbox-{}", Uuid::new_v4().simple()), "hash").await.unwrap();
+    let second = users.save(&format!("outbox-{}", Uuid::new_v4().simple()), "hash").await.unwrap();
+    let agenda_id = open_agenda(&agendas, first).await;
+
+    // 첫 번째 투표: 투표 트랜잭션과 같은 방법으로 이벤트 id를 받은 채 커밋 전에 멈춤
+    let mut tx = pool.begin().await.unwrap();
+    vote::append_event(&mut tx, agenda_id, DomainEvent::vote_cast(agenda_id, first, &Choice::Agree)).await.unwrap();
+
+    // 두 번째 투표: 그 사이에 시작
+    let pending = tokio::spawn({
+        let pool = pool.clone();
+        let counters = counters.clone();
+        async move { vote::cast(&pool, &counters, agenda_id, second, Choice::Disagree).await }
+    });
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert!(!pending.is_finished(), "second vote must wait for the first to commit");
+    assert_eq!(drain(&outbox, &sink, agenda_id).await, [("agenda.created".to_string(), None)]);
+
+    tx.commit().await.unwrap();
+    pending.await.unwrap().unwrap();
+
+    let events = drain(&outbox, &sink, agenda_id).await;
+    assert_eq!(
+        events,
+        [
+            ("agenda.created".to_string(), None),
+            ("vote.cast".to_string(), Some(first.to_string())),
+            ("vote.cast".to_string(), Some(second.to_string())),
+        ]
+    );
+}
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::Utc;
+use crate::api::agenda::repository::{traits::AgendaRepository, AgendaStatus, Choice};
+use crate::api::config::OutboxConfig;
+use crate::api::outbox::relay::run_once;
+use crate::api::outbox::repository::traits::OutboxRepository;
+use crate::api::outbox::sink::{ChannelSink, Sink};
+use super::{open_agenda, setup, RecordingSink};
+
+fn config(retry_delay_ms: u64) -> OutboxConfig {
+    OutboxConfig {
+        retry_delay_ms,
+        ..Default::default()
+    }
+}
+
+// 테스트 1: 변경마다 이벤트가 변경 순서대로 발행되고, 발행한 이벤트는 다시 가져오지 않음
+#[tokio::test]
+async fn test_mutations_publish_events_in_order() {
+    let (agendas, outbox, users) = setup(1).await;
+    let sink = Arc::new(RecordingSink::default());
+    let sinks: Vec<Arc<dyn Sink>> = vec![sink.clone()];
+
+    let id = open_agenda(&agendas, users[0]).await;
+    agendas.vote(id, users[0], Choice::Agree).await.unwrap();
+    agendas.change_vote(id, users[0], Choice::Disagree).await.unwrap();
+    agendas.retract_vote(id, users[0]).await.unwrap();
+    agendas.update_status(id, AgendaStatus::Open, AgendaStatus::Closed).await.unwrap();
+
+    assert_eq!(run_once(&outbox, &sinks, &config(5000)).await, 5);
+    assert_eq!(run_once(&outbox, &sinks, &config(5000)).await, 0);
+
+    let types: Vec<String> = sink.events().into_iter().map(|(_, event)| event).collect();
+    assert_eq!(types, ["agenda.created", "vote.cast", "vote.changed", "vote.retracted", "agenda.closed"]);
+
+    let published = sink.published.lock().unwrap();
+    assert!(published.windows(2).all(|pair| pair[0].id < pair[1].id));
+    assert_eq!(published[2].payload["previous"], "agree");
+    assert_eq!(published[2].payload["choice"], "disagree");
+    assert_eq!(published[4].payload["status"], "closed");
+}
+
+// 테스트 2: 실패한 변경은 이벤트를 남기지 않음
+#[tokio::test]
+async fn test_rejected_mutation_records_no_event() {
+    let (agendas, outbox, users) = setup(1).await;
+    let sink = Arc::new(RecordingSink::default());
+    let sinks: Vec<Arc<dyn Sink>> = vec![sink.clone()];
+
+    let id = open_agenda(&agendas, users[0]).await;
+    agendas.vote(id, users[0], Choice::Agree).await.unwrap();
+    assert!(agendas.vote(id, users[0], Choice::Disagree).await.is_err()); // 중복 투표
+    assert!(agendas.update_status(id, AgendaStatus::Draft, AgendaStatus::Open).await.unwrap().is_none());
+
+    run_once(&outbox, &sinks, &config(5000)).await;
+
+    let types: Vec<String> = sink.events().into_iter().map(|(_, event)| event).collect();
+    assert_eq!(types, ["agenda.created", "vote.cast"]);
+}
+
+// 테스트 3: 싱크가 실패한 안건은 뒤 이벤트까지 미루고, 다른 안건은 그대로 발행
+#[tokio::test]
+async fn test_failed_agenda_is_retried_in_order() {
+    let (agendas, outbox, users) = setup(2).await;
+    let sink = Arc::new(RecordingSink::default());
+    let sinks: Vec<Arc<dyn Sink>> = vec![sink.clone()];
+
+    let a = open_agenda(&agendas, users[0]).await;
+    let b = open_agenda(&agendas, users[0]).await;
+    agendas.vote(a, users[0], Choice::Agree).await.unwrap();
+    agendas.vote(b, users[1], Choice::Agree).await.unwrap();
+    agendas.vote(a, users[1], Choice::Disagree).await.unwrap();
+
+    *sink.failing.lock().unwrap() = Some(a);
+    run_once(&outbox, &sinks, &config(50)).await;
+    assert!(sink.events().iter().all(|(agenda, _)| *agenda == b));
+    assert_eq!(sink.events().len(), 2);
+
+    // 재시도 간격 전에는 그 안건을 다시 가져오지 않음 (새 이벤트도 뒤에서 기다림)
+    *sink.failing.lock().unwrap() = None;
+    agendas.update_status(a, AgendaStatus::Open, AgendaStatus::Closed).await.unwrap();
+    assert_eq!(run_once(&outbox, &sinks, &config(50)).await, 0);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(run_once(&outbox, &sinks, &config(50)).await, 4);
+
+    let a_events: Vec<String> = sink.events().into_iter().filter(|(agenda, _)| *agenda == a).map(|(_, e)| e).collect();
+    assert_eq!(a_events, ["agenda.created", "vote.cast", "vote.cast", "agenda.closed"]);
+}
+
+// 테스트 4: 임대만 하고 결과를 남기지 못한 이벤트는 임대가 끝나면 다시 발행 (at-least-once)
+#[tokio::test]
+async fn test_unacknowledged_events_are_published_again() {
+    let (agendas, outbox, users) = setup(1).await;
+    let sink = Arc::new(RecordingSink::default());
+    let sinks: Vec<Arc<dyn Sink>> = vec![sink.clone()];
+    open_agenda(&agendas, users[0]).await;
+
+    // 발행 도중 죽은 relay
+    let claimed = outbox.claim(10, Utc::now() + Duration::from_millis(20)).await.unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(run_once(&outbox, &sinks, &config(5000)).await, 0);
+
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(run_once(&outbox, &sinks, &config(5000)).await, 1);
+    assert_eq!(sink.published.lock().unwrap()[0].id, claimed[0].id);
+}
+
+// 테스트 5: 채널 싱크 구독자가 발행된 이벤트를 받음
+#[tokio::test]
+async fn test_channel_sink_delivers_to_subscribers() {
+    let (agendas, outbox, users) = setup(1).await;
+    let channel = Arc::new(ChannelSink::default());
+    let mut receiver = channel.subscribe();
+    let sinks: Vec<Arc<dyn Sink>> = vec![channel];
+
+    let id = open_agenda(&agendas, users[0]).await;
+    run_once(&outbox, &sinks, &config(5000)).await;
+
+    let record = receiver.try_recv().unwrap();
+    assert_eq!((record.agenda_id, record.event_type.as_str()), (id, "agenda.created"));
+    assert_eq!(record.payload["id"], id.to_string());
+}
+
+// 테스트 6: 보관 기간이 지난 발행 완료 이벤트만 삭제
+#[tokio::test]
+async fn test_purge_keeps_pending_events() {
+    let (agendas, outbox, users) = setup(1).await;
+    let sinks: Vec<Arc<dyn Sink>> = vec![];
+
+    let id = open_agenda(&agendas, users[0]).await;
+    run_once(&outbox, &sinks, &config(5000)).await;
+    agendas.vote(id, users[0], Choice::Agree).await.unwrap();
+
+    assert_eq!(outbox.purge_published(Utc::now() - Duration::from_secs(60)).await.unwrap(), 0);
+    assert_eq!(outbox.purge_published(Utc::now() + Duration::from_secs(1)).await.unwrap(), 1);
+    assert_eq!(outbox.claim(10, Utc::now()).await.unwrap().len(), 1);
+}
@@ -0,0 +1,140 @@
+//! 라우터 공유 상태
+//!
+//! 핸들러는 `State<AppState>` 전체 대신 필요한 부분만 꺼내 씁니다 (`FromRef`).
+//! ```ignore
+//! async fn handler(State(users): State<Users>, State(config): State<Arc<Config>>) { ... }
+//! ```
+//! - Repository는 trait object로 보관하므로 테스트에서는 Mock Repository로 바꿔 끼울 수 있습니다.
+
+use std::sync::Arc;
+use axum::extract::FromRef;
+use sqlx::PgPool;
+use super::agenda::{repository::{memory::MemoryAgendaRepository, traits::AgendaRepository, PgAgendaRepository}, CounterBuffer, LiveTally};
+use super::auth::{keys::KeyStore, repository::{memory::MemoryUserRepository, traits::UserRepository, PgUserRepository}};
+use super::config::Config;
+use super::memory::MemoryDb;
+use super::outbox::{repository::{memory::MemoryOutboxRepository, traits::OutboxRepository, PgOutboxRepository}, sink::ChannelSink};
+use super::webhook::repository::{memory::MemoryWebhookRepository, traits::WebhookRepository, PgWebhookRepository};
+
+/// 사용자 Repository (State substate)
+pub type Users = Arc<dyn UserRepository>;
+
+/// 안건 Repository (State substate)
+pub type Agendas = Arc<dyn AgendaRepository>;
+
+/// 웹훅 Repository (State substate)
+pub type Webhooks = Arc<dyn WebhookRepository>;
+
+/// outbox Repository (relay 전용, 기록은 안건 Repository가 함)
+pub type Outbox = Arc<dyn OutboxRepository>;
+
+/// JWT 서명/검증 키 (State substate, 시작 시 설정에서 한 번 읽음)
+pub type Keys = Arc<KeyStore>;
+
+#[derive(Clone)]
+pub struct AppState {
+    pub users: Users,
+    pub agendas: Agendas,
+    pub webhooks: Webhooks,
+    pub outbox: Outbox,
+    pub config: Arc<Config>,
+    pub keys: Keys,
+    pub counters: Arc<CounterBuffer>, // 카운터 쓰기 지연 버퍼 (안건 Repository와 반영 작업이 공유)
+    pub live: Arc<LiveTally>,         // 실시간 집계 구독 채널 (투표 핸들러와 전송 작업이 공유)
+    pub events: Arc<ChannelSink>,     // 도메인 이벤트 채널 (`outbox.sinks`에 channel이 있을 때 relay가 발행)
+}
+
+impl AppState {
+    pub fn new(
+        users: Users,
+        agendas: Agendas,
+        webhooks: Webhooks,
+        outbox: Outbox,
+        config: Arc<Config>,
+        keys: Keys,
+        counters: Arc<CounterBuffer>,
+    ) -> Self {
+        Self {
+            users,
+            agendas,
+            webhooks,
+            outbox,
+            config,
+            keys,
+            counters,
+            live: Arc::new(LiveTally::default()),
+            events: Arc::new(ChannelSink::default()),
+        }
+    }
+
+    /// PostgreSQL Repository로 구성
+    pub fn postgres(pool: PgPool, config: Arc<Config>, keys: Keys) -> Self {
+        let counters = Arc::new(CounterBuffer::new(config.counters.flush_batch_size));
+        Self::new(
+            Arc::new(PgUserRepository::new(&pool)),
+            Arc::new(PgAgendaRepository::new(&pool, &counters)),
+            Arc::new(PgWebhookRepository::new(&pool)),
+            Arc::new(PgOutboxRepository::new(&pool)),
+            config,
+            keys,
+            counters,
+        )
+    }
+
+    /// 메모리 Repository로 구성 (데이터는 프로세스가 끝나면 사라짐)
+    pub fn memory(config: Arc<Config>, keys: Keys) -> Self {
+        let db = Arc::new(MemoryDb::default());
+        let counters = Arc::new(CounterBuffer::new(config.counters.flush_batch_size));
+        Self::new(
+            Arc::new(MemoryUserRepository::new(&db)),
+            Arc::new(MemoryAgendaRepository::new(&db)),
+            Arc::new(MemoryWebhookRepository::new(&db)),
+            Arc::new(MemoryOutboxRepository::new(&db)),
+            config,
+            keys,
+            counters,
+        )
+    }
+}
+
+impl FromRef<AppState> for Users {
+    fn from_ref(state: &AppState) -> Self {
+        state.users.clone()
+    }
+}
+
+impl FromRef<AppState> for Agendas {
+    fn from_ref(state: &AppState) -> Self {
+        state.agendas.clone()
+    }
+}
+
+impl FromRef<AppState> for Webhooks {
+    fn from_ref(state: &AppState) -> Self {
+        state.webhooks.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<Config> {
+    fn from_ref(state: &AppState) -> Self {
+        state.config.clone()
+    }
+}
+
+impl FromRef<AppState> for Keys {
+    fn from_ref(state: &AppState) -> Self {
+        state.keys.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<CounterBuffer> {
+    fn from_ref(state: &AppState) -> Self {
+        state.counters.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<LiveTally> {
+    fn from_ref(state: &AppState) -> Self {
+        state.live.clone()
+    }
+}
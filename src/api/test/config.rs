@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use crate::api::config::{Backend, Config, ConfigError, Environment, SinkKind};
+
+const DB_URL: &str = "postgres://localhost/vote_db";
+
+/// 고정 환경 변수로 설정 만들기
+fn load(file: Option<&str>, vars: &[(&str, &str)]) -> Result<Config, ConfigError> {
+    let env: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    Config::from_sources(file, |key| env.get(key).cloned())
+}
+
+fn message(result: Result<Config, ConfigError>) -> String {
+    result.expect_err("config should be rejected").to_string()
+}
+
+#[test]
+fn test_defaults_with_database_url() {
+    let config = load(None, &[("DATABASE_URL", DB_URL)]).unwrap();
+
+    assert_eq!(config.environment, Environment::Development);
+    assert_eq!(config.server.bind_addr.to_string(), "0.0.0.0:3000");
+    assert_eq!(config.database.url, DB_URL);
+    assert_eq!(config.database.max_connections, 5);
+    assert_eq!(config.counters.flush_batch_size, 1000);
+    assert!(config.cors.allowed_origins.is_empty());
+    assert!(config.features.signup && config.features.scheduler && config.features.reconciler);
+}
+
+#[test]
+fn test_missing_database_url() {
+    assert!(message(load(None, &[])).contains("DATABASE_URL"));
+}
+
+#[test]
+fn test_env_overrides_file() {
+    let file = r#"
+        environment = "production"
+
+        [server]
+        bind_addr = "127.0.0.1:8080"
+
+        [database]
+        url = "postgres://file/vote_db"
+        max_connections = 20
+
+        [cors]
+        allowed_origins = ["https://vote.example.com"]
+
+        [outbox]
+        sinks = ["log"]
+
+        [features]
+        signup = false
+    "#;
+    let config = load(
+        Some(file),
+        &[
+            ("DB_MAX_CONNECTIONS", "50"),
+            ("CORS_ALLOWED_ORIGINS", "https://a.example.com, https://b.example.com"),
+            ("OUTBOX_SINKS", "webhook, channel"),
+        ],
+    )
+    .unwrap();
+
+    assert!(config.is_production());
+    assert_eq!(config.server.bind_addr.to_string(), "127.0.0.1:8080");
+    assert_eq!(config.database.url, "postgres://file/vote_db");
+    assert_eq!(config.database.max_connections, 50); // 환경 변수 우선
+    assert_eq!(config.cors.allowed_origins, vec!["https://a.example.com", "https://b.example.com"]);
+    assert_eq!(config.outbox.sinks, vec![SinkKind::Webhook, SinkKind::Channel]);
+    assert!(!config.features.signup);
+}
+
+#[test]
+fn test_invalid_env_value_is_rejected() {
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("DB_MAX_CONNECTIONS", "many")]));
+    assert!(error.contains("DB_MAX_CONNECTIONS"), "{}", error);
+
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("FEATURE_SIGNUP", "yes")]));
+    assert!(error.contains("FEATURE_SIGNUP"), "{}", error);
+
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("OUTBOX_SINKS", "log,kafka")]));
+    assert!(error.contains("kafka"), "{}", error);
+}
+
+#[test]
+fn test_unknown_file_key_is_rejected() {
+    let file = "[database]\nurl = \"postgres://file/vote_db\"\nmax_conections = 3\n";
+    let error = message(load(Some(file), &[]));
+    assert!(error.contains("max_conections"), "{}", error);
+}
+
+#[test]
+fn test_range_validation() {
+    let error = message(load(
+        None,
+        &[("DATABASE_URL", DB_URL), ("DB_MIN_CONNECTIONS", "10"), ("DB_MAX_CONNECTIONS", "5")],
+    ));
+    assert!(error.contains("min_connections"), "{}", error);
+
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("COUNTER_FLUSH_INTERVAL_MS", "0")]));
+    assert!(error.contains("flush_interval_ms"), "{}", error);
+
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("LIVE_UPDATE_INTERVAL_MS", "0")]));
+    assert!(error.contains("update_interval_ms"), "{}", error);
+
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("WEBHOOK_MAX_ATTEMPTS", "0")]));
+    assert!(error.contains("max_attempts"), "{}", error);
+
+    let error = message(load(
+        None,
+        &[("DATABASE_URL", DB_URL), ("WEBHOOK_INITIAL_BACKOFF_SECS", "60"), ("WEBHOOK_MAX_BACKOFF_SECS", "30")],
+    ));
+    assert!(error.contains("max_backoff_secs"), "{}", error);
+
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("WEBHOOK_TIMEOUT_SECS", "301")]));
+    assert!(error.contains("timeout_secs"), "{}", error);
+
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("OUTBOX_RETENTION_HOURS", "18446744073709551615")]));
+    assert!(error.contains("retention_hours"), "{}", error);
+}
+
+#[test]
+fn test_cors_origin_validation() {
+    let error = message(load(None, &[("DATABASE_URL", DB_URL), ("CORS_ALLOWED_ORIGINS", "vote.example.com")]));
+    assert!(error.contains("vote.example.com"), "{}", error);
+
+    // `*`는 개발 모드에서만 허용
+    assert!(load(None, &[("DATABASE_URL", DB_URL), ("CORS_ALLOWED_ORIGINS", "*")]).is_ok());
+    let error = message(load(
+        None,
+        &[("DATABASE_URL", DB_URL), ("CORS_ALLOWED_ORIGINS", "*"), ("APP_ENV", "production")],
+    ));
+    assert!(error.contains("production"), "{}", error);
+}
+
+#[test]
+fn test_memory_backend_needs_no_database_url() {
+    let config = load(None, &[("DATABASE_BACKEND", "memory")]).unwrap();
+    assert_eq!(config.database.backend, Backend::Memory);
+
+    let error = message(load(None, &[("DATABASE_BACKEND", "sqlite")]));
+    assert!(error.contains("DATABASE_BACKEND"), "{}", error);
+}
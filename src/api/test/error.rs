@@ -0,0 +1,122 @@
+use crate::api::error::{AppError, ErrorCode};
+use crate::api::validation::FieldError;
+use axum::{body::to_bytes, http::{header, StatusCode}, response::IntoResponse};
+use sqlx::error::{DatabaseError, ErrorKind};
+
+/// 제약 이름과 종류를 지정할 수 있는 가짜 DB 에러
+#[derive(Debug)]
+struct FakeDbError {
+    kind: ErrorKind,
+    constraint: &'static str,
+}
+
+impl std::fmt::Display for FakeDbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "violates constraint \"{}\"", self.constraint)
+    }
+}
+
+impl std::error::Error for FakeDbError {}
+
+impl DatabaseError for FakeDbError {
+    fn message(&self) -> &str {
+        "constraint violation"
+    }
+
+    fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn constraint(&self) -> Option<&str> {
+        Some(self.constraint)
+    }
+
+    fn kind(&self) -> ErrorKind {
+        // ErrorKind는 Clone이 아니므로 다시 만들어서 반환
+        match self.kind {
+            ErrorKind::UniqueViolation => ErrorKind::UniqueViolation,
+            ErrorKind::ForeignKeyViolation => ErrorKind::ForeignKeyViolation,
+            ErrorKind::CheckViolation => ErrorKind::CheckViolation,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+fn db_error(kind: ErrorKind, constraint: &'static str) -> AppError {
+    AppError::from(sqlx::Error::Database(Box::new(FakeDbError { kind, constraint })))
+}
+
+async fn body_json(error: AppError) -> (StatusCode, String, serde_json::Value) {
+    let response = error.into_response();
+    let status = response.status();
+    let content_type = response.headers()[header::CONTENT_TYPE].to_str().unwrap().to_string();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, serde_json::from_slice(&bytes).unwrap())
+}
+
+// 테스트 1: unique 위반은 제약 이름으로 전용 코드 분류
+#[test]
+fn test_unique_violation_classified_by_constraint() {
+    assert!(matches!(db_error(ErrorKind::UniqueViolation, "users_username_key"), AppError::UsernameTaken(_)));
+    assert!(matches!(db_error(ErrorKind::UniqueViolation, "uk_vote_user_agenda"), AppError::AlreadyVoted(_)));
+    assert!(matches!(db_error(ErrorKind::UniqueViolation, "uk_refresh_token_hash"), AppError::Conflict(_)));
+}
+
+// 테스트 2: 외래 키 위반 / check 위반 / 행 없음 / 그 외
+#[test]
+fn test_other_database_errors_classified() {
+    assert!(matches!(db_error(ErrorKind::ForeignKeyViolation, "votes_user_id_fkey"), AppError::Conflict(_)));
+    assert!(matches!(db_error(ErrorKind::CheckViolation, "ck_agenda_window"), AppError::BadRequest(..)));
+    assert!(matches!(AppError::from(sqlx::Error::RowNotFound), AppError::NotFound(_)));
+    assert!(matches!(AppError::from(sqlx::Error::PoolTimedOut), AppError::InternalServerError(_)));
+}
+
+// 테스트 3: problem+json 응답 - type / title / status / detail / code
+#[tokio::test]
+async fn test_problem_json_body() {
+    let (status, content_type, body) = body_json(AppError::AlreadyVoted("Already voted on this agenda".to_string())).await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(content_type, "application/problem+json");
+    assert_eq!(body["type"], "/problems/already-voted");
+    assert_eq!(body["title"], "Conflict");
+    assert_eq!(body["status"], 409);
+    assert_eq!(body["detail"], "Already voted on this agenda");
+    assert_eq!(body["code"], "ALREADY_VOTED");
+    assert!(body.get("errors").is_none());
+}
+
+// 테스트 4: 필드별 상세가 있으면 VALIDATION_FAILED + errors
+#[tokio::test]
+async fn test_problem_json_field_errors() {
+    let error = AppError::BadRequest(
+        "Invalid request".to_string(),
+        vec![FieldError::new("username", "too_short", "must not be empty")],
+    );
+    assert_eq!(error.code(), ErrorCode::ValidationFailed);
+
+    let (status, _, body) = body_json(error).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "VALIDATION_FAILED");
+    assert_eq!(body["errors"][0]["field"], "username");
+    assert_eq!(body["errors"][0]["code"], "too_short");
+}
+
+// 테스트 5: 내부 에러는 원인을 노출하지 않음
+#[tokio::test]
+async fn test_internal_error_hides_cause() {
+    let (status, _, body) = body_json(AppError::from(sqlx::Error::PoolTimedOut)).await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    assert_eq!(body["code"], "INTERNAL_ERROR");
+    assert_eq!(body["detail"], "Database error occurred");
+}
@@ -0,0 +1,113 @@
+//! HTTP 통합 테스트 하네스
+//!
+//! 주입한 `AppState`로 `api::app` 라우터 전체를 만들고 `oneshot`으로 요청을 보냅니다.
+//! - 라우팅, Extractor, JSON 직렬화, `AppError` → 상태 코드 변환까지 실제 서버와 같은 경로를 거칩니다.
+//! - `TestApp::memory()`는 메모리 저장소를 써서 요청 간 상태가 이어지는 흐름(가입 → 로그인 → 투표)을 검증합니다.
+//! - `TestApp::mock()`은 Mock Repository로 특정 응답/에러를 흉내냅니다.
+
+use std::sync::Arc;
+use axum::{body::{to_bytes, Body}, http::{header, HeaderMap, Method, Request, StatusCode}, response::Response, Router};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+use uuid::Uuid;
+use crate::api::{app, AppState, Config};
+use crate::api::agenda::{test::MockAgendaRepository, CounterBuffer};
+use crate::api::auth::{keys::KeyStore, test::MockUserRepository};
+use crate::api::outbox::test::MockOutboxRepository;
+use crate::api::webhook::test::MockWebhookRepository;
+
+pub struct TestApp {
+    router: Router,
+}
+
+/// 응답 (본문이 JSON이 아니면 `body`는 Null)
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Value,
+}
+
+impl TestApp {
+    pub fn new(state: AppState) -> Self {
+        Self { router: app(state) }
+    }
+
+    /// 메모리 저장소 (기본 설정)
+    pub fn memory() -> Self {
+        Self::new(AppState::memory(Arc::new(Config::default()), Arc::new(KeyStore::development())))
+    }
+
+    /// Mock Repository (기본 설정)
+    pub fn mock(users: MockUserRepository, agendas: MockAgendaRepository) -> Self {
+        Self::mock_with(users, agendas, Config::default())
+    }
+
+    pub fn mock_with(users: MockUserRepository, agendas: MockAgendaRepository, config: Config) -> Self {
+        let counters = Arc::new(CounterBuffer::new(config.counters.flush_batch_size));
+        Self::new(AppState::new(
+            Arc::new(users),
+            Arc::new(agendas),
+            Arc::new(MockWebhookRepository::default()),
+            Arc::new(MockOutboxRepository),
+            Arc::new(config),
+            Arc::new(KeyStore::development()),
+            counters,
+        ))
+    }
+
+    /// 응답 본문을 읽지 않고 반환 (SSE처럼 끝나지 않는 응답)
+    pub async fn open(&self, request: Request<Body>) -> Response {
+        self.router.clone().oneshot(request).await.unwrap()
+    }
+
+    /// 요청을 그대로 보냄 (헤더를 직접 구성해야 할 때)
+    pub async fn request(&self, request: Request<Body>) -> TestResponse {
+        let response = self.open(request).await;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        TestResponse {
+            status,
+            headers,
+            body: serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        }
+    }
+
+    /// Bearer 토큰과 JSON 본문을 붙여 요청하고 (상태 코드, JSON 본문) 반환
+    pub async fn send(&self, method: Method, uri: &str, token: Option<&str>, body: Option<Value>) -> (StatusCode, Value) {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let request = match body {
+            Some(body) => request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .unwrap();
+
+        let response = self.request(request).await;
+        (response.status, response.body)
+    }
+
+    /// 가입 후 로그인해서 Access Token 반환
+    pub async fn sign_up_and_login(&self, username: &str) -> String {
+        let credentials = json!({ "username": username, "password": "password123" });
+
+        let (status, _) = self.send(Method::POST, "/auth/signup", None, Some(credentials.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = self.send(Method::POST, "/auth/login", None, Some(credentials)).await;
+        assert_eq!(status, StatusCode::OK);
+        body["token"].as_str().unwrap().to_string()
+    }
+
+    /// 안건 생성 후 ID 반환
+    pub async fn create_agenda(&self, token: &str, body: Value) -> Uuid {
+        let (status, body) = self.send(Method::POST, "/agendas", Some(token), Some(body)).await;
+        assert_eq!(status, StatusCode::OK, "{}", body);
+        body["id"].as_str().unwrap().parse().unwrap()
+    }
+}
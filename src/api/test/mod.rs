@@ -0,0 +1,10 @@
+#[cfg(test)]
+pub mod error;
+#[cfg(test)]
+pub mod config;
+#[cfg(test)]
+pub mod harness;
+#[cfg(test)]
+pub mod state;
+#[cfg(test)]
+pub mod http;
@@ -0,0 +1,123 @@
+//! AppState에 Mock Repository를 넣어 라우터 전체(Extractor → Handler → Service)를 호출하는 테스트
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+use uuid::Uuid;
+use super::harness::TestApp;
+use crate::api::Config;
+use crate::api::agenda::test::{open_agenda, MockAgendaRepository};
+use crate::api::auth::{jwt, keys::KeyStore, role::Role, test::{user_with_password, MockUserRepository}};
+
+fn token(role: Role) -> String {
+    jwt::generate_token(&KeyStore::development(), Uuid::new_v4(), "alice".to_string(), role, Uuid::new_v4()).unwrap()
+}
+
+#[tokio::test]
+async fn test_signup_through_router() {
+    let app = TestApp::mock(MockUserRepository::default(), MockAgendaRepository::default());
+    let body = json!({ "username": "alice", "password": "password123" });
+
+    let (status, body) = app.send(Method::POST, "/auth/signup", None, Some(body)).await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["username"], "alice");
+}
+
+#[tokio::test]
+async fn test_signup_taken_username_through_router() {
+    let users = MockUserRepository {
+        should_exist: true,
+        ..Default::default()
+    };
+    let body = json!({ "username": "alice", "password": "password123" });
+
+    let (status, body) = TestApp::mock(users, MockAgendaRepository::default()).send(Method::POST, "/auth/signup", None, Some(body)).await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "USERNAME_TAKEN");
+}
+
+#[tokio::test]
+async fn test_signup_disabled_by_config() {
+    let mut config = Config::default();
+    config.features.signup = false;
+    let app = TestApp::mock_with(MockUserRepository::default(), MockAgendaRepository::default(), config);
+    let body = json!({ "username": "alice", "password": "password123" });
+
+    let (status, body) = app.send(Method::POST, "/auth/signup", None, Some(body)).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["detail"], "Sign-up is disabled");
+}
+
+#[tokio::test]
+async fn test_login_through_router() {
+    let users = MockUserRepository {
+        find_result: Some(user_with_password("alice", "password123")),
+        ..Default::default()
+    };
+    let body = json!({ "username": "alice", "password": "password123" });
+
+    let (status, body) = TestApp::mock(users, MockAgendaRepository::default()).send(Method::POST, "/auth/login", None, Some(body)).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let claims = jwt::verify_token(&KeyStore::development(), body["token"].as_str().unwrap()).unwrap();
+    assert_eq!(claims.username, "alice");
+}
+
+#[tokio::test]
+async fn test_vote_through_router() {
+    let agenda = open_agenda();
+    let uri = format!("/agendas/{}/vote", agenda.id);
+    let app = TestApp::mock(MockUserRepository::default(), MockAgendaRepository::with_agenda(agenda));
+
+    let (status, body) = app.send(Method::POST, &uri, Some(&token(Role::User)), Some(json!({ "is_agree": true }))).await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["agree_count"], 1);
+}
+
+#[tokio::test]
+async fn test_duplicate_vote_through_router() {
+    let agenda = open_agenda();
+    let uri = format!("/agendas/{}/vote", agenda.id);
+    let agendas = MockAgendaRepository {
+        already_voted: true,
+        ..MockAgendaRepository::with_agenda(agenda)
+    };
+
+    let (status, body) = TestApp::mock(MockUserRepository::default(), agendas).send(Method::POST, &uri, Some(&token(Role::User)), Some(json!({ "is_agree": true }))).await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "ALREADY_VOTED");
+}
+
+#[tokio::test]
+async fn test_revoked_session_through_router() {
+    // 토큰 서명은 유효하지만 사용자 Repository에서 세션이 폐기됨
+    let users = MockUserRepository {
+        session_revoked: true,
+        ..Default::default()
+    };
+    let body = json!({ "title": "Agenda" });
+
+    let (status, body) = TestApp::mock(users, MockAgendaRepository::default()).send(Method::POST, "/agendas", Some(&token(Role::User)), Some(body)).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "UNAUTHORIZED");
+}
+
+#[tokio::test]
+async fn test_admin_route_through_router() {
+    let agenda = open_agenda();
+    let uri = format!("/agendas/{}/force-close", agenda.id);
+
+    let app = TestApp::mock(MockUserRepository::default(), MockAgendaRepository::with_agenda(agenda.clone()));
+    let (status, _) = app.send(Method::POST, &uri, Some(&token(Role::Moderator)), None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let app = TestApp::mock(MockUserRepository::default(), MockAgendaRepository::with_agenda(agenda));
+    let (status, body) = app.send(Method::POST, &uri, Some(&token(Role::Admin)), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "closed");
+}
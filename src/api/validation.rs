@@ -0,0 +1,218 @@
+//! 요청 DTO 입력 검증
+//!
+//! DTO마다 `Validate::rules`에 필드별 규칙을 선언하면 `validated()`가
+//! 정규화(앞뒤 공백 제거, 유니코드 정규화)를 먼저 적용한 뒤 규칙을 검사합니다.
+//! - 실패한 필드를 모두 모아 `AppError::BadRequest`의 필드별 상세로 반환합니다.
+//! - 한 필드는 첫 번째로 실패한 규칙 하나만 보고합니다.
+//! - 길이는 정규화 후 문자(char) 수 기준입니다.
+//!
+//! ```ignore
+//! impl Validate for SignupRequest {
+//!     fn rules(&mut self, v: &mut Validator) {
+//!         v.text("username", &mut self.username).trim().nfkc().length(3, 32).chars(is_username_char, "...");
+//!     }
+//! }
+//! ```
+
+use std::collections::HashSet;
+use serde::Serialize;
+use unicode_normalization::UnicodeNormalization;
+use super::error::AppError;
+
+/// 필드 하나의 검증 실패
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: &'static str, // too_short / too_long / invalid_characters / duplicate ...
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// 필드별 규칙 선언 (정규화 → 검사 순서)
+pub trait Validate: Sized {
+    fn rules(&mut self, v: &mut Validator);
+
+    /// 정규화된 값으로 바꾼 뒤 검사 (실패 시 400 BadRequest + 필드별 상세)
+    fn validated(mut self) -> Result<Self, AppError> {
+        let mut validator = Validator::default();
+        self.rules(&mut validator);
+        validator.finish()?;
+        Ok(self)
+    }
+}
+
+/// 검증 실패 수집기
+#[derive(Default)]
+pub struct Validator {
+    errors: Vec<FieldError>,
+}
+
+impl Validator {
+    /// 문자열 필드 규칙 시작
+    pub fn text<'a>(&'a mut self, field: impl Into<String>, value: &'a mut String) -> Text<'a> {
+        Text {
+            field: field.into(),
+            value,
+            errors: &mut self.errors,
+            failed: false,
+        }
+    }
+
+    /// 문자열 목록 필드 규칙 - 개수 제한 후 각 항목은 `field[i]`로 검사
+    pub fn list<'a>(&'a mut self, field: &'a str, values: &'a mut [String]) -> List<'a> {
+        List {
+            field,
+            values,
+            validator: self,
+            failed: false,
+        }
+    }
+
+    /// 선언적 규칙으로 표현하기 어려운 검사 결과 추가
+    pub fn add(&mut self, error: FieldError) {
+        self.errors.push(error);
+    }
+
+    fn finish(self) -> Result<(), AppError> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+        Err(AppError::BadRequest("Invalid request".to_string(), self.errors))
+    }
+}
+
+/// 문자열 필드 하나의 규칙 체인
+pub struct Text<'a> {
+    field: String,
+    value: &'a mut String,
+    errors: &'a mut Vec<FieldError>,
+    failed: bool,
+}
+
+impl Text<'_> {
+    /// 앞뒤 공백 제거
+    pub fn trim(self) -> Self {
+        let trimmed = self.value.trim();
+        if trimmed.len() != self.value.len() {
+            *self.value = trimmed.to_string();
+        }
+        self
+    }
+
+    /// NFC 정규화 (조합형/완성형 한글 등 같은 글자를 같은 바이트로)
+    pub fn nfc(self) -> Self {
+        *self.value = self.value.nfc().collect();
+        self
+    }
+
+    /// NFKC 정규화 (NFC + 전각 문자 등 호환 문자를 기본 문자로 - 식별자/비밀번호용)
+    pub fn nfkc(self) -> Self {
+        *self.value = self.value.nfkc().collect();
+        self
+    }
+
+    /// 문자 수 `min..=max`
+    pub fn length(mut self, min: usize, max: usize) -> Self {
+        let len = self.value.chars().count();
+        if len < min {
+            let message = if min == 1 {
+                "must not be empty".to_string()
+            } else {
+                format!("must be at least {} characters", min)
+            };
+            self.fail("too_short", message);
+        } else if len > max {
+            self.fail("too_long", format!("must be at most {} characters", max));
+        }
+        self
+    }
+
+    /// 허용 문자 검사 (`allowed`: 허용 문자 설명)
+    pub fn chars(mut self, is_allowed: fn(char) -> bool, allowed: &str) -> Self {
+        if !self.value.chars().all(is_allowed) {
+            self.fail("invalid_characters", format!("may only contain {}", allowed));
+        }
+        self
+    }
+
+    /// 호스트가 있는 http(s) 절대 URL
+    pub fn http_url(mut self) -> Self {
+        let valid = self.value.parse::<axum::http::Uri>().is_ok_and(|uri| {
+            matches!(uri.scheme_str(), Some("http" | "https")) && uri.host().is_some_and(|host| !host.is_empty())
+        });
+        if !valid {
+            self.fail("invalid_url", "must be an absolute http(s) URL".to_string());
+        }
+        self
+    }
+
+    fn fail(&mut self, code: &'static str, message: String) {
+        if !self.failed {
+            self.failed = true;
+            self.errors.push(FieldError::new(self.field.clone(), code, message));
+        }
+    }
+}
+
+/// 문자열 목록 필드의 규칙 체인
+pub struct List<'a> {
+    field: &'a str,
+    values: &'a mut [String],
+    validator: &'a mut Validator,
+    failed: bool,
+}
+
+impl List<'_> {
+    /// 항목 수 `min..=max` (비어 있는 목록은 허용 - 선택 필드)
+    pub fn count(mut self, min: usize, max: usize) -> Self {
+        let len = self.values.len();
+        if len > 0 && !(min..=max).contains(&len) {
+            self.fail("invalid_count", format!("must have {} to {} items", min, max));
+        }
+        self
+    }
+
+    /// 각 항목에 같은 문자열 규칙 적용
+    pub fn each(self, rules: impl Fn(Text<'_>) -> Text<'_>) -> Self {
+        for (i, value) in self.values.iter_mut().enumerate() {
+            rules(self.validator.text(format!("{}[{}]", self.field, i), value));
+        }
+        self
+    }
+
+    /// 정규화 후 중복 금지
+    pub fn unique(mut self) -> Self {
+        let mut seen = HashSet::new();
+        let duplicate = self.values.iter().any(|value| !seen.insert(value.as_str()));
+        if duplicate {
+            self.fail("duplicate", "items must be unique".to_string());
+        }
+        self
+    }
+
+    fn fail(&mut self, code: &'static str, message: String) {
+        if !self.failed {
+            self.failed = true;
+            self.validator.add(FieldError::new(self.field, code, message));
+        }
+    }
+}
+
+/// 사용자 이름 허용 문자 - 문자(한글 포함), 숫자, `_`, `-`, `.`
+pub fn is_username_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+/// 제목/라벨 허용 문자 - 제어 문자(줄바꿈, 탭 등)만 금지
+pub fn is_printable(c: char) -> bool {
+    !c.is_control()
+}
@@ -0,0 +1,157 @@
+//! 웹훅 전송 작업
+//!
+//! `poll_interval` 마다 시도할 때가 된 전송을 대기열에서 가져와 동시에 보냅니다.
+//! - 2xx 응답 → delivered
+//! - 그 외 응답 / 연결 실패 / 시간 초과 → 지수 백오프 후 재시도, `max_attempts`번 실패하면 dead
+//! - 대기열이 DB에 있으므로 서버가 재시작해도 남은 전송과 재시도 일정이 유지됩니다.
+//! - 내부망 주소로는 보내지 않고(`target`), 리다이렉트는 따라가지 않습니다 (3xx도 실패로 기록).
+
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use chrono::Utc;
+use futures_util::future::join_all;
+use reqwest::{header, redirect::Policy, Client, Url};
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use crate::api::config::WebhookConfig;
+use crate::api::state::Webhooks;
+use super::repository::{traits::WebhookRepository, AttemptResult, DeliveryUpdate, DueDelivery};
+use super::{signature, target};
+
+/// 한 번에 가져오는 전송 수
+const BATCH_SIZE: i64 = 50;
+
+/// 전송 요청의 User-Agent
+const USER_AGENT: &str = concat!("vote-backend-webhooks/", env!("CARGO_PKG_VERSION"));
+
+/// 백그라운드 전송 작업 시작
+pub fn spawn(repo: Webhooks, config: WebhookConfig) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let client = client(&config);
+        let mut ticker = tokio::time::interval(config.poll_interval());
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            // 한 묶음을 꽉 채웠으면 남은 전송이 더 있을 수 있으므로 바로 다음 묶음 처리
+            while run_once(repo.as_ref(), &client, &config).await == BATCH_SIZE as usize {}
+        }
+    })
+}
+
+/// 전송용 HTTP 클라이언트 (요청 제한 시간 포함, 리다이렉트 안 따라감, 내부망 주소로 연결 안 함)
+pub fn client(config: &WebhookConfig) -> Client {
+    let builder = Client::builder()
+        .timeout(config.timeout())
+        .user_agent(USER_AGENT)
+        .redirect(Policy::none());
+    let builder = if config.allow_private_targets {
+        builder
+    } else {
+        builder.dns_resolver(Arc::new(target::PublicResolver))
+    };
+    builder.build().expect("HTTP client configuration is valid")
+}
+
+/// 전송 1회 실행 - 처리한 전송 수 반환 (DB 에러는 다음 주기에 다시 시도하므로 로그만 남김)
+pub async fn run_once<R: WebhookRepository + ?Sized>(repo: &R, client: &Client, config: &WebhookConfig) -> usize {
+    // 임대 시간은 요청 제한 시간보다 길어야 전송 중인 항목을 다른 작업이 다시 가져가지 않음
+    let lease_until = Utc::now() + config.timeout() * 2;
+    let due = match repo.claim_due(BATCH_SIZE, lease_until).await {
+        Ok(due) => due,
+        Err(e) => {
+            eprintln!("❌ Webhook queue read failed: {}", e);
+            return 0;
+        }
+    };
+
+    let results = join_all(due.iter().map(|delivery| deliver(client, delivery, config))).await;
+    for (delivery, result) in due.iter().zip(results) {
+        let update = next_update(delivery.attempts + 1, &result, config);
+        if update == DeliveryUpdate::Dead {
+            eprintln!("💀 Webhook delivery {} moved to dead letter after {} attempts", delivery.id, delivery.attempts + 1);
+        }
+        if let Err(e) = repo.record_attempt(delivery.id, &result, update).await {
+            eprintln!("❌ Webhook attempt record failed for {}: {}", delivery.id, e);
+        }
+    }
+    due.len()
+}
+
+/// `attempt`번째(1부터) 실패 후 다음 재시도까지 대기 시간
+/// - `initial_backoff_secs * 2^(attempt - 1)`, 최대 `max_backoff_secs`
+pub fn retry_delay(attempt: u32, config: &WebhookConfig) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(32);
+    let secs = config.initial_backoff_secs.saturating_mul(1 << exponent);
+    Duration::from_secs(secs.min(config.max_backoff_secs))
+}
+
+/// 시도 결과 → 전송 상태 변경
+fn next_update(attempt: i32, result: &AttemptResult, config: &WebhookConfig) -> DeliveryUpdate {
+    if result.error.is_none() {
+        return DeliveryUpdate::Delivered;
+    }
+
+    let attempt = attempt.max(1) as u32;
+    if attempt >= config.max_attempts {
+        return DeliveryUpdate::Dead;
+    }
+    DeliveryUpdate::Retry {
+        at: Utc::now() + retry_delay(attempt, config),
+    }
+}
+
+/// 서명한 요청 1회 전송
+async fn deliver(client: &Client, delivery: &DueDelivery, config: &WebhookConfig) -> AttemptResult {
+    // IP 주소로 쓴 호스트는 DNS를 거치지 않으므로 리졸버 대신 여기서 거름
+    if !config.allow_private_targets && !literal_host_allowed(&delivery.url) {
+        return AttemptResult {
+            status_code: None,
+            error: Some("Target address is not allowed".to_string()),
+            duration_ms: 0,
+        };
+    }
+
+    let body = delivery.payload.to_string().into_bytes();
+    let timestamp = Utc::now().timestamp();
+    let started = Instant::now();
+
+    let response = client
+        .post(&delivery.url)
+        .header(header::CONTENT_TYPE, "application/json")
+        .header("X-Webhook-Id", delivery.id.to_string())
+        .header("X-Webhook-Event", &delivery.event_type)
+        .header("X-Webhook-Timestamp", timestamp.to_string())
+        .header("X-Webhook-Signature", signature::sign(&delivery.secret, timestamp, &body))
+        .body(body)
+        .send()
+        .await;
+    let duration_ms = started.elapsed().as_millis().min(i32::MAX as u128) as i32;
+
+    match response {
+        Ok(response) if response.status().is_success() => AttemptResult {
+            status_code: Some(response.status().as_u16() as i32),
+            error: None,
+            duration_ms,
+        },
+        Ok(response) => AttemptResult {
+            status_code: Some(response.status().as_u16() as i32),
+            error: Some(format!("Unexpected status {}", response.status())),
+            duration_ms,
+        },
+        Err(e) => AttemptResult {
+            status_code: None,
+            error: Some(if e.is_timeout() { "Request timed out".to_string() } else { e.to_string() }),
+            duration_ms,
+        },
+    }
+}
+
+/// URL 호스트가 IP 주소면 허용된 주소인지 (도메인은 `PublicResolver`가 확인)
+fn literal_host_allowed(url: &str) -> bool {
+    let Ok(url) = Url::parse(url) else {
+        return false;
+    };
+    let host = url.host_str().unwrap_or_default().trim_start_matches('[').trim_end_matches(']');
+    host.parse::<IpAddr>().map_or(true, target::is_allowed)
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::api::validation::{Validate, Validator};
+use super::repository::{AttemptEntity, DeliveryStatus, SubscriptionEntity, WebhookEvent};
+
+/// URL 최대 길이
+const URL_MAX_LEN: usize = 2048;
+
+// [Request] 웹훅 구독 (POST /webhooks)
+#[derive(Deserialize, Debug, Default)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub agenda_id: Option<Uuid>, // 없으면 모든 안건
+    #[serde(default)]
+    pub events: Vec<WebhookEvent>, // 비어 있으면 모든 이벤트
+}
+
+impl Validate for CreateWebhookRequest {
+    fn rules(&mut self, v: &mut Validator) {
+        v.text("url", &mut self.url).trim().length(1, URL_MAX_LEN).http_url();
+    }
+}
+
+// [Response] 웹훅 구독 - secret은 등록 응답에서만 포함
+#[derive(Serialize, Debug)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub url: String,
+    pub agenda_id: Option<Uuid>,
+    pub events: Vec<WebhookEvent>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl WebhookResponse {
+    pub fn from_entity(subscription: SubscriptionEntity, with_secret: bool) -> Self {
+        Self {
+            id: subscription.id,
+            url: subscription.url,
+            agenda_id: subscription.agenda_id,
+            events: subscription.events,
+            created_at: subscription.created_at,
+            secret: with_secret.then_some(subscription.secret),
+        }
+    }
+}
+
+// [Query] 최근 전송 시도 (GET /webhooks/:id/attempts?limit=...)
+#[derive(Deserialize)]
+pub struct ListAttemptsQuery {
+    pub limit: Option<i64>,
+}
+
+// [Response] 전송 시도 1회
+#[derive(Serialize, Debug)]
+pub struct AttemptResponse {
+    pub delivery_id: Uuid,
+    pub event: String,
+    pub delivery_status: DeliveryStatus, // 전송의 현재 상태 (pending이면 재시도 예정)
+    pub attempted_at: DateTime<Utc>,
+    pub status_code: Option<i32>,        // 응답을 받지 못했으면 null
+    pub error: Option<String>,
+    pub duration_ms: i32,
+}
+
+impl From<AttemptEntity> for AttemptResponse {
+    fn from(attempt: AttemptEntity) -> Self {
+        Self {
+            delivery_id: attempt.delivery_id,
+            event: attempt.event_type,
+            delivery_status: attempt.delivery_status,
+            attempted_at: attempt.attempted_at,
+            status_code: attempt.status_code,
+            error: attempt.error,
+            duration_ms: attempt.duration_ms,
+        }
+    }
+}
+
+// [Payload] 수신자에게 보내는 본문
+#[derive(Serialize, Debug)]
+pub struct WebhookPayload<T: Serialize> {
+    pub id: i64, // outbox 이벤트 id (다시 전송돼도 같음 - closed와 decided는 같은 id라 중복 키는 (id, event))
+    pub event: WebhookEvent,
+    pub agenda_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub data: T, // agenda.*: 안건, agenda.decided: 가결 판정
+}
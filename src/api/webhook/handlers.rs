@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use axum::{extract::{Path, State}, http::StatusCode};
+use uuid::Uuid;
+use crate::api::auth::jwt::Claims;
+use crate::api::config::Config;
+use crate::api::error::AppError;
+use crate::api::extract::{Json, Query};
+use crate::api::state::Webhooks;
+use super::dtos::{AttemptResponse, CreateWebhookRequest, ListAttemptsQuery, WebhookResponse};
+use super::service;
+
+/// 웹훅 구독 핸들러 (POST /webhooks)
+/// - 성공: 201 Created + 서명 키 (이 응답에서만 공개)
+pub async fn create_webhook(
+    State(repo): State<Webhooks>,
+    State(config): State<Arc<Config>>,
+    claims: Claims,
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<WebhookResponse>), AppError> {
+    let owner_id = claims.user_id()?;
+    let response = service::create_subscription(repo.as_ref(), &config.webhooks, payload, owner_id).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 내 웹훅 구독 목록 핸들러 (GET /webhooks)
+pub async fn list_webhooks(
+    State(repo): State<Webhooks>,
+    claims: Claims,
+) -> Result<Json<Vec<WebhookResponse>>, AppError> {
+    let owner_id = claims.user_id()?;
+    let response = service::list_subscriptions(repo.as_ref(), owner_id).await?;
+
+    Ok(Json(response))
+}
+
+/// 웹훅 구독 삭제 핸들러 (DELETE /webhooks/:id)
+/// - 성공: 204 No Content (대기 중인 전송도 취소)
+pub async fn delete_webhook(
+    State(repo): State<Webhooks>,
+    Path(id): Path<String>,
+    claims: Claims,
+) -> Result<StatusCode, AppError> {
+    let owner_id = claims.user_id()?;
+    service::delete_subscription(repo.as_ref(), parse_webhook_id(&id)?, owner_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 최근 전송 시도 핸들러 (GET /webhooks/:id/attempts?limit=...)
+pub async fn list_attempts(
+    State(repo): State<Webhooks>,
+    Path(id): Path<String>,
+    claims: Claims,
+    Query(query): Query<ListAttemptsQuery>,
+) -> Result<Json<Vec<AttemptResponse>>, AppError> {
+    let owner_id = claims.user_id()?;
+    let response = service::list_attempts(repo.as_ref(), parse_webhook_id(&id)?, owner_id, query.limit).await?;
+
+    Ok(Json(response))
+}
+
+fn parse_webhook_id(id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(id).map_err(|_| AppError::BadRequest("Invalid webhook id".to_string(), vec![]))
+}
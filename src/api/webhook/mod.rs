@@ -0,0 +1,32 @@
+use axum::{routing::{delete, get}, Router};
+use crate::api::state::AppState;
+
+// Webhook 모듈 구조
+// - repository: 구독 / 전송 대기열 / 시도 기록 저장소
+// - signature: HMAC 서명
+// - service: 구독 관리
+// - sink: outbox 이벤트 → 대기열 추가
+// - target: 전송 대상 주소 제한 (SSRF 방지)
+// - dispatcher: 대기열 전송 작업 (재시도 / dead letter)
+// - handlers: HTTP 요청 처리
+
+mod dtos;
+pub mod repository;
+pub mod signature;
+pub mod service;
+pub mod sink;
+pub mod target;
+pub mod dispatcher;
+mod handlers;
+
+#[cfg(test)]
+pub mod test;
+
+pub use repository::WebhookEvent;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(handlers::list_webhooks).post(handlers::create_webhook))
+        .route("/:id", delete(handlers::delete_webhook))
+        .route("/:id/attempts", get(handlers::list_attempts))
+}
@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::{AttemptEntity, AttemptResult, DeliveryStatus, DeliveryUpdate, DueDelivery, WebhookEvent};
+
+/// 이벤트를 받는 구독마다 전송 1건씩 추가 (INSERT ... SELECT 한 번)
+pub async fn enqueue(pool: &PgPool, event: WebhookEvent, agenda_id: Uuid, payload: &serde_json::Value) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
+        "INSERT INTO webhook_deliveries (subscription_id, event_type, payload)
+         SELECT id, $1, $3
+         FROM webhook_subscriptions
+         WHERE (agenda_id = $2 OR agenda_id IS NULL)
+           AND (cardinality(events) = 0 OR $1 = ANY(events))",
+        event.as_str(),
+        agenda_id,
+        payload
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// 시도할 때가 된 전송 임대
+/// - `FOR UPDATE SKIP LOCKED`: 여러 서버가 동시에 실행해도 같은 전송을 가져가지 않음
+pub async fn claim_due(pool: &PgPool, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<DueDelivery>, sqlx::Error> {
+    sqlx::query_as!(
+        DueDelivery,
+        "WITH due AS (
+             SELECT id FROM webhook_deliveries
+             WHERE status = 'pending' AND next_attempt_at <= NOW()
+             ORDER BY next_attempt_at
+             LIMIT $1
+             FOR UPDATE SKIP LOCKED
+         )
+         UPDATE webhook_deliveries d
+         SET next_attempt_at = $2
+         FROM due, webhook_subscriptions s
+         WHERE d.id = due.id AND s.id = d.subscription_id
+         RETURNING d.id, s.url, s.secret, d.event_type, d.payload, d.attempts",
+        limit,
+        lease_until
+    )
+    .fetch_all(pool)
+    .await
+}
+
+pub async fn record_attempt(pool: &PgPool, delivery_id: Uuid, result: &AttemptResult, update: DeliveryUpdate) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query!(
+        "INSERT INTO webhook_attempts (delivery_id, status_code, error, duration_ms)
+         VALUES ($1, $2, $3, $4)",
+        delivery_id,
+        result.status_code,
+        result.error,
+        result.duration_ms
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let (status, next_attempt_at) = match update {
+        DeliveryUpdate::Delivered => (DeliveryStatus::Delivered, None),
+        DeliveryUpdate::Retry { at } => (DeliveryStatus::Pending, Some(at)),
+        DeliveryUpdate::Dead => (DeliveryStatus::Dead, None),
+    };
+    sqlx::query!(
+        "UPDATE webhook_deliveries
+         SET attempts = attempts + 1,
+             status = $2,
+             next_attempt_at = COALESCE($3, next_attempt_at),
+             delivered_at = CASE WHEN $2 = 'delivered'::webhook_delivery_status THEN NOW() END
+         WHERE id = $1",
+        delivery_id,
+        status as DeliveryStatus,
+        next_attempt_at
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await
+}
+
+pub async fn list_attempts(pool: &PgPool, subscription_id: Uuid, limit: i64) -> Result<Vec<AttemptEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        AttemptEntity,
+        r#"SELECT a.delivery_id, d.event_type, d.status as "delivery_status: DeliveryStatus",
+                  a.attempted_at, a.status_code, a.error, a.duration_ms
+           FROM webhook_attempts a
+           JOIN webhook_deliveries d ON d.id = a.delivery_id
+           WHERE d.subscription_id = $1
+           ORDER BY a.attempted_at DESC, a.id DESC
+           LIMIT $2"#,
+        subscription_id,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::error::ErrorKind;
+use uuid::Uuid;
+use super::{
+    traits::WebhookRepository, AttemptEntity, AttemptResult, DeliveryStatus, DeliveryUpdate, DueDelivery, NewSubscription,
+    SubscriptionEntity, WebhookEvent,
+};
+use crate::api::memory::{violation, AttemptRow, DeliveryRow, MemoryDb};
+
+/// 메모리 Repository 구현체 (PostgreSQL 구현과 같은 제약/동작)
+pub struct MemoryWebhookRepository {
+    db: Arc<MemoryDb>,
+}
+
+impl MemoryWebhookRepository {
+    pub fn new(db: &Arc<MemoryDb>) -> Self {
+        Self { db: db.clone() }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for MemoryWebhookRepository {
+    /// 외래 키 (`webhook_subscriptions_owner_id_fkey`, `webhook_subscriptions_agenda_id_fkey`)
+    async fn create_subscription(&self, subscription: &NewSubscription) -> Result<SubscriptionEntity, sqlx::Error> {
+        let mut tables = self.db.lock();
+        if !tables.user_exists(subscription.owner_id) {
+            return Err(violation(ErrorKind::ForeignKeyViolation, "webhook_subscriptions_owner_id_fkey"));
+        }
+        if let Some(agenda_id) = subscription.agenda_id {
+            if !tables.agendas.iter().any(|a| a.id == agenda_id) {
+                return Err(violation(ErrorKind::ForeignKeyViolation, "webhook_subscriptions_agenda_id_fkey"));
+            }
+        }
+
+        let entity = SubscriptionEntity {
+            id: Uuid::new_v4(),
+            owner_id: subscription.owner_id,
+            agenda_id: subscription.agenda_id,
+            url: subscription.url.clone(),
+            secret: subscription.secret.clone(),
+            events: subscription.events.clone(),
+            created_at: Utc::now(),
+        };
+        tables.webhook_subscriptions.push(entity.clone());
+        Ok(entity)
+    }
+
+    async fn list_subscriptions(&self, owner_id: Uuid) -> Result<Vec<SubscriptionEntity>, sqlx::Error> {
+        let tables = self.db.lock();
+        let mut subscriptions: Vec<_> = tables
+            .webhook_subscriptions
+            .iter()
+            .filter(|s| s.owner_id == owner_id)
+            .cloned()
+            .collect();
+        subscriptions.sort_by_key(|s| std::cmp::Reverse((s.created_at, s.id)));
+        Ok(subscriptions)
+    }
+
+    async fn find_subscription(&self, id: Uuid) -> Result<Option<SubscriptionEntity>, sqlx::Error> {
+        Ok(self.db.lock().webhook_subscriptions.iter().find(|s| s.id == id).cloned())
+    }
+
+    /// ON DELETE CASCADE (전송 → 시도 기록)
+    async fn delete_subscription(&self, id: Uuid) -> Result<bool, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let before = tables.webhook_subscriptions.len();
+        tables.webhook_subscriptions.retain(|s| s.id != id);
+        if tables.webhook_subscriptions.len() == before {
+            return Ok(false);
+        }
+
+        let removed: Vec<Uuid> = tables
+            .webhook_deliveries
+            .iter()
+            .filter(|d| d.subscription_id == id)
+            .map(|d| d.id)
+            .collect();
+        tables.webhook_deliveries.retain(|d| d.subscription_id != id);
+        tables.webhook_attempts.retain(|a| !removed.contains(&a.delivery_id));
+        Ok(true)
+    }
+
+    async fn enqueue(&self, event: WebhookEvent, agenda_id: Uuid, payload: &serde_json::Value) -> Result<u64, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let now = Utc::now();
+        let targets: Vec<Uuid> = tables
+            .webhook_subscriptions
+            .iter()
+            .filter(|s| s.agenda_id.is_none_or(|id| id == agenda_id))
+            .filter(|s| s.events.is_empty() || s.events.contains(&event))
+            .map(|s| s.id)
+            .collect();
+
+        for subscription_id in &targets {
+            tables.webhook_deliveries.push(DeliveryRow {
+                id: Uuid::new_v4(),
+                subscription_id: *subscription_id,
+                event_type: event.as_str().to_string(),
+                payload: payload.clone(),
+                status: DeliveryStatus::Pending,
+                attempts: 0,
+                next_attempt_at: now,
+                delivered_at: None,
+            });
+        }
+        Ok(targets.len() as u64)
+    }
+
+    async fn claim_due(&self, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<DueDelivery>, sqlx::Error> {
+        let mut tables = self.db.lock();
+        let tables = &mut *tables;
+        let now = Utc::now();
+
+        let mut due: Vec<&mut DeliveryRow> = tables
+            .webhook_deliveries
+            .iter_mut()
+            .filter(|d| d.status == DeliveryStatus::Pending && d.next_attempt_at <= now)
+            .collect();
+        due.sort_by_key(|d| d.next_attempt_at);
+
+        let mut claimed = Vec::new();
+        for delivery in due.into_iter().take(limit.max(0) as usize) {
+            let Some(subscription) = tables.webhook_subscriptions.iter().find(|s| s.id == delivery.subscription_id) else {
+                continue;
+            };
+            delivery.next_attempt_at = lease_until;
+            claimed.push(DueDelivery {
+                id: delivery.id,
+                url: subscription.url.clone(),
+                secret: subscription.secret.clone(),
+                event_type: delivery.event_type.clone(),
+                payload: delivery.payload.clone(),
+                attempts: delivery.attempts,
+            });
+        }
+        Ok(claimed)
+    }
+
+    async fn record_attempt(&self, delivery_id: Uuid, result: &AttemptResult, update: DeliveryUpdate) -> Result<(), sqlx::Error> {
+        let mut tables = self.db.lock();
+        let now = Utc::now();
+        let Some(delivery) = tables.webhook_deliveries.iter_mut().find(|d| d.id == delivery_id) else {
+            return Err(violation(ErrorKind::ForeignKeyViolation, "webhook_attempts_delivery_id_fkey"));
+        };
+
+        delivery.attempts += 1;
+        match update {
+            DeliveryUpdate::Delivered => {
+                delivery.status = DeliveryStatus::Delivered;
+                delivery.delivered_at = Some(now);
+            }
+            DeliveryUpdate::Retry { at } => delivery.next_attempt_at = at,
+            DeliveryUpdate::Dead => delivery.status = DeliveryStatus::Dead,
+        }
+
+        tables.webhook_attempts.push(AttemptRow {
+            delivery_id,
+            attempted_at: now,
+            result: result.clone(),
+        });
+        Ok(())
+    }
+
+    async fn list_attempts(&self, subscription_id: Uuid, limit: i64) -> Result<Vec<AttemptEntity>, sqlx::Error> {
+        let tables = self.db.lock();
+        // 기록은 시각 순으로 추가되므로 뒤에서부터 읽으면 최신순
+        Ok(tables
+            .webhook_attempts
+            .iter()
+            .rev()
+            .filter_map(|attempt| {
+                let delivery = tables
+                    .webhook_deliveries
+                    .iter()
+                    .find(|d| d.id == attempt.delivery_id && d.subscription_id == subscription_id)?;
+                Some(AttemptEntity {
+                    delivery_id: delivery.id,
+                    event_type: delivery.event_type.clone(),
+                    delivery_status: delivery.status,
+                    attempted_at: attempt.attempted_at,
+                    status_code: attempt.result.status_code,
+                    error: attempt.result.error.clone(),
+                    duration_ms: attempt.result.duration_ms,
+                })
+            })
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+}
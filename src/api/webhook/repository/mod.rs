@@ -0,0 +1,168 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+use self::traits::WebhookRepository;
+
+pub mod traits;
+pub mod subscription;
+pub mod delivery;
+pub mod memory; // PostgreSQL 없이 실행할 때의 메모리 구현체
+
+/// 웹훅 이벤트 종류 (webhook_subscriptions.events, webhook_deliveries.event_type)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::enum_variant_names)] // 앞으로 안건 외의 이벤트도 추가될 수 있으므로 대상 이름을 붙여 둠
+pub enum WebhookEvent {
+    #[serde(rename = "agenda.created")]
+    AgendaCreated,
+    #[serde(rename = "agenda.opened")]
+    AgendaOpened,
+    #[serde(rename = "agenda.closed")]
+    AgendaClosed,
+    #[serde(rename = "agenda.decided")]
+    AgendaDecided, // 마감 후 가결 판정 (closed 직후 함께 전송)
+}
+
+impl WebhookEvent {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WebhookEvent::AgendaCreated => "agenda.created",
+            WebhookEvent::AgendaOpened => "agenda.opened",
+            WebhookEvent::AgendaClosed => "agenda.closed",
+            WebhookEvent::AgendaDecided => "agenda.decided",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        [
+            WebhookEvent::AgendaCreated,
+            WebhookEvent::AgendaOpened,
+            WebhookEvent::AgendaClosed,
+            WebhookEvent::AgendaDecided,
+        ]
+        .into_iter()
+        .find(|event| event.as_str() == value)
+    }
+}
+
+/// 전송 상태 (PostgreSQL `webhook_delivery_status` ENUM)
+///
+/// ```text
+/// pending ──→ delivered
+///    └──────→ dead (재시도 한도 초과)
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, sqlx::Type)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Dead,
+}
+
+/// 웹훅 구독
+#[derive(Debug, Clone)]
+pub struct SubscriptionEntity {
+    pub id: Uuid,
+    pub owner_id: Uuid,
+    pub agenda_id: Option<Uuid>, // None: 모든 안건
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>, // 비어 있으면 모든 이벤트
+    pub created_at: DateTime<Utc>,
+}
+
+/// 구독 생성 입력
+pub struct NewSubscription {
+    pub owner_id: Uuid,
+    pub agenda_id: Option<Uuid>,
+    pub url: String,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+/// 전송할 차례가 된 대기열 항목 (구독의 URL/서명 키 포함)
+#[derive(Debug, Clone)]
+pub struct DueDelivery {
+    pub id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub attempts: i32, // 지금까지 시도한 횟수
+}
+
+/// 전송 시도 1회의 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttemptResult {
+    pub status_code: Option<i32>, // None: 응답을 받지 못함
+    pub error: Option<String>,    // None: 2xx 응답
+    pub duration_ms: i32,
+}
+
+/// 시도 후 전송 상태 변경
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryUpdate {
+    Delivered,
+    Retry { at: DateTime<Utc> },
+    Dead,
+}
+
+/// 전송 시도 기록 (최근 시도 목록)
+#[derive(Debug, Clone)]
+pub struct AttemptEntity {
+    pub delivery_id: Uuid,
+    pub event_type: String,
+    pub delivery_status: DeliveryStatus, // 전송의 현재 상태
+    pub attempted_at: DateTime<Utc>,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub duration_ms: i32,
+}
+
+/// PostgreSQL Repository 구현체
+pub struct PgWebhookRepository {
+    pool: PgPool,
+}
+
+impl PgWebhookRepository {
+    pub fn new(pool: &PgPool) -> Self {
+        Self { pool: pool.clone() }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for PgWebhookRepository {
+    async fn create_subscription(&self, subscription: &NewSubscription) -> Result<SubscriptionEntity, sqlx::Error> {
+        subscription::create(&self.pool, subscription).await
+    }
+
+    async fn list_subscriptions(&self, owner_id: Uuid) -> Result<Vec<SubscriptionEntity>, sqlx::Error> {
+        subscription::list_by_owner(&self.pool, owner_id).await
+    }
+
+    async fn find_subscription(&self, id: Uuid) -> Result<Option<SubscriptionEntity>, sqlx::Error> {
+        subscription::find_by_id(&self.pool, id).await
+    }
+
+    async fn delete_subscription(&self, id: Uuid) -> Result<bool, sqlx::Error> {
+        subscription::delete(&self.pool, id).await
+    }
+
+    async fn enqueue(&self, event: WebhookEvent, agenda_id: Uuid, payload: &serde_json::Value) -> Result<u64, sqlx::Error> {
+        delivery::enqueue(&self.pool, event, agenda_id, payload).await
+    }
+
+    async fn claim_due(&self, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<DueDelivery>, sqlx::Error> {
+        delivery::claim_due(&self.pool, limit, lease_until).await
+    }
+
+    async fn record_attempt(&self, delivery_id: Uuid, result: &AttemptResult, update: DeliveryUpdate) -> Result<(), sqlx::Error> {
+        delivery::record_attempt(&self.pool, delivery_id, result, update).await
+    }
+
+    async fn list_attempts(&self, subscription_id: Uuid, limit: i64) -> Result<Vec<AttemptEntity>, sqlx::Error> {
+        delivery::list_attempts(&self.pool, subscription_id, limit).await
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use super::{NewSubscription, SubscriptionEntity, WebhookEvent};
+
+/// webhook_subscriptions 행 (events는 TEXT[])
+struct SubscriptionRow {
+    id: Uuid,
+    owner_id: Uuid,
+    agenda_id: Option<Uuid>,
+    url: String,
+    secret: String,
+    events: Vec<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<SubscriptionRow> for SubscriptionEntity {
+    fn from(row: SubscriptionRow) -> Self {
+        Self {
+            id: row.id,
+            owner_id: row.owner_id,
+            agenda_id: row.agenda_id,
+            url: row.url,
+            secret: row.secret,
+            events: row.events.iter().filter_map(|event| WebhookEvent::parse(event)).collect(),
+            created_at: row.created_at,
+        }
+    }
+}
+
+pub async fn create(pool: &PgPool, subscription: &NewSubscription) -> Result<SubscriptionEntity, sqlx::Error> {
+    let events: Vec<String> = subscription.events.iter().map(|event| event.as_str().to_string()).collect();
+
+    let row = sqlx::query_as!(
+        SubscriptionRow,
+        "INSERT INTO webhook_subscriptions (owner_id, agenda_id, url, secret, events)
+         VALUES ($1, $2, $3, $4, $5)
+         RETURNING id, owner_id, agenda_id, url, secret, events, created_at",
+        subscription.owner_id,
+        subscription.agenda_id,
+        subscription.url,
+        subscription.secret,
+        &events
+    )
+    .fetch_one(pool)
+    .await?;
+
+    Ok(row.into())
+}
+
+pub async fn list_by_owner(pool: &PgPool, owner_id: Uuid) -> Result<Vec<SubscriptionEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        SubscriptionRow,
+        "SELECT id, owner_id, agenda_id, url, secret, events, created_at
+         FROM webhook_subscriptions
+         WHERE owner_id = $1
+         ORDER BY created_at DESC, id DESC",
+        owner_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(SubscriptionEntity::from).collect())
+}
+
+pub async fn find_by_id(pool: &PgPool, id: Uuid) -> Result<Option<SubscriptionEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        SubscriptionRow,
+        "SELECT id, owner_id, agenda_id, url, secret, events, created_at
+         FROM webhook_subscriptions
+         WHERE id = $1",
+        id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(SubscriptionEntity::from))
+}
+
+pub async fn delete(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM webhook_subscriptions WHERE id = $1", id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use super::{AttemptEntity, AttemptResult, DeliveryUpdate, DueDelivery, NewSubscription, SubscriptionEntity, WebhookEvent};
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    /// 구독 저장
+    /// - 없는 안건: 외래 키 위반
+    async fn create_subscription(&self, subscription: &NewSubscription) -> Result<SubscriptionEntity, sqlx::Error>;
+
+    /// 사용자의 구독 목록 (최신순)
+    async fn list_subscriptions(&self, owner_id: Uuid) -> Result<Vec<SubscriptionEntity>, sqlx::Error>;
+
+    /// 구독 단건 조회
+    async fn find_subscription(&self, id: Uuid) -> Result<Option<SubscriptionEntity>, sqlx::Error>;
+
+    /// 구독 삭제 (대기 중인 전송과 시도 기록도 함께 삭제), 없으면 false
+    async fn delete_subscription(&self, id: Uuid) -> Result<bool, sqlx::Error>;
+
+    /// 이벤트를 받는 구독마다 전송 1건씩 대기열에 추가하고 추가한 수 반환
+    /// - 안건 구독은 그 안건, 전체 구독은 모든 안건의 이벤트를 받음
+    async fn enqueue(&self, event: WebhookEvent, agenda_id: Uuid, payload: &serde_json::Value) -> Result<u64, sqlx::Error>;
+
+    /// 시도할 때가 된 전송을 최대 `limit`건 가져옴
+    /// - 가져간 전송은 `lease_until`까지 다시 가져가지 않음 (결과를 기록하기 전에 작업이 죽으면 그 뒤에 재시도)
+    async fn claim_due(&self, limit: i64, lease_until: DateTime<Utc>) -> Result<Vec<DueDelivery>, sqlx::Error>;
+
+    /// 시도 기록 저장 + 시도 횟수 증가 + 전송 상태 변경
+    async fn record_attempt(&self, delivery_id: Uuid, result: &AttemptResult, update: DeliveryUpdate) -> Result<(), sqlx::Error>;
+
+    /// 구독의 최근 시도 기록 (최신순, 최대 `limit`건)
+    async fn list_attempts(&self, subscription_id: Uuid, limit: i64) -> Result<Vec<AttemptEntity>, sqlx::Error>;
+}
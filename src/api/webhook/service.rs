@@ -0,0 +1,87 @@
+use uuid::Uuid;
+use crate::api::config::WebhookConfig;
+use crate::api::error::AppError;
+use crate::api::validation::{FieldError, Validate};
+use super::dtos::{AttemptResponse, CreateWebhookRequest, WebhookResponse};
+use super::repository::{traits::WebhookRepository, NewSubscription, SubscriptionEntity};
+use super::{signature, target};
+
+/// 최근 시도 목록 기본 / 최대 개수
+const DEFAULT_ATTEMPTS_LIMIT: i64 = 20;
+const MAX_ATTEMPTS_LIMIT: i64 = 100;
+
+/// 웹훅 구독 비즈니스 로직
+///
+/// - URL 형식 오류, 내부망 주소(루프백/사설망/링크 로컬, `allow_private_targets`가 꺼져 있을 때) → 400 BadRequest
+/// - 없는 안건 → 404 NotFound
+/// - 응답에는 서명 키가 포함되며, 이후 조회에서는 다시 보여주지 않음
+pub async fn create_subscription<R: WebhookRepository + ?Sized>(
+    repo: &R,
+    config: &WebhookConfig,
+    payload: CreateWebhookRequest,
+    owner_id: Uuid,
+) -> Result<WebhookResponse, AppError> {
+    let mut payload = payload.validated()?;
+    if !config.allow_private_targets {
+        target::check_url(&payload.url).map_err(|message| {
+            AppError::BadRequest("Invalid request".to_string(), vec![FieldError::new("url", "forbidden_target", message)])
+        })?;
+    }
+    payload.events.sort_by_key(|event| event.as_str());
+    payload.events.dedup();
+
+    let subscription = repo
+        .create_subscription(&NewSubscription {
+            owner_id,
+            agenda_id: payload.agenda_id,
+            url: payload.url,
+            secret: signature::generate_secret(),
+            events: payload.events,
+        })
+        .await
+        .map_err(|e| match e.as_database_error().and_then(|db| db.constraint()) {
+            Some("webhook_subscriptions_agenda_id_fkey") => AppError::NotFound("Agenda not found".to_string()),
+            _ => AppError::from(e),
+        })?;
+
+    Ok(WebhookResponse::from_entity(subscription, true))
+}
+
+/// 내 웹훅 구독 목록
+pub async fn list_subscriptions<R: WebhookRepository + ?Sized>(repo: &R, owner_id: Uuid) -> Result<Vec<WebhookResponse>, AppError> {
+    let subscriptions = repo.list_subscriptions(owner_id).await?;
+
+    Ok(subscriptions
+        .into_iter()
+        .map(|subscription| WebhookResponse::from_entity(subscription, false))
+        .collect())
+}
+
+/// 웹훅 구독 삭제 (다른 사용자의 구독 → 404)
+pub async fn delete_subscription<R: WebhookRepository + ?Sized>(repo: &R, id: Uuid, owner_id: Uuid) -> Result<(), AppError> {
+    find_owned(repo, id, owner_id).await?;
+    repo.delete_subscription(id).await?;
+    Ok(())
+}
+
+/// 구독의 최근 전송 시도 (최신순, `limit`: 1 ~ 100, 기본 20)
+pub async fn list_attempts<R: WebhookRepository + ?Sized>(
+    repo: &R,
+    id: Uuid,
+    owner_id: Uuid,
+    limit: Option<i64>,
+) -> Result<Vec<AttemptResponse>, AppError> {
+    find_owned(repo, id, owner_id).await?;
+    let limit = limit.unwrap_or(DEFAULT_ATTEMPTS_LIMIT).clamp(1, MAX_ATTEMPTS_LIMIT);
+    let attempts = repo.list_attempts(id, limit).await?;
+
+    Ok(attempts.into_iter().map(AttemptResponse::from).collect())
+}
+
+/// 본인 구독 조회 (다른 사용자의 구독은 존재를 드러내지 않도록 404)
+async fn find_owned<R: WebhookRepository + ?Sized>(repo: &R, id: Uuid, owner_id: Uuid) -> Result<SubscriptionEntity, AppError> {
+    repo.find_subscription(id)
+        .await?
+        .filter(|subscription| subscription.owner_id == owner_id)
+        .ok_or(AppError::NotFound("Webhook not found".to_string()))
+}
@@ -0,0 +1,44 @@
+//! 웹훅 서명 (HMAC-SHA256)
+//!
+//! 수신자는 `X-Webhook-Signature`를 구독 등록 때 받은 서명 키로 다시 계산해 비교합니다.
+//! - 서명 대상: `{X-Webhook-Timestamp}.{본문}` (타임스탬프를 포함해 오래된 요청의 재전송을 막을 수 있음)
+//! - 헤더 값: `sha256=<hex>`
+
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+
+/// 서명 키 접두사 (로그 등에서 비밀 값임을 알아보기 쉽게)
+const SECRET_PREFIX: &str = "whsec_";
+
+/// 새 서명 키 (256비트 난수)
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut bytes);
+    format!("{}{}", SECRET_PREFIX, hex(&bytes))
+}
+
+/// `X-Webhook-Signature` 헤더 값
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!("sha256={}", hex(&mac.finalize().into_bytes()))
+}
+
+/// 수신 측 검증 (상수 시간 비교) - 서버는 보내기만 하므로 테스트의 수신자에서만 사용
+#[cfg(test)]
+pub fn verify(secret: &str, timestamp: i64, body: &[u8], signature: &str) -> bool {
+    let expected = sign(secret, timestamp, body);
+    expected.len() == signature.len()
+        && expected
+            .bytes()
+            .zip(signature.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
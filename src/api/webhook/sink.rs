@@ -0,0 +1,62 @@
+//! outbox → 웹훅 전송 대기열
+//!
+//! 안건 이벤트만 구독마다 전송 1건씩 대기열에 넣습니다 (투표 이벤트는 보내지 않음).
+//! - `agenda.closed` 다음에는 그 시점의 가결 판정을 `agenda.decided`로 함께 넣습니다.
+//! - `agenda.cancelled`는 웹훅 이벤트가 없습니다.
+//! - 본문의 `id`는 outbox 이벤트 id라 relay가 다시 발행해도 같으므로 수신자가 중복을 거를 수 있습니다.
+//!   `agenda.decided`는 이어지는 `agenda.closed`와 `id`가 같으므로 중복 키는 `(id, event)`입니다.
+
+use async_trait::async_trait;
+use crate::api::agenda;
+use crate::api::outbox::{event, repository::OutboxRecord, sink::{Sink, SinkError}};
+use crate::api::state::{Agendas, Webhooks};
+use super::dtos::WebhookPayload;
+use super::WebhookEvent;
+
+pub struct WebhookSink {
+    webhooks: Webhooks,
+    agendas: Agendas, // 가결 판정 계산용
+}
+
+impl WebhookSink {
+    pub fn new(webhooks: Webhooks, agendas: Agendas) -> Self {
+        Self { webhooks, agendas }
+    }
+
+    async fn enqueue(&self, record: &OutboxRecord, event: WebhookEvent, data: impl serde::Serialize) -> Result<(), SinkError> {
+        let payload = WebhookPayload {
+            id: record.id,
+            event,
+            agenda_id: record.agenda_id,
+            occurred_at: record.created_at,
+            data,
+        };
+        self.webhooks.enqueue(event, record.agenda_id, &serde_json::to_value(&payload)?).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Sink for WebhookSink {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn publish(&self, record: &OutboxRecord) -> Result<(), SinkError> {
+        let event = match record.event_type.as_str() {
+            event::AGENDA_CREATED => WebhookEvent::AgendaCreated,
+            event::AGENDA_OPENED => WebhookEvent::AgendaOpened,
+            event::AGENDA_CLOSED => WebhookEvent::AgendaClosed,
+            _ => return Ok(()),
+        };
+        self.enqueue(record, event, &record.payload).await?;
+
+        if event == WebhookEvent::AgendaClosed {
+            let result = agenda::get_result(self.agendas.as_ref(), record.agenda_id)
+                .await
+                .map_err(|e| format!("result load failed: {:?}", e))?;
+            self.enqueue(record, WebhookEvent::AgendaDecided, result).await?;
+        }
+        Ok(())
+    }
+}
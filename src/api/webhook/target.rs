@@ -0,0 +1,73 @@
+//! 웹훅 전송 대상 제한 (SSRF 방지)
+//!
+//! 구독 URL로 서버 내부망(루프백, 사설망, 링크 로컬 - 클라우드 메타데이터 주소 포함)에 요청을 보내지 못하게 합니다.
+//! - 등록 시: 호스트를 IP 주소나 `localhost`로 쓴 URL은 바로 거부 (`check_url`)
+//! - 전송 시: DNS로 얻은 주소 중 허용된 주소로만 연결 (`PublicResolver`), 리다이렉트는 따라가지 않음
+//! - 개발/테스트에서 로컬 수신 서버를 쓰려면 `webhooks.allow_private_targets = true`
+
+use std::net::{IpAddr, SocketAddr};
+use reqwest::{dns::{Addrs, Name, Resolve, Resolving}, Url};
+
+/// 웹훅을 보낼 수 있는 주소인지 (루프백 / 사설망 / 링크 로컬 / 미지정 / 멀티캐스트 거부)
+pub fn is_allowed(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast())
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(v4) => is_allowed(IpAddr::V4(v4)),
+            None => {
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
+
+/// 등록 시 검사 - 호스트가 허용되지 않는 IP 주소이거나 `localhost`면 거부 사유 반환
+/// (도메인은 전송할 때마다 다시 확인하므로 여기서는 DNS를 조회하지 않음)
+pub fn check_url(url: &str) -> Result<(), String> {
+    let url = Url::parse(url).map_err(|_| "must be an absolute http(s) URL".to_string())?;
+    let host = url.host_str().unwrap_or_default().trim_start_matches('[').trim_end_matches(']');
+    let allowed = match host.parse::<IpAddr>() {
+        Ok(ip) => is_allowed(ip),
+        Err(_) => {
+            let domain = host.trim_end_matches('.').to_ascii_lowercase();
+            !domain.is_empty() && domain != "localhost" && !domain.ends_with(".localhost")
+        }
+    };
+
+    if allowed {
+        Ok(())
+    } else {
+        Err("must not point to a loopback, private or link-local address".to_string())
+    }
+}
+
+/// 허용된 주소만 돌려주는 DNS 리졸버 (모두 걸러지면 연결 실패)
+///
+/// 연결할 주소를 여기서 정하므로, 등록 후 DNS 응답을 내부 주소로 바꿔도 그 주소로는 연결하지 않습니다.
+pub struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_allowed(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", name.as_str()).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
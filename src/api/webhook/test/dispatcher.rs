@@ -0,0 +1,185 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use axum::{body::Bytes, extract::State, http::{HeaderMap, StatusCode}, routing::post, Router};
+use chrono::Utc;
+use serde_json::json;
+use uuid::Uuid;
+use crate::api::auth::repository::{memory::MemoryUserRepository, traits::UserRepository};
+use crate::api::config::WebhookConfig;
+use crate::api::memory::MemoryDb;
+use crate::api::webhook::dispatcher::{client, retry_delay, run_once};
+use crate::api::webhook::repository::{memory::MemoryWebhookRepository, traits::WebhookRepository, DeliveryStatus, NewSubscription};
+use crate::api::webhook::{signature, WebhookEvent};
+
+/// 받은 요청을 기록하고 정해진 상태 코드로 응답하는 수신 서버
+struct Receiver {
+    status: StatusCode,
+    received: Mutex<Vec<(HeaderMap, Bytes)>>,
+}
+
+async fn receive(State(receiver): State<Arc<Receiver>>, headers: HeaderMap, body: Bytes) -> StatusCode {
+    receiver.received.lock().unwrap().push((headers, body));
+    receiver.status
+}
+
+/// 127.0.0.1의 빈 포트에 수신 서버 실행 → (수신 기록, URL)
+async fn spawn_receiver(status: StatusCode) -> (Arc<Receiver>, String) {
+    let receiver = Arc::new(Receiver { status, received: Mutex::new(vec![]) });
+    let app = Router::new().route("/hook", post(receive)).with_state(receiver.clone());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (receiver, url)
+}
+
+/// 메모리 DB + `url`로 보내는 구독 1개 + 대기 중인 전송 1건 → (저장소, 구독 ID, 서명 키)
+async fn setup(url: String) -> (MemoryWebhookRepository, Uuid, String) {
+    let db = Arc::new(MemoryDb::default());
+    let owner_id = MemoryUserRepository::new(&db).save("owner", "hash").await.unwrap();
+    let repo = MemoryWebhookRepository::new(&db);
+    let subscription = repo
+        .create_subscription(&NewSubscription {
+            owner_id,
+            agenda_id: None,
+            url,
+            secret: signature::generate_secret(),
+            events: vec![],
+        })
+        .await
+        .unwrap();
+
+    let enqueued = repo
+        .enqueue(WebhookEvent::AgendaCreated, Uuid::new_v4(), &json!({ "event": "agenda.created" }))
+        .await
+        .unwrap();
+    assert_eq!(enqueued, 1);
+    (repo, subscription.id, subscription.secret)
+}
+
+fn config(max_attempts: u32) -> WebhookConfig {
+    WebhookConfig {
+        max_attempts,
+        timeout_secs: 5,
+        allow_private_targets: true, // 수신 서버가 127.0.0.1
+        ..Default::default()
+    }
+}
+
+// 테스트 1: 재시도 간격은 지수적으로 늘어나고 최대값에서 멈춤
+#[test]
+fn test_retry_delay_backs_off_exponentially() {
+    let config = WebhookConfig {
+        initial_backoff_secs: 10,
+        max_backoff_secs: 100,
+        ..Default::default()
+    };
+
+    assert_eq!(retry_delay(1, &config), Duration::from_secs(10));
+    assert_eq!(retry_delay(2, &config), Duration::from_secs(20));
+    assert_eq!(retry_delay(3, &config), Duration::from_secs(40));
+    assert_eq!(retry_delay(5, &config), Duration::from_secs(100));
+    assert_eq!(retry_delay(u32::MAX, &config), Duration::from_secs(100));
+}
+
+// 테스트 2: 2xx 응답 → delivered, 수신자는 서명 키로 본문 검증 가능
+#[tokio::test]
+async fn test_delivers_signed_payload() {
+    let (receiver, url) = spawn_receiver(StatusCode::NO_CONTENT).await;
+    let (repo, subscription_id, secret) = setup(url).await;
+    let config = config(3);
+
+    assert_eq!(run_once(&repo, &client(&config), &config).await, 1);
+
+    let (headers, body) = receiver.received.lock().unwrap().remove(0);
+    let timestamp: i64 = headers["x-webhook-timestamp"].to_str().unwrap().parse().unwrap();
+    let signature = headers["x-webhook-signature"].to_str().unwrap();
+    assert_eq!(headers["x-webhook-event"], "agenda.created");
+    assert!(signature::verify(&secret, timestamp, &body, signature));
+    assert!((Utc::now().timestamp() - timestamp).abs() < 60);
+
+    let attempts = repo.list_attempts(subscription_id, 10).await.unwrap();
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].delivery_status, DeliveryStatus::Delivered);
+    assert_eq!(attempts[0].status_code, Some(204));
+    assert!(attempts[0].error.is_none());
+}
+
+// 테스트 3: 실패 응답 → 백오프 동안은 다시 가져가지 않음
+#[tokio::test]
+async fn test_failed_delivery_is_retried_later() {
+    let (receiver, url) = spawn_receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+    let (repo, subscription_id, _) = setup(url).await;
+    let config = config(3);
+    let client = client(&config);
+
+    assert_eq!(run_once(&repo, &client, &config).await, 1);
+    assert_eq!(run_once(&repo, &client, &config).await, 0);
+    assert_eq!(receiver.received.lock().unwrap().len(), 1);
+
+    let attempts = repo.list_attempts(subscription_id, 10).await.unwrap();
+    assert_eq!(attempts[0].delivery_status, DeliveryStatus::Pending);
+    assert_eq!(attempts[0].status_code, Some(500));
+    assert!(attempts[0].error.is_some());
+}
+
+// 테스트 4: 재시도 한도 초과 → dead (연결 실패도 시도로 기록)
+#[tokio::test]
+async fn test_exhausted_delivery_moves_to_dead_letter() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    drop(listener); // 아무도 받지 않는 주소
+    let (repo, subscription_id, _) = setup(url).await;
+    let config = config(1);
+
+    assert_eq!(run_once(&repo, &client(&config), &config).await, 1);
+    assert_eq!(run_once(&repo, &client(&config), &config).await, 0);
+
+    let attempts = repo.list_attempts(subscription_id, 10).await.unwrap();
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0].delivery_status, DeliveryStatus::Dead);
+    assert_eq!(attempts[0].status_code, None);
+}
+
+// 테스트 5: 내부망 주소로는 보내지 않음 (IP 주소 호스트, DNS로 얻은 주소 모두) - 실패로 기록 후 재시도 대기
+#[tokio::test]
+async fn test_private_targets_are_not_contacted() {
+    let (receiver, url) = spawn_receiver(StatusCode::NO_CONTENT).await;
+    let config = WebhookConfig {
+        allow_private_targets: false,
+        ..config(3)
+    };
+
+    for url in [url.clone(), url.replace("127.0.0.1", "localhost")] {
+        let (repo, subscription_id, _) = setup(url).await;
+
+        assert_eq!(run_once(&repo, &client(&config), &config).await, 1);
+
+        let attempts = repo.list_attempts(subscription_id, 10).await.unwrap();
+        assert_eq!(attempts[0].delivery_status, DeliveryStatus::Pending);
+        assert_eq!(attempts[0].status_code, None);
+        assert!(attempts[0].error.is_some());
+    }
+    assert!(receiver.received.lock().unwrap().is_empty());
+}
+
+// 테스트 6: 리다이렉트는 따라가지 않고 실패로 기록
+#[tokio::test]
+async fn test_redirect_is_not_followed() {
+    let (receiver, target) = spawn_receiver(StatusCode::NO_CONTENT).await;
+    let app = Router::new().route(
+        "/hook",
+        post(move || async move { (StatusCode::TEMPORARY_REDIRECT, [(axum::http::header::LOCATION, target)]) }),
+    );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    let (repo, subscription_id, _) = setup(url).await;
+    let config = config(3);
+
+    assert_eq!(run_once(&repo, &client(&config), &config).await, 1);
+
+    let attempts = repo.list_attempts(subscription_id, 10).await.unwrap();
+    assert_eq!(attempts[0].delivery_status, DeliveryStatus::Pending);
+    assert_eq!(attempts[0].status_code, Some(307));
+    assert!(receiver.received.lock().unwrap().is_empty());
+}
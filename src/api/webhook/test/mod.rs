@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::error::ErrorKind;
+use uuid::Uuid;
+use crate::api::memory::violation;
+use super::repository::traits::WebhookRepository;
+use super::repository::{AttemptEntity, AttemptResult, DeliveryUpdate, DueDelivery, NewSubscription, SubscriptionEntity, WebhookEvent};
+
+pub mod signature;
+pub mod service;
+pub mod dispatcher;
+pub mod sink;
+pub mod target;
+
+#[derive(Default)]
+pub struct MockWebhookRepository {
+    pub missing_agenda: bool, // create_subscription 호출 시 안건 외래 키 위반 여부
+    pub subscriptions: Vec<SubscriptionEntity>, // 조회 대상 구독들
+}
+
+impl MockWebhookRepository {
+    /// `owner_id`의 구독 하나가 저장된 Mock
+    pub fn with_subscription(owner_id: Uuid) -> Self {
+        Self {
+            subscriptions: vec![SubscriptionEntity {
+                id: Uuid::new_v4(),
+                owner_id,
+                agenda_id: None,
+                url: "http://example.com/hook".to_string(),
+                secret: "whsec_test".to_string(),
+                events: vec![],
+                created_at: Utc::now(),
+            }],
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for MockWebhookRepository {
+    async fn create_subscription(&self, subscription: &NewSubscription) -> Result<SubscriptionEntity, sqlx::Error> {
+        if self.missing_agenda {
+            return Err(violation(ErrorKind::ForeignKeyViolation, "webhook_subscriptions_agenda_id_fkey"));
+        }
+        Ok(SubscriptionEntity {
+            id: Uuid::new_v4(),
+            owner_id: subscription.owner_id,
+            agenda_id: subscription.agenda_id,
+            url: subscription.url.clone(),
+            secret: subscription.secret.clone(),
+            events: subscription.events.clone(),
+            created_at: Utc::now(),
+        })
+    }
+
+    async fn list_subscriptions(&self, owner_id: Uuid) -> Result<Vec<SubscriptionEntity>, sqlx::Error> {
+        Ok(self.subscriptions.iter().filter(|s| s.owner_id == owner_id).cloned().collect())
+    }
+
+    async fn find_subscription(&self, id: Uuid) -> Result<Option<SubscriptionEntity>, sqlx::Error> {
+        Ok(self.subscriptions.iter().find(|s| s.id == id).cloned())
+    }
+
+    async fn delete_subscription(&self, id: Uuid) -> Result<bool, sqlx::Error> {
+        Ok(self.subscriptions.iter().any(|s| s.id == id))
+    }
+
+    async fn enqueue(&self, _event: WebhookEvent, _agenda_id: Uuid, _payload: &serde_json::Value) -> Result<u64, sqlx::Error> {
+        Ok(0)
+    }
+
+    async fn claim_due(&self, _limit: i64, _lease_until: DateTime<Utc>) -> Result<Vec<DueDelivery>, sqlx::Error> {
+        Ok(vec![])
+    }
+
+    async fn record_attempt(&self, _delivery_id: Uuid, _result: &AttemptResult, _update: DeliveryUpdate) -> Result<(), sqlx::Error> {
+        Ok(())
+    }
+
+    async fn list_attempts(&self, _subscription_id: Uuid, _limit: i64) -> Result<Vec<AttemptEntity>, sqlx::Error> {
+        Ok(vec![])
+    }
+}
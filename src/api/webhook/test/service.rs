@@ -0,0 +1,101 @@
+use uuid::Uuid;
+use crate::api::config::WebhookConfig;
+use crate::api::error::AppError;
+use crate::api::webhook::dtos::CreateWebhookRequest;
+use crate::api::webhook::service::{create_subscription, delete_subscription, list_attempts, list_subscriptions};
+use crate::api::webhook::WebhookEvent;
+use super::MockWebhookRepository;
+
+fn request(url: &str) -> CreateWebhookRequest {
+    CreateWebhookRequest {
+        url: url.to_string(),
+        ..Default::default()
+    }
+}
+
+// 테스트 1: 등록 응답에만 서명 키 포함, 이벤트는 중복 제거
+#[tokio::test]
+async fn test_create_subscription_returns_secret_once() {
+    let repo = MockWebhookRepository::default();
+    let payload = CreateWebhookRequest {
+        events: vec![WebhookEvent::AgendaClosed, WebhookEvent::AgendaCreated, WebhookEvent::AgendaClosed],
+        ..request("  https://example.com/hook  ")
+    };
+
+    let created = create_subscription(&repo, &WebhookConfig::default(), payload, Uuid::new_v4()).await.unwrap();
+
+    assert_eq!(created.url, "https://example.com/hook");
+    assert!(created.secret.unwrap().starts_with("whsec_"));
+    assert_eq!(created.events, vec![WebhookEvent::AgendaClosed, WebhookEvent::AgendaCreated]);
+}
+
+// 테스트 2: http(s)가 아닌 URL → 필드 에러
+#[tokio::test]
+async fn test_create_subscription_rejects_invalid_url() {
+    let repo = MockWebhookRepository::default();
+
+    for url in ["", "not a url", "ftp://example.com/hook", "/relative/path"] {
+        let result = create_subscription(&repo, &WebhookConfig::default(), request(url), Uuid::new_v4()).await;
+
+        match result {
+            Err(AppError::BadRequest(_, errors)) => assert_eq!(errors[0].field, "url", "{}", url),
+            other => panic!("expected validation error for {:?}, got {:?}", url, other),
+        }
+    }
+}
+
+// 테스트 3: 없는 안건 구독 → 404
+#[tokio::test]
+async fn test_create_subscription_for_missing_agenda() {
+    let repo = MockWebhookRepository {
+        missing_agenda: true,
+        ..Default::default()
+    };
+    let payload = CreateWebhookRequest {
+        agenda_id: Some(Uuid::new_v4()),
+        ..request("https://example.com/hook")
+    };
+
+    let result = create_subscription(&repo, &WebhookConfig::default(), payload, Uuid::new_v4()).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+// 테스트 4: 다른 사용자의 구독은 보이지 않음 (목록 제외, 삭제/시도 조회 → 404)
+#[tokio::test]
+async fn test_other_users_subscription_is_hidden() {
+    let owner = Uuid::new_v4();
+    let repo = MockWebhookRepository::with_subscription(owner);
+    let id = repo.subscriptions[0].id;
+    let stranger = Uuid::new_v4();
+
+    assert!(list_subscriptions(&repo, stranger).await.unwrap().is_empty());
+    assert!(matches!(delete_subscription(&repo, id, stranger).await, Err(AppError::NotFound(_))));
+    assert!(matches!(list_attempts(&repo, id, stranger, None).await, Err(AppError::NotFound(_))));
+
+    let listed = list_subscriptions(&repo, owner).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert!(listed[0].secret.is_none());
+    assert!(delete_subscription(&repo, id, owner).await.is_ok());
+}
+
+// 테스트 5: 내부망 주소로 보내는 구독 → 필드 에러 (allow_private_targets면 허용)
+#[tokio::test]
+async fn test_create_subscription_rejects_private_target() {
+    let repo = MockWebhookRepository::default();
+
+    for url in ["http://127.0.0.1:8080/hook", "http://localhost/hook", "http://169.254.169.254/latest", "http://[::1]/hook"] {
+        let result = create_subscription(&repo, &WebhookConfig::default(), request(url), Uuid::new_v4()).await;
+
+        match result {
+            Err(AppError::BadRequest(_, errors)) => assert_eq!(errors[0].code, "forbidden_target", "{}", url),
+            other => panic!("expected forbidden target for {:?}, got {:?}", url, other),
+        }
+    }
+
+    let config = WebhookConfig {
+        allow_private_targets: true,
+        ..Default::default()
+    };
+    assert!(create_subscription(&repo, &config, request("http://127.0.0.1:8080/hook"), Uuid::new_v4()).await.is_ok());
+}
@@ -0,0 +1,37 @@
+use crate::api::webhook::signature::{generate_secret, sign, verify};
+
+// 테스트 1: 같은 키/타임스탬프/본문으로 계산한 서명은 검증 통과
+#[test]
+fn test_sign_and_verify_round_trip() {
+    let secret = generate_secret();
+    let body = br#"{"event":"agenda.created"}"#;
+
+    let signature = sign(&secret, 1_700_000_000, body);
+
+    assert!(signature.starts_with("sha256="));
+    assert_eq!(signature.len(), "sha256=".len() + 64);
+    assert!(verify(&secret, 1_700_000_000, body, &signature));
+}
+
+// 테스트 2: 본문/타임스탬프/키 중 하나라도 다르면 검증 실패
+#[test]
+fn test_verify_rejects_tampering() {
+    let secret = generate_secret();
+    let body = br#"{"event":"agenda.created"}"#;
+    let signature = sign(&secret, 1_700_000_000, body);
+
+    assert!(!verify(&secret, 1_700_000_000, br#"{"event":"agenda.closed"}"#, &signature));
+    assert!(!verify(&secret, 1_700_000_001, body, &signature));
+    assert!(!verify(&generate_secret(), 1_700_000_000, body, &signature));
+    assert!(!verify(&secret, 1_700_000_000, body, "sha256=00"));
+}
+
+// 테스트 3: 서명 키는 매번 새로 생성
+#[test]
+fn test_generate_secret_is_unique() {
+    let (a, b) = (generate_secret(), generate_secret());
+
+    assert!(a.starts_with("whsec_"));
+    assert_eq!(a.len(), "whsec_".len() + 64);
+    assert_ne!(a, b);
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use chrono::Utc;
+use crate::api::agenda::repository::{memory::MemoryAgendaRepository, AgendaStatus, traits::AgendaRepository, Choice};
+use crate::api::auth::repository::{memory::MemoryUserRepository, traits::UserRepository};
+use crate::api::config::OutboxConfig;
+use crate::api::memory::MemoryDb;
+use crate::api::outbox::{relay::run_once, repository::memory::MemoryOutboxRepository, sink::Sink, test::open_agenda};
+use crate::api::webhook::repository::{memory::MemoryWebhookRepository, traits::WebhookRepository, NewSubscription};
+use crate::api::webhook::sink::WebhookSink;
+
+// 테스트 1: 안건 이벤트만 대기열에 들어가고, 마감 뒤에는 가결 판정이 따라옴
+#[tokio::test]
+async fn test_agenda_events_are_enqueued_with_decision() {
+    let db = Arc::new(MemoryDb::default());
+    let owner = MemoryUserRepository::new(&db).save("owner", "hash").await.unwrap();
+    let agendas = Arc::new(MemoryAgendaRepository::new(&db));
+    let webhooks = Arc::new(MemoryWebhookRepository::new(&db));
+    let outbox = MemoryOutboxRepository::new(&db);
+    webhooks
+        .create_subscription(&NewSubscription {
+            owner_id: owner,
+            agenda_id: None,
+            url: "https://example.com/hook".to_string(),
+            secret: "whsec_test".to_string(),
+            events: vec![],
+        })
+        .await
+        .unwrap();
+
+    let id = open_agenda(agendas.as_ref(), owner).await;
+    agendas.vote(id, owner, Choice::Agree).await.unwrap();
+    agendas.update_status(id, AgendaStatus::Open, AgendaStatus::Closed).await.unwrap();
+
+    let sinks: Vec<Arc<dyn Sink>> = vec![Arc::new(WebhookSink::new(webhooks.clone(), agendas.clone()))];
+    assert_eq!(run_once(&outbox, &sinks, &OutboxConfig::default()).await, 3);
+
+    let mut due = webhooks.claim_due(10, Utc::now()).await.unwrap();
+    due.sort_by_key(|delivery| (delivery.payload["id"].as_i64(), delivery.event_type.clone()));
+    let events: Vec<&str> = due.iter().map(|delivery| delivery.event_type.as_str()).collect();
+    assert_eq!(events, ["agenda.created", "agenda.closed", "agenda.decided"]);
+
+    let (closed, decided) = (&due[1].payload, &due[2].payload);
+    assert_eq!(closed["id"], decided["id"]);
+    assert_eq!(closed["data"]["status"], "closed");
+    assert_eq!(decided["data"]["outcome"], "passed");
+}
@@ -0,0 +1,37 @@
+use std::net::IpAddr;
+use crate::api::webhook::target::{check_url, is_allowed};
+
+fn ip(addr: &str) -> IpAddr {
+    addr.parse().unwrap()
+}
+
+// 테스트 1: 루프백 / 사설망 / 링크 로컬 / 미지정 주소는 거부, 공인 주소는 허용
+#[test]
+fn test_is_allowed() {
+    for addr in [
+        "127.0.0.1", "10.0.0.1", "172.16.5.4", "192.168.1.1", "169.254.169.254", "0.0.0.0", "255.255.255.255",
+        "::1", "::", "fe80::1", "fd00::1", "::ffff:127.0.0.1", "::ffff:10.0.0.1",
+    ] {
+        assert!(!is_allowed(ip(addr)), "{}", addr);
+    }
+    for addr in ["93.184.216.34", "8.8.8.8", "2606:2800:220:1::1"] {
+        assert!(is_allowed(ip(addr)), "{}", addr);
+    }
+}
+
+// 테스트 2: 등록 시에는 IP 주소 / localhost 호스트만 거부 (다른 표기로 쓴 IP 주소 포함)
+#[test]
+fn test_check_url() {
+    for url in [
+        "http://127.0.0.1/hook",
+        "http://2130706433/hook",
+        "http://0x7f.1/hook",
+        "http://[::ffff:7f00:1]/hook",
+        "http://LOCALHOST./hook",
+        "http://api.localhost/hook",
+    ] {
+        assert!(check_url(url).is_err(), "{}", url);
+    }
+    assert!(check_url("https://example.com/hook").is_ok());
+    assert!(check_url("https://93.184.216.34/hook").is_ok());
+}
@@ -1,25 +1,23 @@
 use sqlx::{postgres::PgPoolOptions, PgPool};
-use std::env;
+use crate::api::config::DatabaseConfig;
 
 // DB 연결 초기화 함수
 // pub : 다른 모듈에서 불러다 쓸 수 있게 공개
-// 반환 값은 연결이 완료된 PgPool 객체
+// 반환 값은 연결이 완료된 PgPool 객체 (연결 실패는 에러로 반환 - 시작 중단)
 
-pub async fn init_pool() -> PgPool {
-    // 1. 환경 변수 읽기
-    let database_url = env::var("DATABASE_URL")
-        .expect("DATABASE_URL 이 존재하지 않습니다.");
-
-    // 2. 연결 풀 생성 설정
+pub async fn init_pool(config: &DatabaseConfig) -> Result<PgPool, sqlx::Error> {
+    // 1. 연결 풀 생성 설정 (크기 / 타임아웃은 설정 값)
     println!("⏳ Connecting to Database...");
 
     let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
-        .await
-        .expect("PostgreSQL 연결 실패");
+        .max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .acquire_timeout(config.acquire_timeout())
+        .idle_timeout(config.idle_timeout())
+        .connect(&config.url)
+        .await?;
 
     println!("✅ Connection to the database is successful!");
 
-    pool // 완성된 pool 반환
-}
\ No newline at end of file
+    Ok(pool) // 완성된 pool 반환
+}
@@ -1,3 +1,4 @@
+use std::{process::ExitCode, sync::Arc};
 use axum::serve;
 use dotenvy::dotenv;
 use tokio::net::TcpListener;
@@ -8,20 +9,84 @@ mod db;
 mod api;
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    dotenv().ok(); // 환경변수 로드
+async fn main() -> ExitCode {
+    // 시작 실패는 원인만 한 줄로 출력하고 종료 코드 1
+    match run().await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("❌ {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    // 1. DB 연결 (db 모듈에게 위임)
+async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok(); // .env를 환경변수로 로드
+
+    // 0. 설정 로드 (기본값 → config.toml → 환경변수, 잘못된 값이면 시작하지 않음)
+    let config = Arc::new(api::Config::load()?);
+
+    // 1. JWT 서명 키 로드 (운영 모드에서 키 설정이 없으면 시작하지 않음)
+    let keys = Arc::new(api::load_signing_keys(&config)?);
+
+    // 2. 저장소 구성 (PostgreSQL 연결은 db 모듈에게 위임, memory면 DB 없이 실행)
     // Node.js: const pool = await require('./db').initPool();
-    let pool = db::init_pool().await;
+    let state = match config.database.backend {
+        api::config::Backend::Postgres => {
+            let pool = db::init_pool(&config.database)
+                .await
+                .map_err(|e| format!("database connection failed: {}", e))?;
+            api::AppState::postgres(pool, config.clone(), keys)
+        }
+        api::config::Backend::Memory => {
+            println!("⚠️ Using the in-memory backend (data is lost on restart)");
+            api::AppState::memory(config.clone(), keys)
+        }
+    };
+
+    // 3. 백그라운드 작업 시작 (안건 예약 시작/마감, 카운터 반영)
+    api::spawn_background_tasks(&state);
+
+    // 4. 앱 라우터 생성 (api 모듈에게 위임 + 상태 주입)
+    let app = api::app(state.clone());
 
-    // 2. 앱 라우터 생성 (api 모듈에게 위임 + pool 주입)
-    let app = api::app(pool);
+    // 5. 서버 실행 (Ctrl+C / SIGTERM 시 진행 중인 요청을 마치고 종료)
+    let bind_addr = config.server.bind_addr;
+    let listener = TcpListener::bind(bind_addr)
+        .await
+        .map_err(|e| format!("cannot bind {}: {}", bind_addr, e))?;
+    println!("🚀 Server started at http://{}", bind_addr);
+    serve(listener, app)
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
 
-    // 3. 서버 실행
-    let listener = TcpListener::bind("0.0.0.0:3000").await?;
-    println!("🚀 Server started at http://0.0.0.0:3000");
-    serve(listener, app).await?;
+    // 6. 메모리에 남은 카운터 증감 반영
+    api::shutdown(&state).await;
 
     Ok(())
+}
+
+/// 종료 신호 대기 (Ctrl+C, 컨테이너 환경의 SIGTERM)
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c().await.expect("Ctrl+C 핸들러 등록 실패");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("SIGTERM 핸들러 등록 실패")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+
+    println!("🛑 Shutting down...");
 }
\ No newline at end of file